@@ -37,7 +37,12 @@ where
         json: matches.get_flag("json"),
         verbose: matches.get_flag("verbose"),
     };
-    let output = if verbosity.json {
+    // `status --format json` is an alias for the global `--json` flag.
+    let format_json = matches
+        .subcommand()
+        .and_then(|(_, sub)| sub.try_get_one::<String>("format").ok().flatten())
+        .is_some_and(|format| format == "json");
+    let output = if verbosity.json || format_json {
         OutputFormat::Json
     } else {
         OutputFormat::Text
@@ -45,7 +50,7 @@ where
 
     let workspace_override = matches.get_one::<String>("workspace").cloned();
 
-    let result = dispatch(&matches, workspace_override, verbosity)?;
+    let result = dispatch(&matches, workspace_override, verbosity, output)?;
     emit_result(result, output)
 }
 
@@ -82,10 +87,19 @@ fn build_cli() -> Command {
         .subcommand_required(true)
         .subcommand(commands::init::command())
         .subcommand(commands::status::command())
+        .subcommand(commands::lint::command())
         .subcommand(commands::spec::command())
         .subcommand(commands::implementation::command())
         .subcommand(commands::scratch::command())
         .subcommand(commands::templates::command())
+        .subcommand(commands::graph::command())
+        .subcommand(commands::plan::command())
+        .subcommand(commands::deps::command())
+        .subcommand(commands::vendor::command())
+        .subcommand(commands::references::command())
+        .subcommand(commands::search::command())
+        .subcommand(commands::query::command())
+        .subcommand(commands::show::command())
 }
 
 /// Delegates parsed subcommands to their respective modules, ensuring the Lifecycle
@@ -95,6 +109,7 @@ fn dispatch(
     matches: &ArgMatches,
     workspace_override: Option<String>,
     verbosity: Verbosity,
+    output: OutputFormat,
 ) -> Result<commands::CommandResult, CliError> {
     match matches.subcommand() {
         Some(("init", sub)) => commands::init::run(workspace_override, sub),
@@ -109,7 +124,7 @@ fn dispatch(
                     "resolved workspace context"
                 );
             }
-            dispatch_with_session(&session, matches)
+            dispatch_with_session(&session, matches, output)
         }
     }
 }
@@ -117,13 +132,23 @@ fn dispatch(
 fn dispatch_with_session(
     session: &CliSession,
     matches: &ArgMatches,
+    output: OutputFormat,
 ) -> Result<commands::CommandResult, CliError> {
     match matches.subcommand() {
-        Some(("status", sub)) => commands::status::run(session, sub),
+        Some(("status", sub)) => commands::status::run(session, sub, output),
+        Some(("lint", sub)) => commands::lint::run(session, sub),
         Some(("spec", sub)) => commands::spec::run(session, sub),
         Some(("impl", sub)) => commands::implementation::run(session, sub),
         Some(("scratch", sub)) => commands::scratch::run(session, sub),
         Some(("template", sub)) => commands::templates::run(session, sub),
+        Some(("graph", sub)) => commands::graph::run(session, sub),
+        Some(("plan", sub)) => commands::plan::run(session, sub),
+        Some(("deps", sub)) => commands::deps::run(session, sub),
+        Some(("vendor", sub)) => commands::vendor::run(session, sub),
+        Some(("references", sub)) => commands::references::run(session, sub),
+        Some(("search", sub)) => commands::search::run(session, sub),
+        Some(("query", sub)) => commands::query::run(session, sub),
+        Some(("show", sub)) => commands::show::run(session, sub),
         _ => Err(CliError::new("missing command", ExitStatus::Usage)),
     }
 }
@@ -0,0 +1,41 @@
+use clap::{ArgMatches, Command};
+use specman::DependencyLocker;
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::{CliError, ExitStatus};
+
+/// Defines the `specman deps` command tree for `.specman/deps.lock`.
+pub fn command() -> Command {
+    Command::new("deps")
+        .about("Pin remote (https://) dependencies in .specman/deps.lock")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("lock")
+                .about("Lock remote dependencies that are not pinned yet and drop unused entries"),
+        )
+        .subcommand(
+            Command::new("update").about("Re-fetch every remote dependency and refresh the lock"),
+        )
+        .subcommand(Command::new("verify").about(
+            "Check the lock against the workspace and the cached copies without network access",
+        ))
+}
+
+pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let locker = DependencyLocker::new(session.workspace_paths.clone());
+    match matches.subcommand() {
+        Some(("lock", _)) => Ok(CommandResult::DepsLocked {
+            report: locker.lock().map_err(CliError::from)?,
+            updated: false,
+        }),
+        Some(("update", _)) => Ok(CommandResult::DepsLocked {
+            report: locker.update().map_err(CliError::from)?,
+            updated: true,
+        }),
+        Some(("verify", _)) => Ok(CommandResult::DepsVerified {
+            verification: locker.verify().map_err(CliError::from)?,
+        }),
+        _ => Err(CliError::new("missing deps subcommand", ExitStatus::Usage)),
+    }
+}
@@ -0,0 +1,59 @@
+use clap::{Arg, ArgMatches, Command};
+use specman::{DependencyGraphExport, GraphFormat};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+pub fn command() -> Command {
+    Command::new("graph")
+        .about("Export the workspace dependency graph as DOT, Mermaid, or GraphML")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["dot", "mermaid", "graphml"])
+                .default_value("dot")
+                .help("Graph format to emit"),
+        )
+        .arg(
+            Arg::new("root")
+                .long("root")
+                .value_name("HANDLE")
+                .help("Export only the dependency tree of this artifact, e.g. spec://core"),
+        )
+}
+
+pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let format: GraphFormat = matches
+        .get_one::<String>("format")
+        .map(String::as_str)
+        .unwrap_or("dot")
+        .parse()
+        .map_err(CliError::from)?;
+
+    let graph = match matches.get_one::<String>("root") {
+        Some(handle) => {
+            let tree = session
+                .env
+                .graph
+                .dependency_tree_from_locator(handle)
+                .map_err(CliError::from)?;
+            DependencyGraphExport::from_tree(&tree)
+        }
+        None => {
+            let snapshot = session
+                .env
+                .graph
+                .inventory_snapshot()
+                .map_err(CliError::from)?;
+            DependencyGraphExport::from_inventory(&snapshot)
+        }
+    };
+
+    Ok(CommandResult::Graph {
+        nodes: graph.nodes.len(),
+        edges: graph.edges.len(),
+        document: graph.render(format),
+    })
+}
@@ -6,8 +6,8 @@ use serde::Serialize;
 use specman::{
     ArtifactId, ArtifactKind, ArtifactSummary, DependencyTree,
     ImplementationFrontMatter, SpecificationFrontMatter, ArtifactIdentityFields, ReferenceEntry,
-    CreateImplOptions, CreateResult, DeleteOptions, DeleteResult,
-    create_implementation, delete_artifact, split_front_matter,
+    CreateImplOptions, CreateResult, DeleteOptions, DeleteResult, RenameOptions,
+    create_implementation, delete_artifact, rename_artifact, split_front_matter,
 };
 
 use crate::commands::CommandResult;
@@ -33,6 +33,7 @@ pub fn command() -> Command {
         .subcommand(ls_command())
         .subcommand(new_command())
         .subcommand(delete_command())
+        .subcommand(rename_command())
         .subcommand(dependencies_command())
 }
 
@@ -41,6 +42,7 @@ pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult,
         Some(("ls", _)) => list_impls(session),
         Some(("new", sub)) => create_impl(session, sub),
         Some(("delete", sub)) => delete_impl(session, sub),
+        Some(("rename", sub)) => rename_impl(session, sub),
         Some(("dependencies", sub)) => impl_dependencies(session, sub),
         _ => Err(CliError::new("unsupported impl command", ExitStatus::Usage)),
     }
@@ -202,6 +204,39 @@ fn delete_impl(session: &CliSession, matches: &ArgMatches) -> Result<CommandResu
     })
 }
 
+fn rename_impl(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let name = matches
+        .get_one::<String>("name")
+        .cloned()
+        .ok_or_else(|| CliError::new("implementation name required", ExitStatus::Usage))?;
+    let new_name = matches
+        .get_one::<String>("new-name")
+        .cloned()
+        .ok_or_else(|| CliError::new("new implementation name required", ExitStatus::Usage))?;
+    util::validate_slug(&new_name, "implementation")?;
+    let dry_run = matches.get_flag("dry-run");
+
+    let folder = session.workspace_paths.impl_dir().join(&name);
+    if !folder.exists() {
+        return Err(CliError::new(
+            format!("implementation {name} does not exist"),
+            ExitStatus::Usage,
+        ));
+    }
+
+    let artifact = ArtifactId {
+        kind: ArtifactKind::Implementation,
+        name,
+    };
+    let result = rename_artifact(&session.env, &artifact, &new_name, RenameOptions { dry_run })
+        .map_err(CliError::from)?;
+
+    Ok(CommandResult::ImplRenamed {
+        plan: result.plan().clone(),
+        dry_run,
+    })
+}
+
 fn ls_command() -> Command {
     Command::new("ls").about("List implementations in the workspace")
 }
@@ -247,6 +282,29 @@ fn delete_command() -> Command {
         )
 }
 
+fn rename_command() -> Command {
+    Command::new("rename")
+        .about("Rename an implementation and rewrite references held by dependent artifacts")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .value_name("NAME")
+                .help("Current implementation name"),
+        )
+        .arg(
+            Arg::new("new-name")
+                .required(true)
+                .value_name("NEW_NAME")
+                .help("New implementation name"),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .action(ArgAction::SetTrue)
+                .help("Report the planned move and reference rewrites without changing files."),
+        )
+}
+
 fn dependencies_command() -> Command {
     dependencies::with_direction_flags(
         Command::new("dependencies")
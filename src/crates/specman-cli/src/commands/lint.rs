@@ -0,0 +1,15 @@
+use clap::{ArgMatches, Command};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+pub fn command() -> Command {
+    Command::new("lint")
+        .about("Check specifications against the layout rules configured in .specman/lint.yaml")
+}
+
+pub fn run(session: &CliSession, _matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let report = specman::lint_workspace(&session.workspace_paths).map_err(CliError::from)?;
+    Ok(CommandResult::Lint { report })
+}
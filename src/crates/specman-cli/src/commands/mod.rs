@@ -4,17 +4,34 @@ use specman::DependencyTree;
 use crate::error::ExitStatus;
 
 pub mod dependencies;
+pub mod deps;
+pub mod graph;
 pub mod implementation;
 pub mod init;
+pub mod lint;
+pub mod plan;
+pub mod query;
+pub mod references;
 pub mod scratch;
+pub mod search;
+pub mod show;
 pub mod spec;
 pub mod status;
 pub mod templates;
+pub mod vendor;
 
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CommandResult {
     Status(specman::WorkspaceStatusReport),
+    StatusDiff(specman::StatusDiff),
+    Sarif(specman::SarifLog),
+    /// Compliance coverage rendered as a JUnit XML document.
+    Junit {
+        xml: String,
+        failures: usize,
+        errors: usize,
+    },
     WorkspaceInitialized {
         root: String,
         dot_specman: String,
@@ -34,6 +51,20 @@ pub enum CommandResult {
         tree: spec::DeletionTree,
         removed_path: String,
     },
+    SpecRenamed {
+        plan: specman::RenamePlan,
+        dry_run: bool,
+    },
+    SpecBumped {
+        result: specman::BumpResult,
+        dry_run: bool,
+    },
+    SpecDiff {
+        diff: specman::SpecDiff,
+    },
+    SpecImpact {
+        impact: specman::SpecImpact,
+    },
     ImplList {
         implementations: Vec<implementation::ImplSummary>,
     },
@@ -47,6 +78,10 @@ pub enum CommandResult {
         tree: implementation::DeletionTree,
         removed_path: String,
     },
+    ImplRenamed {
+        plan: specman::RenamePlan,
+        dry_run: bool,
+    },
     ScratchList {
         pads: Vec<scratch::ScratchSummary>,
         missing_metadata: bool,
@@ -69,6 +104,70 @@ pub enum CommandResult {
     TemplatePointer {
         report: templates::PointerReport,
     },
+    TemplateList {
+        templates: Vec<templates::TemplateListing>,
+    },
+    TemplateShown {
+        template: String,
+        provenance: specman::TemplateProvenance,
+        body: String,
+    },
+    TemplateTokens {
+        template: String,
+        provenance: specman::TemplateProvenance,
+        tokens: Vec<specman::TemplateToken>,
+    },
+    TemplateValidated {
+        reports: Vec<specman::TemplateValidation>,
+        unresolved: Vec<templates::TemplateListing>,
+    },
+    DepsLocked {
+        report: specman::DependencyLockReport,
+        /// `deps update` re-fetched every entry rather than only missing ones.
+        updated: bool,
+    },
+    DepsVerified {
+        verification: specman::DependencyLockVerification,
+    },
+    Vendored {
+        report: specman::VendorReport,
+    },
+    ReferencesFixed {
+        report: specman::ReferenceFixReport,
+    },
+    Search {
+        query: String,
+        hits: Vec<specman::SearchHit>,
+    },
+    Query {
+        expression: String,
+        table: specman::QueryTable,
+    },
+    Shown {
+        target: String,
+        kind: show::ShowKind,
+        /// References were expanded into the rendered markdown.
+        expanded: bool,
+        markdown: String,
+        #[serde(skip)]
+        highlight: bool,
+    },
+    Backlinks {
+        target: String,
+        backlinks: Vec<show::BacklinkSummary>,
+    },
+    Lint {
+        report: specman::LintReport,
+    },
+    Plan {
+        plan: specman::ImplementationPlan,
+    },
+    /// Dependency graph rendered as DOT, Mermaid, or GraphML.
+    Graph {
+        document: String,
+        nodes: usize,
+        edges: usize,
+    },
 }
 
 impl CommandResult {
@@ -81,7 +180,55 @@ impl CommandResult {
                     ExitStatus::Data
                 }
             }
+            CommandResult::Sarif(log) => {
+                if log.has_errors() {
+                    ExitStatus::Data
+                } else {
+                    ExitStatus::Ok
+                }
+            }
+            CommandResult::Junit {
+                failures, errors, ..
+            } => {
+                if failures + errors > 0 {
+                    ExitStatus::Data
+                } else {
+                    ExitStatus::Ok
+                }
+            }
+            CommandResult::Lint { report } => {
+                if report.errors() > 0 {
+                    ExitStatus::Data
+                } else {
+                    ExitStatus::Ok
+                }
+            }
+            // Links that stay broken after the fix still fail, as they would in `status`.
+            CommandResult::ReferencesFixed { report } => {
+                if report.unresolved.is_empty() {
+                    ExitStatus::Ok
+                } else {
+                    ExitStatus::Data
+                }
+            }
+            CommandResult::TemplateValidated {
+                reports,
+                unresolved,
+            } => {
+                if unresolved.is_empty() && reports.iter().all(|report| report.is_valid()) {
+                    ExitStatus::Ok
+                } else {
+                    ExitStatus::Data
+                }
+            }
             CommandResult::WorkspaceInitialized { .. } => ExitStatus::Ok,
+            CommandResult::DepsVerified { verification } => {
+                if verification.is_clean() {
+                    ExitStatus::Ok
+                } else {
+                    ExitStatus::Data
+                }
+            }
             CommandResult::ScratchList {
                 missing_metadata, ..
             } => {
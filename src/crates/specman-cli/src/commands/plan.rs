@@ -0,0 +1,16 @@
+use clap::{ArgMatches, Command};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+pub fn command() -> Command {
+    Command::new("plan")
+        .about("Order specifications by their dependencies and show what can be implemented next")
+}
+
+pub fn run(session: &CliSession, _matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let plan = specman::plan_workspace(session.workspace_paths.root().to_path_buf())
+        .map_err(CliError::from)?;
+    Ok(CommandResult::Plan { plan })
+}
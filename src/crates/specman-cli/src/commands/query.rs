@@ -0,0 +1,38 @@
+use clap::{Arg, ArgMatches, Command};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+pub fn command() -> Command {
+    Command::new("query")
+        .about("Query headings, constraint groups, and relationships in the structure index")
+        .long_about(
+            "Query headings, constraint groups, and relationships in the structure index.\n\n\
+             <source> [where <condition>] [select <field>, ...] [limit <n>]\n\n\
+             Sources are `headings`, `constraints`, and `relationships`. Conditions combine \
+             `field op value` comparisons with `and`, `or`, `not`, and parentheses; operators are \
+             =, !=, ~ (contains), ^= (starts with), <, <=, >, >=.\n\n\
+             Examples:\n  \
+             specman query 'constraints where under = concept-lifecycle-automation and external_references > 0'\n  \
+             specman query 'headings where constraints = 0'\n  \
+             specman query 'relationships where to_artifact ^= spec/specman-core and external = true select from_artifact'",
+        )
+        .arg(
+            Arg::new("expression")
+                .value_name("EXPR")
+                .required(true)
+                .num_args(1..)
+                .help("Query expression; quote it to keep the shell away from `<`, `>`, and `!`"),
+        )
+}
+
+pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let expression = matches
+        .get_many::<String>("expression")
+        .map(|words| words.cloned().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default();
+    let table =
+        specman::query_workspace(&session.workspace_paths, &expression).map_err(CliError::from)?;
+    Ok(CommandResult::Query { expression, table })
+}
@@ -0,0 +1,40 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use specman::ReferenceFixOptions;
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::{CliError, ExitStatus};
+
+/// Defines the `specman references` command tree.
+pub fn command() -> Command {
+    Command::new("references")
+        .about("Repair markdown links between workspace documents")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("fix")
+                .about("Rewrite broken heading fragments to the closest heading and links to files renamed in git")
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .action(ArgAction::SetTrue)
+                        .help("Report the rewrites without touching any file"),
+                ),
+        )
+}
+
+pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    match matches.subcommand() {
+        Some(("fix", sub)) => {
+            let options = ReferenceFixOptions {
+                dry_run: sub.get_flag("dry-run"),
+            };
+            let report = specman::fix_references(&session.workspace_paths, &options)
+                .map_err(CliError::from)?;
+            Ok(CommandResult::ReferencesFixed { report })
+        }
+        _ => Err(CliError::new(
+            "missing references subcommand",
+            ExitStatus::Usage,
+        )),
+    }
+}
@@ -1,13 +1,15 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
-use clap::{Arg, ArgAction, ArgMatches, Command, ValueEnum, builder::EnumValueParser};
+use clap::{Arg, ArgAction, ArgMatches, Command, ValueEnum};
 use serde::Serialize;
+use serde_json::Value as JsonValue;
 use specman::{
-    ArtifactId, ArtifactKind, CreateResult, CreateScratchOptions, DeleteOptions, DeleteResult,
-    DependencyTree, ScratchFrontMatter, ScratchRefactorMetadata, ScratchRevisionMetadata,
-    ScratchWorkType, ScratchWorkloadExtras, create_scratch_pad, delete_artifact,
-    split_front_matter,
+    ArtifactId, ArtifactKind, CreateResult, CreateScratchOptions, CustomWorkType, DeleteOptions,
+    DeleteResult, DependencyTree, ScratchFrontMatter, ScratchRefactorMetadata,
+    ScratchRevisionMetadata, ScratchWorkType, ScratchWorkloadExtras, create_scratch_pad,
+    delete_artifact, split_front_matter,
 };
 
 use crate::commands::CommandResult;
@@ -97,14 +99,14 @@ fn create_scratchpad(
         .ok_or_else(|| CliError::new("--target is required", ExitStatus::Usage))?;
     util::validate_locator(&target, "scratch target")?;
 
-    let work_type_enum = matches
-        .get_one::<ScratchType>("type")
+    let work_type_name = matches
+        .get_one::<String>("type")
         .expect("clap ensures required option");
-    let work_type = match work_type_enum {
-        ScratchType::Feat => ScratchWorkType::Feat(ScratchWorkloadExtras::default()),
-        ScratchType::Ref => ScratchWorkType::Refactor(ScratchRefactorMetadata::default()),
-        ScratchType::Revision => ScratchWorkType::Revision(ScratchRevisionMetadata::default()),
-    };
+    let meta: Vec<&String> = matches
+        .get_many::<String>("meta")
+        .map(|values| values.collect())
+        .unwrap_or_default();
+    let work_type = parse_work_type(work_type_name, &meta)?;
 
     let result = create_scratch_pad(
         &session.env,
@@ -131,6 +133,39 @@ fn create_scratchpad(
     })
 }
 
+/// Maps `--type` to a built-in work type, or to a custom type carrying the `--meta` pairs.
+/// Whether a custom type is declared in `.specman/work-types.yaml` is checked on creation.
+fn parse_work_type(name: &str, meta: &[&String]) -> Result<ScratchWorkType, CliError> {
+    if let Ok(builtin) = ScratchType::from_str(name, false) {
+        if !meta.is_empty() {
+            return Err(CliError::new(
+                format!("--meta is only supported for custom work types, not {name}"),
+                ExitStatus::Usage,
+            ));
+        }
+        return Ok(match builtin {
+            ScratchType::Feat => ScratchWorkType::Feat(ScratchWorkloadExtras::default()),
+            ScratchType::Ref => ScratchWorkType::Refactor(ScratchRefactorMetadata::default()),
+            ScratchType::Revision => ScratchWorkType::Revision(ScratchRevisionMetadata::default()),
+        });
+    }
+
+    let mut metadata = BTreeMap::new();
+    for pair in meta {
+        let Some((key, value)) = pair.split_once('=').filter(|(key, _)| !key.is_empty()) else {
+            return Err(CliError::new(
+                format!("invalid --meta `{pair}`; expected KEY=VALUE"),
+                ExitStatus::Usage,
+            ));
+        };
+        metadata.insert(key.to_string(), JsonValue::String(value.to_string()));
+    }
+    Ok(ScratchWorkType::Custom(CustomWorkType {
+        name: name.to_string(),
+        metadata,
+    }))
+}
+
 fn delete_scratchpad(
     session: &CliSession,
     matches: &ArgMatches,
@@ -224,9 +259,15 @@ fn new_command() -> Command {
             Arg::new("type")
                 .required(true)
                 .long("type")
-                .value_name("feat|ref|revision")
-                .value_parser(EnumValueParser::<ScratchType>::new())
-                .help("Scratch pad work type"),
+                .value_name("feat|ref|revision|CUSTOM")
+                .help("Scratch pad work type, or one declared in .specman/work-types.yaml"),
+        )
+        .arg(
+            Arg::new("meta")
+                .long("meta")
+                .value_name("KEY=VALUE")
+                .action(ArgAction::Append)
+                .help("Metadata for a custom work type (repeatable)"),
         )
 }
 
@@ -306,10 +347,7 @@ fn read_scratch_summary(root: &Path, path: &Path) -> Result<ScratchSummary, CliE
         .map_err(|err| CliError::new(err.to_string(), ExitStatus::Config))?;
     let fm: ScratchFrontMatter = serde_yaml::from_str(split.yaml)
         .map_err(|err| CliError::new(err.to_string(), ExitStatus::Config))?;
-    let work_type = fm
-        .work_type
-        .as_ref()
-        .map(|ty| ty.kind().as_str().to_string());
+    let work_type = fm.work_type.as_ref().map(|ty| ty.name().to_string());
     let target = fm.target.clone().and_then(|value| {
         if value.trim().is_empty() {
             None
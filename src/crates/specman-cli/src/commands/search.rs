@@ -0,0 +1,60 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use specman::{ArtifactKind, SearchOptions};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+pub fn command() -> Command {
+    Command::new("search")
+        .about("Full-text search over headings and constraint groups, ranked with BM25")
+        .arg(
+            Arg::new("query")
+                .value_name("QUERY")
+                .required(true)
+                .num_args(1..)
+                .help("Words to search for; multiple words are ranked together"),
+        )
+        .arg(
+            Arg::new("kind")
+                .long("kind")
+                .value_name("KIND")
+                .value_parser(["spec", "impl", "scratch"])
+                .help("Only search artifacts of this kind"),
+        )
+        .arg(
+            Arg::new("constraints-only")
+                .long("constraints-only")
+                .action(ArgAction::SetTrue)
+                .help("Only return constraint groups"),
+        )
+        .arg(
+            Arg::new("limit")
+                .long("limit")
+                .value_name("N")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("20")
+                .help("Maximum number of results"),
+        )
+}
+
+pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let query = matches
+        .get_many::<String>("query")
+        .map(|words| words.cloned().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default();
+    let kind = matches
+        .get_one::<String>("kind")
+        .map(|kind| kind.parse::<ArtifactKind>())
+        .transpose()
+        .map_err(CliError::from)?;
+    let options = SearchOptions {
+        kind,
+        constraints_only: matches.get_flag("constraints-only"),
+        limit: matches.get_one::<usize>("limit").copied().unwrap_or(20),
+    };
+
+    let hits = specman::search_workspace(&session.workspace_paths, &query, &options)
+        .map_err(CliError::from)?;
+    Ok(CommandResult::Search { query, hits })
+}
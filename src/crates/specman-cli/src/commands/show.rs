@@ -0,0 +1,136 @@
+use std::io::IsTerminal;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde::Serialize;
+use specman::{
+    ArtifactKind, Backlink, FilesystemStructureIndexer, FilesystemWorkspaceLocator, IndexTarget,
+    WorkspaceIndex,
+};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+/// Where a link into the shown section comes from.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BacklinkSource {
+    Heading,
+    Constraint,
+    /// A heading inside an implementation artifact.
+    Implementation,
+    /// A heading inside a scratch pad.
+    ScratchPad,
+}
+
+/// What a `show` target resolved to.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShowKind {
+    Artifact,
+    Heading,
+    Constraint,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BacklinkSummary {
+    pub source: BacklinkSource,
+    /// `<path>#<slug>` or `<path>!<group>` of the linking heading or constraint group.
+    pub from: String,
+    /// `<path>#<slug>` of the linked heading inside the shown section.
+    pub to: String,
+}
+
+pub fn command() -> Command {
+    Command::new("show")
+        .about("Render an artifact, heading, or constraint group from the structure index")
+        .arg(
+            Arg::new("target")
+                .value_name("TARGET")
+                .required(true)
+                .help("Handle or workspace path, optionally followed by #<heading> or /constraints/<group>, e.g. spec://core#concept-workspace-discovery"),
+        )
+        .arg(
+            Arg::new("no-expand")
+                .long("no-expand")
+                .action(ArgAction::SetTrue)
+                .help("Render only the target itself, without the sections its references point to"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["markdown", "ansi", "json"])
+                .help("Output format; defaults to `ansi` on a terminal (unless NO_COLOR is set) and `markdown` otherwise"),
+        )
+        .arg(
+            Arg::new("backlinks")
+                .long("backlinks")
+                .action(ArgAction::SetTrue)
+                .help("List headings, constraint groups, and implementations that link into the target"),
+        )
+}
+
+pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let raw = matches
+        .get_one::<String>("target")
+        .expect("target is required");
+    let index = FilesystemStructureIndexer::new(FilesystemWorkspaceLocator::new(
+        session.workspace_paths.root().to_path_buf(),
+    ))
+    .build_cached_with_workspace(&session.workspace_paths)
+    .map_err(CliError::from)?;
+    let target =
+        IndexTarget::resolve(&index, &session.workspace_paths, raw).map_err(CliError::from)?;
+
+    if matches.get_flag("backlinks") {
+        return Ok(CommandResult::Backlinks {
+            target: target.identifier(),
+            backlinks: summarize_backlinks(&index, &target),
+        });
+    }
+
+    let expanded = !matches.get_flag("no-expand");
+    let markdown = target.render(&index, expanded).map_err(CliError::from)?;
+    // `--format json` is routed to the JSON formatter by the CLI entry point.
+    let highlight = match matches.get_one::<String>("format").map(String::as_str) {
+        Some(format) => format == "ansi",
+        None => std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+    };
+    Ok(CommandResult::Shown {
+        target: target.identifier(),
+        kind: match target {
+            IndexTarget::Artifact(_) => ShowKind::Artifact,
+            IndexTarget::Heading(_) => ShowKind::Heading,
+            IndexTarget::Constraint(_) => ShowKind::Constraint,
+        },
+        expanded,
+        markdown,
+        highlight,
+    })
+}
+
+fn summarize_backlinks(index: &WorkspaceIndex, target: &IndexTarget) -> Vec<BacklinkSummary> {
+    target
+        .backlinks(index)
+        .into_iter()
+        .map(|link| {
+            let source = match (&link.source, link.source.artifact().kind) {
+                (Backlink::Constraint(_), _) => BacklinkSource::Constraint,
+                (Backlink::Heading(_), ArtifactKind::Implementation) => {
+                    BacklinkSource::Implementation
+                }
+                (Backlink::Heading(_), ArtifactKind::ScratchPad) => BacklinkSource::ScratchPad,
+                (Backlink::Heading(_), ArtifactKind::Specification) => BacklinkSource::Heading,
+            };
+            BacklinkSummary {
+                source,
+                from: link.source.identifier(),
+                to: format!(
+                    "{}#{}",
+                    link.target.artifact.workspace_path, link.target.slug
+                ),
+            }
+        })
+        .collect()
+}
@@ -1,13 +1,14 @@
 use std::fs;
 use std::path::Path;
 
-use clap::{Arg, ArgAction, ArgMatches, Command};
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
 use serde::Serialize;
 use specman::{
-    ArtifactId, ArtifactKind, ArtifactSummary, DependencyTree,
+    ArtifactId, ArtifactKind, ArtifactSummary, BumpOptions, DependencyTree,
     DependencyEntry, ArtifactIdentityFields, SpecificationFrontMatter,
-    CreateSpecOptions, CreateResult, DeleteOptions, DeleteResult,
-    create_specification, delete_artifact, split_front_matter,
+    CreateSpecOptions, CreateResult, DeleteOptions, DeleteResult, RenameOptions, SpecRevision,
+    VersionBump, analyze_spec_impact, bump_version, create_specification, delete_artifact,
+    diff_specifications, rename_artifact, split_front_matter,
 };
 
 use crate::commands::CommandResult;
@@ -31,6 +32,10 @@ pub fn command() -> Command {
         .subcommand(spec_ls_command())
         .subcommand(spec_new_command())
         .subcommand(spec_delete_command())
+        .subcommand(spec_rename_command())
+        .subcommand(spec_bump_command())
+        .subcommand(spec_diff_command())
+        .subcommand(spec_impact_command())
         .subcommand(spec_dependencies_command())
 }
 
@@ -39,6 +44,10 @@ pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult,
         Some(("ls", _)) => list_specs(session),
         Some(("new", sub)) => create_spec(session, sub),
         Some(("delete", sub)) => delete_spec(session, sub),
+        Some(("rename", sub)) => rename_spec(session, sub),
+        Some(("bump", sub)) => bump_spec(session, sub),
+        Some(("diff", sub)) => diff_spec(session, sub),
+        Some(("impact", sub)) => impact_spec(session, sub),
         Some(("dependencies", sub)) => spec_dependencies(session, sub),
         _ => Err(CliError::new("unsupported spec command", ExitStatus::Usage)),
     }
@@ -184,6 +193,155 @@ fn delete_spec(session: &CliSession, matches: &ArgMatches) -> Result<CommandResu
     })
 }
 
+fn rename_spec(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let name = matches
+        .get_one::<String>("name")
+        .cloned()
+        .ok_or_else(|| CliError::new("spec name required", ExitStatus::Usage))?;
+    let new_name = matches
+        .get_one::<String>("new-name")
+        .cloned()
+        .ok_or_else(|| CliError::new("new spec name required", ExitStatus::Usage))?;
+    util::validate_slug(&new_name, "specification")?;
+    let dry_run = matches.get_flag("dry-run");
+
+    let folder = session.workspace_paths.spec_dir().join(&name);
+    if !folder.exists() {
+        return Err(CliError::new(
+            format!("specification {name} does not exist"),
+            ExitStatus::Usage,
+        ));
+    }
+
+    let artifact = ArtifactId {
+        kind: ArtifactKind::Specification,
+        name,
+    };
+    let result = rename_artifact(&session.env, &artifact, &new_name, RenameOptions { dry_run })
+        .map_err(CliError::from)?;
+
+    Ok(CommandResult::SpecRenamed {
+        plan: result.plan().clone(),
+        dry_run,
+    })
+}
+
+fn bump_spec(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let name = matches
+        .get_one::<String>("name")
+        .cloned()
+        .ok_or_else(|| CliError::new("spec name required", ExitStatus::Usage))?;
+    util::validate_slug(&name, "specification")?;
+    let dry_run = matches.get_flag("dry-run");
+    let bump = if matches.get_flag("major") {
+        VersionBump::Major
+    } else if matches.get_flag("minor") {
+        VersionBump::Minor
+    } else {
+        VersionBump::Patch
+    };
+
+    let folder = session.workspace_paths.spec_dir().join(&name);
+    if !folder.exists() {
+        return Err(CliError::new(
+            format!("specification {name} does not exist"),
+            ExitStatus::Usage,
+        ));
+    }
+
+    let artifact = ArtifactId {
+        kind: ArtifactKind::Specification,
+        name,
+    };
+    let result = bump_version(&session.env, &artifact, bump, BumpOptions { dry_run })
+        .map_err(CliError::from)?;
+
+    Ok(CommandResult::SpecBumped { result, dry_run })
+}
+
+fn diff_spec(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let name = matches
+        .get_one::<String>("name")
+        .cloned()
+        .ok_or_else(|| CliError::new("spec name required", ExitStatus::Usage))?;
+    util::validate_slug(&name, "specification")?;
+
+    let spec_file = session
+        .workspace_paths
+        .spec_dir()
+        .join(&name)
+        .join("spec.md");
+    if !spec_file.is_file() {
+        return Err(CliError::new(
+            format!("specification {name} does not exist"),
+            ExitStatus::Usage,
+        ));
+    }
+
+    let workspace = &session.workspace_paths;
+    let old = match matches.get_one::<String>("old") {
+        Some(path) => {
+            let content = fs::read_to_string(path)?;
+            SpecRevision::from_content(workspace, &spec_file, path.as_str(), &content)
+        }
+        None => {
+            let revision = matches
+                .get_one::<String>("rev")
+                .map(String::as_str)
+                .unwrap_or("HEAD");
+            SpecRevision::from_git(workspace, revision, &spec_file)
+        }
+    }
+    .map_err(CliError::from)?;
+    let new = SpecRevision::from_file(workspace, &spec_file).map_err(CliError::from)?;
+
+    Ok(CommandResult::SpecDiff {
+        diff: diff_specifications(&old, &new),
+    })
+}
+
+fn impact_spec(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let name = matches
+        .get_one::<String>("name")
+        .cloned()
+        .ok_or_else(|| CliError::new("spec name required", ExitStatus::Usage))?;
+    util::validate_slug(&name, "specification")?;
+
+    let spec_file = session
+        .workspace_paths
+        .spec_dir()
+        .join(&name)
+        .join("spec.md");
+    if !spec_file.is_file() {
+        return Err(CliError::new(
+            format!("specification {name} does not exist"),
+            ExitStatus::Usage,
+        ));
+    }
+
+    let workspace = &session.workspace_paths;
+    let revision = matches
+        .get_one::<String>("since")
+        .map(String::as_str)
+        .unwrap_or("HEAD");
+    let old = SpecRevision::from_git(workspace, revision, &spec_file).map_err(CliError::from)?;
+    let new = SpecRevision::from_file(workspace, &spec_file).map_err(CliError::from)?;
+
+    let artifact = ArtifactId {
+        kind: ArtifactKind::Specification,
+        name,
+    };
+    let impact = analyze_spec_impact(
+        &session.env,
+        workspace,
+        &artifact,
+        diff_specifications(&old, &new),
+    )
+    .map_err(CliError::from)?;
+
+    Ok(CommandResult::SpecImpact { impact })
+}
+
 fn spec_ls_command() -> Command {
     Command::new("ls").about("List specifications in the workspace")
 }
@@ -230,6 +388,110 @@ fn spec_delete_command() -> Command {
         )
 }
 
+fn spec_rename_command() -> Command {
+    Command::new("rename")
+        .about("Rename a specification and rewrite references held by dependent artifacts")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .value_name("NAME")
+                .help("Current specification name"),
+        )
+        .arg(
+            Arg::new("new-name")
+                .required(true)
+                .value_name("NEW_NAME")
+                .help("New specification name"),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .action(ArgAction::SetTrue)
+                .help("Report the planned move and reference rewrites without changing files."),
+        )
+}
+
+fn spec_bump_command() -> Command {
+    Command::new("bump")
+        .about("Increment a specification's version and list dependents it no longer satisfies")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .value_name("NAME")
+                .help("Specification name to bump"),
+        )
+        .arg(
+            Arg::new("major")
+                .long("major")
+                .action(ArgAction::SetTrue)
+                .help("Increment the major version and reset minor and patch."),
+        )
+        .arg(
+            Arg::new("minor")
+                .long("minor")
+                .action(ArgAction::SetTrue)
+                .help("Increment the minor version and reset patch."),
+        )
+        .arg(
+            Arg::new("patch")
+                .long("patch")
+                .action(ArgAction::SetTrue)
+                .help("Increment the patch version."),
+        )
+        .group(
+            ArgGroup::new("level")
+                .args(["major", "minor", "patch"])
+                .required(true),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .action(ArgAction::SetTrue)
+                .help("Report the new version and broken dependents without changing files."),
+        )
+}
+
+fn spec_diff_command() -> Command {
+    Command::new("diff")
+        .about("Report constraint groups and headings changed since an earlier revision")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .value_name("NAME")
+                .help("Specification name to compare"),
+        )
+        .arg(
+            Arg::new("rev")
+                .long("rev")
+                .value_name("REV")
+                .conflicts_with("old")
+                .help("Git revision holding the old document (defaults to HEAD)."),
+        )
+        .arg(
+            Arg::new("old")
+                .long("old")
+                .value_name("FILE")
+                .help("Compare against this file instead of a git revision."),
+        )
+}
+
+fn spec_impact_command() -> Command {
+    Command::new("impact")
+        .about("List implementations and ENSURES tags affected by changes to a specification")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .value_name("NAME")
+                .help("Specification name to analyze"),
+        )
+        .arg(
+            Arg::new("since")
+                .long("since")
+                .value_name("REV")
+                .help("Git revision to compare the specification against (defaults to HEAD)."),
+        )
+}
+
 fn spec_dependencies_command() -> Command {
     dependencies::with_direction_flags(
         Command::new("dependencies")
@@ -2,9 +2,13 @@ use std::time::Duration;
 
 use crate::commands::CommandResult;
 use crate::context::CliSession;
-use crate::error::CliError;
+use crate::error::{CliError, ExitStatus};
+use crate::formatter::{OutputFormat, emit_result};
 use clap::Command;
 
+/// How long each watch iteration waits for filesystem changes before polling again.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 pub fn command() -> Command {
     Command::new("status")
         .about("Validate specifications and implementations")
@@ -14,9 +18,27 @@ pub fn command() -> Command {
                 .action(clap::ArgAction::SetTrue)
                 .help("Disable network reachability checks during validation"),
         )
+        .arg(
+            clap::Arg::new("watch")
+                .long("watch")
+                .action(clap::ArgAction::SetTrue)
+                .help("Keep running and print newly introduced or resolved issues as files change"),
+        )
+        .arg(
+            clap::Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["text", "json", "sarif", "junit"])
+                .help("Report format; `sarif` emits a SARIF 2.1.0 log for code-scanning tools and `junit` emits compliance coverage as JUnit XML"),
+        )
 }
 
-pub fn run(session: &CliSession, matches: &clap::ArgMatches) -> Result<CommandResult, CliError> {
+/// `output` is the format resolved from `--json` and `--format`; `--watch` streams in it.
+pub fn run(
+    session: &CliSession,
+    matches: &clap::ArgMatches,
+    output: OutputFormat,
+) -> Result<CommandResult, CliError> {
     let local = matches.get_flag("local");
     // [ENSURES: concept-workspace-status.requirements:TEST]
     let options = specman::ReferenceValidationOptions {
@@ -34,17 +56,72 @@ pub fn run(session: &CliSession, matches: &clap::ArgMatches) -> Result<CommandRe
         transitive: specman::TransitiveOptions::default(),
     };
 
-    let report = specman::validate_workspace_status(
-        session.workspace_paths.root().to_path_buf(),
-        specman::WorkspaceStatusConfig {
-            structure: true,
-            references: true,
-            cycles: true,
-            compliance: true,
-            scratchpads: true,
-            reference_options: Some(options),
-        },
-    )?;
+    let config = specman::WorkspaceStatusConfig {
+        structure: true,
+        references: true,
+        cycles: true,
+        compliance: true,
+        scratchpads: true,
+        lint: true,
+        reference_options: Some(options),
+    };
+
+    let format = matches.get_one::<String>("format").map(String::as_str);
+
+    if matches.get_flag("watch") {
+        if let Some(format @ ("sarif" | "junit")) = format {
+            return Err(CliError::new(
+                format!("--format {format} cannot be combined with --watch"),
+                ExitStatus::Usage,
+            ));
+        }
+        return watch(session, config, output);
+    }
+
+    let mut report =
+        specman::validate_workspace_status(session.workspace_paths.root().to_path_buf(), config)?;
+
+    match format {
+        Some("sarif") => Ok(CommandResult::Sarif(specman::SarifLog::from_status_report(
+            &report,
+            &session.workspace_paths,
+        ))),
+        Some("junit") => {
+            let junit = specman::JUnitReport::from_status_report(&report);
+            Ok(CommandResult::Junit {
+                xml: junit.to_xml(),
+                failures: junit.failures(),
+                errors: junit.errors(),
+            })
+        }
+        _ => {
+            specman::SuggestionSources::new(&session.workspace_paths).attach(
+                report
+                    .artifacts
+                    .values_mut()
+                    .flat_map(|status| status.reference_errors.iter_mut()),
+            );
+            Ok(CommandResult::Status(report))
+        }
+    }
+}
+
+/// Prints the full report once, then only the issues each batch of edits introduces or
+/// resolves. Runs until the process is interrupted or the watcher fails.
+fn watch(
+    session: &CliSession,
+    config: specman::WorkspaceStatusConfig,
+    format: OutputFormat,
+) -> Result<CommandResult, CliError> {
+    let mut watcher =
+        specman::StatusWatcher::with_notify(session.workspace_paths.root().to_path_buf(), config)?;
+    emit_result(CommandResult::Status(watcher.report().clone()), format)?;
 
-    Ok(CommandResult::Status(report))
+    loop {
+        if let Some(diff) = watcher.poll(WATCH_POLL_INTERVAL)?
+            && !diff.is_empty()
+        {
+            emit_result(CommandResult::StatusDiff(diff), format)?;
+        }
+    }
 }
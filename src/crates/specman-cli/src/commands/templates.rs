@@ -1,16 +1,20 @@
 use clap::{Arg, ArgMatches, Command};
 use serde::Serialize;
-use specman::{ResolvedTemplate, TemplateDescriptor, TemplateProvenance};
+use specman::{
+    ResolvedTemplate, TemplateDescriptor, TemplateProvenance, TemplateScenario, template_source,
+    template_tokens, validate_template,
+};
 
 use crate::commands::CommandResult;
 use crate::context::CliSession;
 use crate::error::{CliError, ExitStatus};
 use crate::templates::TemplateKind;
 
-/// Defines the `specman template` command tree with `set` and `remove` subcommands.
+/// Defines the `specman template` command tree: `set` and `remove` manage pointers, while
+/// `list`, `show`, `tokens`, and `validate` inspect what each scenario resolves to.
 pub fn command() -> Command {
     Command::new("template")
-        .about("Manage template pointers for spec, impl, or scratch artifacts")
+        .about("Manage and inspect templates for spec, impl, or scratch artifacts")
         .subcommand_required(true)
         .subcommand(
             Command::new("set")
@@ -29,6 +33,25 @@ pub fn command() -> Command {
                 .about("Remove the pointer file for a template kind and fall back to overrides/defaults")
                 .arg(kind_arg()),
         )
+        .subcommand(
+            Command::new("list")
+                .about("List every template with the tier and locator it resolves from"),
+        )
+        .subcommand(
+            Command::new("show")
+                .about("Print the resolved template body")
+                .arg(template_arg().required(true)),
+        )
+        .subcommand(
+            Command::new("tokens")
+                .about("List the Handlebars variables a template reads")
+                .arg(template_arg().required(true)),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Render templates with sample contexts and report problems")
+                .arg(template_arg()),
+        )
 }
 
 /// Dispatches `template` subcommands to the correct handler.
@@ -36,6 +59,10 @@ pub fn run(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult,
     match matches.subcommand() {
         Some(("set", sub)) => set_pointer(session, sub),
         Some(("remove", sub)) => remove_pointer(session, sub),
+        Some(("list", _)) => list_templates(session),
+        Some(("show", sub)) => show_template(session, sub),
+        Some(("tokens", sub)) => list_tokens(session, sub),
+        Some(("validate", sub)) => validate_templates(session, sub),
         _ => Err(CliError::new(
             "missing template subcommand",
             ExitStatus::Usage,
@@ -62,6 +89,95 @@ fn remove_pointer(session: &CliSession, matches: &ArgMatches) -> Result<CommandR
     })
 }
 
+fn list_templates(session: &CliSession) -> Result<CommandResult, CliError> {
+    let templates = session
+        .templates
+        .scenarios()?
+        .into_iter()
+        .map(|scenario| {
+            let template = scenario.label().to_string();
+            match session.templates.resolve(scenario) {
+                Ok(resolved) => TemplateListing {
+                    template,
+                    provenance: Some(resolved.provenance),
+                    error: None,
+                },
+                Err(err) => TemplateListing {
+                    template,
+                    provenance: None,
+                    error: Some(err.to_string()),
+                },
+            }
+        })
+        .collect();
+    Ok(CommandResult::TemplateList { templates })
+}
+
+fn show_template(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let resolved = resolve_template(session, matches)?;
+    let body = template_source(&resolved)?;
+    Ok(CommandResult::TemplateShown {
+        template: resolved.descriptor.scenario.label().to_string(),
+        provenance: resolved.provenance,
+        body,
+    })
+}
+
+fn list_tokens(session: &CliSession, matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let resolved = resolve_template(session, matches)?;
+    let tokens = template_tokens(&template_source(&resolved)?, &resolved.descriptor.scenario)?;
+    Ok(CommandResult::TemplateTokens {
+        template: resolved.descriptor.scenario.label().to_string(),
+        provenance: resolved.provenance,
+        tokens,
+    })
+}
+
+/// Validates one template, or every template when none is named. Scenarios that fail to
+/// resolve are reported alongside the rendered ones rather than aborting the run.
+fn validate_templates(
+    session: &CliSession,
+    matches: &ArgMatches,
+) -> Result<CommandResult, CliError> {
+    if matches.contains_id("template") {
+        let resolved = resolve_template(session, matches)?;
+        return Ok(CommandResult::TemplateValidated {
+            reports: vec![validate_template(&resolved)?],
+            unresolved: Vec::new(),
+        });
+    }
+
+    let mut reports = Vec::new();
+    let mut unresolved = Vec::new();
+    for scenario in session.templates.scenarios()? {
+        let template = scenario.label().to_string();
+        match session.templates.resolve(scenario) {
+            Ok(resolved) => reports.push(validate_template(&resolved)?),
+            Err(err) => unresolved.push(TemplateListing {
+                template,
+                provenance: None,
+                error: Some(err.to_string()),
+            }),
+        }
+    }
+    Ok(CommandResult::TemplateValidated {
+        reports,
+        unresolved,
+    })
+}
+
+fn resolve_template(
+    session: &CliSession,
+    matches: &ArgMatches,
+) -> Result<ResolvedTemplate, CliError> {
+    let label = matches
+        .get_one::<String>("template")
+        .ok_or_else(|| CliError::new("template name is required", ExitStatus::Usage))?;
+    session
+        .templates
+        .resolve(TemplateScenario::from_label(label))
+}
+
 fn resolve_kind(matches: &ArgMatches) -> Result<TemplateKind, CliError> {
     let raw = matches
         .get_one::<String>("kind")
@@ -88,6 +204,22 @@ fn kind_arg() -> Arg {
         .value_parser(["spec", "impl", "scratch"])
 }
 
+fn template_arg() -> Arg {
+    Arg::new("template")
+        .value_name("TEMPLATE")
+        .help("spec, impl, or a scratch work type (ref, feat, fix, revision, or a declared type)")
+}
+
+/// One row of `template list`; `error` explains a scenario that does not resolve.
+#[derive(Debug, Serialize)]
+pub struct TemplateListing {
+    pub template: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<TemplateProvenance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct PointerReport {
     pub action: PointerAction,
@@ -0,0 +1,16 @@
+use clap::{ArgMatches, Command};
+
+use crate::commands::CommandResult;
+use crate::context::CliSession;
+use crate::error::CliError;
+
+pub fn command() -> Command {
+    Command::new("vendor").about(
+        "Copy remote dependencies and template pointers into .specman/vendor for offline use",
+    )
+}
+
+pub fn run(session: &CliSession, _matches: &ArgMatches) -> Result<CommandResult, CliError> {
+    let report = specman::vendor_workspace(&session.workspace_paths).map_err(CliError::from)?;
+    Ok(CommandResult::Vendored { report })
+}
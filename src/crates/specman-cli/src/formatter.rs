@@ -2,13 +2,19 @@ use std::collections::BTreeMap;
 use std::process::ExitCode;
 
 use serde_json::json;
-use specman::{ArtifactKind, ArtifactSummary, DependencyEdge, DependencyTree, TemplateLocator};
+use specman::{
+    ArtifactKind, ArtifactSummary, DependencyEdge, DependencyTree, RenamePlan, TemplateLocator,
+    TemplateProvenance,
+};
 
 use crate::commands::CommandResult;
 use crate::commands::dependencies::{self, DependencyScope, DependencyView};
+use crate::commands::show::BacklinkSource;
 use crate::commands::templates::PointerAction;
 use crate::error::CliError;
+use crate::highlight::highlight_markdown;
 
+#[derive(Clone, Copy)]
 pub enum OutputFormat {
     Text,
     Json,
@@ -45,8 +51,19 @@ fn print_text(result: &CommandResult) {
 
             if !report.cycle_errors.is_empty() {
                 println!("\nCycle Errors:");
-                for error in &report.cycle_errors {
-                    println!("  - {}", error);
+                for cycle in &report.cycle_errors {
+                    println!("  - {}", cycle);
+                    for edge in &cycle.edges {
+                        let location = match (&edge.path, edge.line) {
+                            (Some(path), Some(line)) => format!(" at {path}:{line}"),
+                            (Some(path), None) => format!(" in {path}"),
+                            _ => String::new(),
+                        };
+                        println!(
+                            "      {} `{}` entry `{}`{location}",
+                            edge.from.name, edge.field, edge.reference
+                        );
+                    }
                 }
             }
 
@@ -69,6 +86,12 @@ fn print_text(result: &CommandResult) {
                         "    [{:?}] {}{}",
                         issue.severity, issue.message, loc
                     );
+                    if let Some(suggestion) = &issue.suggestion {
+                        println!(
+                            "      Suggestion: `{}` ({})",
+                            suggestion.destination, suggestion.reason
+                        );
+                    }
                 }
                 for missing in &status.compliance_missing {
                     println!("    [Compliance] Missing: {}", missing);
@@ -76,6 +99,29 @@ fn print_text(result: &CommandResult) {
                 if let Some(scan_root) = &status.compliance_scan_root {
                     println!("    [Compliance] Scan root: {}", scan_root);
                 }
+                if let Some(metrics) = &status.compliance_metrics {
+                    let waived = if metrics.waived_groups > 0 {
+                        format!(", {} waived", metrics.waived_groups)
+                    } else {
+                        String::new()
+                    };
+                    println!(
+                        "    [Compliance] Coverage: {:.1}% ({}/{} constraint groups{})",
+                        metrics.coverage * 100.0,
+                        metrics.covered_groups,
+                        metrics.total_groups,
+                        waived
+                    );
+                }
+                for waiver in &status.compliance_waived {
+                    println!(
+                        "    [Compliance] Waived: {} ({})",
+                        waiver.constraint, waiver.reason
+                    );
+                }
+                for error in &status.compliance_errors {
+                    println!("    [Compliance] {}", error);
+                }
                 for orphan in &status.compliance_orphans {
                      println!(
                         "    [Compliance] Orphan tag: {} at {}:{}",
@@ -84,8 +130,27 @@ fn print_text(result: &CommandResult) {
                         orphan.location.line_number
                     );
                 }
+                for issue in &status.lint_issues {
+                    let loc = issue
+                        .line
+                        .map(|line| format!(":{line}"))
+                        .unwrap_or_default();
+                    println!(
+                        "    [Lint] {}[{}] {}{}",
+                        issue.level, issue.rule, issue.message, loc
+                    );
+                }
             }
         }
+        CommandResult::StatusDiff(diff) => print_status_diff(diff),
+        // SARIF, JUnit, and graph consumers expect the document itself, so it is never wrapped or
+        // summarized.
+        CommandResult::Sarif(log) => match serde_json::to_string_pretty(log) {
+            Ok(rendered) => println!("{rendered}"),
+            Err(err) => eprintln!("failed to render SARIF log: {err}"),
+        },
+        CommandResult::Junit { xml, .. } => print!("{xml}"),
+        CommandResult::Graph { document, .. } => print!("{document}"),
         CommandResult::WorkspaceInitialized {
             root,
             dot_specman,
@@ -145,6 +210,157 @@ fn print_text(result: &CommandResult) {
             }
             print_dependency_tree(tree);
         }
+        CommandResult::SpecRenamed { plan, dry_run } => {
+            print_rename_plan("specification", plan, *dry_run);
+        }
+        CommandResult::SpecBumped { result, dry_run } => {
+            let verb = if *dry_run {
+                "Dry run: would bump"
+            } else {
+                "Bumped"
+            };
+            println!(
+                "{verb} specification '{}' from {} to {}",
+                result.artifact.name, result.previous, result.version
+            );
+            if result.broken.is_empty() {
+                println!("All dependent version requirements are still satisfied.");
+            } else {
+                println!("Dependents whose version requirement is no longer met:");
+                for mismatch in &result.broken {
+                    println!(
+                        "  - {} (requires `{}`)",
+                        mismatch.dependent, mismatch.requirement
+                    );
+                }
+            }
+        }
+        CommandResult::SpecDiff { diff } => {
+            println!("Specification diff: {} -> {}", diff.old, diff.new);
+            if diff.is_empty() {
+                println!("No constraint or heading changes.");
+                return;
+            }
+            println!("Constraint groups ({}):", diff.constraints.len());
+            for change in &diff.constraints {
+                let moved = change
+                    .previous_heading
+                    .as_ref()
+                    .map(|previous| format!(", moved from #{previous}"))
+                    .unwrap_or_default();
+                println!(
+                    "  {} {} (#{}{moved})",
+                    change_marker(change.change),
+                    change.group,
+                    change.heading
+                );
+            }
+            println!("Headings ({}):", diff.headings.len());
+            for change in &diff.headings {
+                println!(
+                    "  {} #{} {}",
+                    change_marker(change.change),
+                    change.slug,
+                    change.title
+                );
+            }
+        }
+        CommandResult::SpecImpact { impact } => {
+            println!(
+                "Impact of {} -> {} on '{}':",
+                impact.diff.old, impact.diff.new, impact.specification.name
+            );
+            if impact.diff.constraints.is_empty() {
+                println!("No constraint groups changed.");
+            } else {
+                println!("Changed constraint groups ({}):", impact.diff.constraints.len());
+                for change in &impact.diff.constraints {
+                    println!("  {} {}", change_marker(change.change), change.group);
+                }
+            }
+            println!("Implementations ({}):", impact.implementations.len());
+            for implementation in &impact.implementations {
+                if implementation.is_direct() {
+                    println!("  - {} (direct)", implementation.implementation.name);
+                } else {
+                    let via: Vec<&str> = implementation
+                        .via
+                        .iter()
+                        .map(|id| id.name.as_str())
+                        .collect();
+                    println!(
+                        "  - {} (via {})",
+                        implementation.implementation.name,
+                        via.join(" -> ")
+                    );
+                }
+                if let Some(error) = &implementation.error {
+                    println!("      error: {error}");
+                }
+                for (group, tags) in &implementation.tags {
+                    for tag in tags {
+                        println!(
+                            "      {group} at {}:{}",
+                            tag.location.file_path.display(),
+                            tag.location.line_number
+                        );
+                    }
+                }
+            }
+        }
+        CommandResult::Plan { plan } => {
+            println!("Implementation plan ({} specifications):", plan.steps.len());
+            for (position, step) in plan.steps.iter().enumerate() {
+                let state = if step.implemented {
+                    "implemented"
+                } else if step.upstream_implemented {
+                    "ready"
+                } else {
+                    "blocked"
+                };
+                println!(
+                    "  {}. {} [{state}]",
+                    position + 1,
+                    step.specification.name
+                );
+                if !step.depends_on.is_empty() {
+                    let names: Vec<&str> =
+                        step.depends_on.iter().map(|id| id.name.as_str()).collect();
+                    println!("      depends on: {}", names.join(", "));
+                }
+                if step.implementations.is_empty() {
+                    println!("      no implementations");
+                }
+                for implementation in &step.implementations {
+                    match (&implementation.error, implementation.coverage) {
+                        (Some(error), _) => println!(
+                            "      - {}: {error}",
+                            implementation.implementation.name
+                        ),
+                        (None, coverage) => println!(
+                            "      - {}: {:.1}% ({}/{} constraint groups)",
+                            implementation.implementation.name,
+                            coverage.unwrap_or_default() * 100.0,
+                            implementation.covered_groups,
+                            implementation.total_groups
+                        ),
+                    }
+                }
+            }
+            let next: Vec<&str> = plan
+                .next()
+                .map(|step| step.specification.name.as_str())
+                .collect();
+            if next.is_empty() {
+                println!("Next: nothing ready to implement");
+            } else {
+                println!("Next: {}", next.join(", "));
+            }
+            if !plan.unordered.is_empty() {
+                let names: Vec<&str> = plan.unordered.iter().map(|id| id.name.as_str()).collect();
+                println!("Unordered (dependency cycle): {}", names.join(", "));
+            }
+        }
         CommandResult::ImplList { implementations } => {
             println!("Implementations ({}):", implementations.len());
             for imp in implementations {
@@ -182,6 +398,9 @@ fn print_text(result: &CommandResult) {
             }
             print_dependency_tree(tree);
         }
+        CommandResult::ImplRenamed { plan, dry_run } => {
+            print_rename_plan("implementation", plan, *dry_run);
+        }
         CommandResult::ScratchList {
             pads,
             missing_metadata,
@@ -259,15 +478,341 @@ fn print_text(result: &CommandResult) {
                 println!("  Last-Modified: {last_modified}");
             }
         }
+        CommandResult::TemplateList { templates } => {
+            println!("Templates ({}):", templates.len());
+            for listing in templates {
+                match (&listing.provenance, &listing.error) {
+                    (Some(provenance), _) => println!(
+                        "  - {}: {}",
+                        listing.template,
+                        describe_provenance(provenance)
+                    ),
+                    (None, Some(error)) => {
+                        println!("  - {}: unresolved ({error})", listing.template)
+                    }
+                    (None, None) => println!("  - {}", listing.template),
+                }
+            }
+        }
+        CommandResult::TemplateShown { body, .. } => print!("{body}"),
+        CommandResult::TemplateTokens {
+            template,
+            provenance,
+            tokens,
+        } => {
+            println!(
+                "{} token(s) in {template} template ({}):",
+                tokens.len(),
+                describe_provenance(provenance)
+            );
+            for token in tokens {
+                let lines = token
+                    .lines
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let noun = if token.lines.len() == 1 {
+                    "line"
+                } else {
+                    "lines"
+                };
+                let note = if token.provided {
+                    ""
+                } else {
+                    " [not provided]"
+                };
+                println!("  - {} ({noun} {lines}){note}", token.name);
+            }
+        }
+        CommandResult::TemplateValidated {
+            reports,
+            unresolved,
+        } => {
+            for report in reports {
+                let label = if report.is_valid() { "OK" } else { "FAIL" };
+                println!(
+                    "[{label}] {} ({})",
+                    report.template,
+                    describe_provenance(&report.provenance)
+                );
+                for error in &report.errors {
+                    println!("    {error}");
+                }
+            }
+            for listing in unresolved {
+                println!("[FAIL] {} (unresolved)", listing.template);
+                if let Some(error) = &listing.error {
+                    println!("    {error}");
+                }
+            }
+            let failed =
+                reports.iter().filter(|report| !report.is_valid()).count() + unresolved.len();
+            println!(
+                "{} template(s) validated: {failed} failed",
+                reports.len() + unresolved.len()
+            );
+        }
+        CommandResult::DepsLocked { report, updated } => {
+            let verb = if *updated { "Updated" } else { "Locked" };
+            let total = report.added.len() + report.changed.len() + report.unchanged.len();
+            println!("{verb} {total} remote dependencies in {}", report.lock_file);
+            for (marker, urls) in [
+                ("+", &report.added),
+                ("~", &report.changed),
+                ("-", &report.removed),
+            ] {
+                for url in urls {
+                    println!("  {marker} {url}");
+                }
+            }
+        }
+        CommandResult::DepsVerified { verification } => {
+            if verification.is_clean() {
+                println!(
+                    "{}: {} remote dependencies verified",
+                    verification.lock_file,
+                    verification.verified.len()
+                );
+            } else {
+                println!(
+                    "{}: {} issue(s), {} verified",
+                    verification.lock_file,
+                    verification.issues.len(),
+                    verification.verified.len()
+                );
+                for issue in &verification.issues {
+                    println!("  [{:?}] {}", issue.kind, issue.message);
+                }
+            }
+        }
+        CommandResult::Vendored { report } => {
+            println!(
+                "Vendored {} remote dependencies and {} template(s) into {} (network policy: {})",
+                report.dependencies.len(),
+                report.templates.len(),
+                report.vendor_dir,
+                report.policy
+            );
+            for url in report.dependencies.iter().chain(&report.templates) {
+                println!("  {url}");
+            }
+        }
+        CommandResult::Lint { report } => {
+            for issue in &report.issues {
+                println!("{issue}");
+            }
+            println!(
+                "{} specification(s) linted: {} error(s), {} warning(s)",
+                report.documents,
+                report.errors(),
+                report.warnings()
+            );
+        }
+        CommandResult::ReferencesFixed { report } => {
+            let verb = if report.dry_run { "Would fix" } else { "Fixed" };
+            println!("{verb} {} reference(s)", report.fixes.len());
+            for fix in &report.fixes {
+                println!(
+                    "  {}:{} `{}` -> `{}` ({})",
+                    fix.document, fix.line, fix.before, fix.after, fix.reason
+                );
+            }
+            if !report.unresolved.is_empty() {
+                println!("{} broken reference(s) left unresolved", report.unresolved.len());
+                for issue in &report.unresolved {
+                    let loc = issue
+                        .source
+                        .range
+                        .as_ref()
+                        .map(|r| format!(":{}", r.start.line))
+                        .unwrap_or_default();
+                    println!("  {}{loc} {}", issue.source.document, issue.message);
+                }
+            }
+        }
+        CommandResult::Search { query, hits } => {
+            if hits.is_empty() {
+                println!("No results for \"{query}\"");
+                return;
+            }
+            println!("{} result(s) for \"{query}\"", hits.len());
+            for hit in hits {
+                let target = match hit.target {
+                    specman::SearchTarget::Heading => "heading",
+                    specman::SearchTarget::Constraint => "constraint",
+                };
+                println!("  [{target}] {} ({:.2})", hit.identifier, hit.score);
+                if !hit.snippet.is_empty() {
+                    println!("      {}", hit.snippet);
+                }
+            }
+        }
+        CommandResult::Query { expression, table } => {
+            if table.rows.is_empty() {
+                println!("No rows for \"{expression}\"");
+                return;
+            }
+            let cells: Vec<Vec<String>> = table
+                .rows
+                .iter()
+                .map(|row| row.iter().map(ToString::to_string).collect())
+                .collect();
+            let widths: Vec<usize> = table
+                .columns
+                .iter()
+                .enumerate()
+                .map(|(column, name)| {
+                    cells
+                        .iter()
+                        .map(|row| row[column].chars().count())
+                        .fold(name.len(), usize::max)
+                })
+                .collect();
+            let print_row = |values: &[String]| {
+                let line: Vec<String> = values
+                    .iter()
+                    .zip(&widths)
+                    .map(|(value, width)| format!("{value:<width$}"))
+                    .collect();
+                println!("{}", line.join("  ").trim_end());
+            };
+            print_row(&table.columns);
+            print_row(
+                &widths
+                    .iter()
+                    .map(|width| "-".repeat(*width))
+                    .collect::<Vec<_>>(),
+            );
+            for row in &cells {
+                print_row(row);
+            }
+            println!("{} row(s)", cells.len());
+        }
+        CommandResult::Shown {
+            markdown,
+            highlight,
+            ..
+        } => {
+            if *highlight {
+                print!("{}", highlight_markdown(markdown));
+            } else {
+                print!("{markdown}");
+            }
+        }
+        CommandResult::Backlinks { target, backlinks } => {
+            if backlinks.is_empty() {
+                println!("No backlinks to {target}");
+                return;
+            }
+            println!("{} backlink(s) to {target}", backlinks.len());
+            for link in backlinks {
+                let source = match link.source {
+                    BacklinkSource::Heading => "heading",
+                    BacklinkSource::Constraint => "constraint",
+                    BacklinkSource::Implementation => "implementation",
+                    BacklinkSource::ScratchPad => "scratch",
+                };
+                println!("  [{source}] {} -> {}", link.from, link.to);
+            }
+        }
     }
 }
 
 fn print_json(result: &CommandResult) -> Result<(), CliError> {
+    match result {
+        CommandResult::Sarif(log) => {
+            println!("{}", json!(log));
+            return Ok(());
+        }
+        CommandResult::Junit { xml, .. } => {
+            print!("{xml}");
+            return Ok(());
+        }
+        CommandResult::Graph { document, .. } => {
+            print!("{document}");
+            return Ok(());
+        }
+        _ => {}
+    }
     let payload = json!(result);
     println!("{payload}");
     Ok(())
 }
 
+fn print_status_diff(diff: &specman::StatusDiff) {
+    let label = |status: specman::StatusResult| match status {
+        specman::StatusResult::Pass => "PASS",
+        specman::StatusResult::Fail => "FAIL",
+    };
+    let noun = if diff.rechecked.len() == 1 {
+        "artifact"
+    } else {
+        "artifacts"
+    };
+    if diff.previous_status == diff.global_status {
+        println!(
+            "Global Status: {} (rechecked {} {noun})",
+            label(diff.global_status),
+            diff.rechecked.len()
+        );
+    } else {
+        println!(
+            "Global Status: {} -> {} (rechecked {} {noun})",
+            label(diff.previous_status),
+            label(diff.global_status),
+            diff.rechecked.len()
+        );
+    }
+
+    for (marker, issues) in [("+", &diff.introduced), ("-", &diff.resolved)] {
+        for issue in issues {
+            let subject = issue
+                .artifact
+                .as_ref()
+                .map(|id| format!("{} ({:?})", id.name, id.kind))
+                .unwrap_or_else(|| "workspace".to_string());
+            println!(
+                "  {marker} [{:?}] {subject}: {}",
+                issue.check, issue.message
+            );
+        }
+    }
+}
+
+fn change_marker(change: specman::ChangeKind) -> &'static str {
+    match change {
+        specman::ChangeKind::Added => "+",
+        specman::ChangeKind::Removed => "-",
+        specman::ChangeKind::Modified => "~",
+    }
+}
+
+fn print_rename_plan(label: &str, plan: &RenamePlan, dry_run: bool) {
+    if dry_run {
+        println!(
+            "Dry run: would rename {label} '{}' to '{}' ({})",
+            plan.from.name,
+            plan.to.name,
+            plan.destination.display()
+        );
+    } else {
+        println!(
+            "Renamed {label} '{}' to '{}' ({})",
+            plan.from.name,
+            plan.to.name,
+            plan.destination.display()
+        );
+    }
+    println!("  Reference rewrites: {}", plan.rewrites.len());
+    for rewrite in &plan.rewrites {
+        println!(
+            "    - {} [{}]: {} -> {}",
+            rewrite.artifact.name, rewrite.field, rewrite.before, rewrite.after
+        );
+    }
+}
+
 fn print_dependency_tree(tree: &DependencyTree) {
     render_direction_section(
         "Downstream",
@@ -412,6 +957,15 @@ fn capitalize(input: &str) -> String {
     }
 }
 
+/// `Tier locator`, plus the pointer file or declaration the locator came from.
+fn describe_provenance(provenance: &TemplateProvenance) -> String {
+    let mut described = format!("{:?} {}", provenance.tier, provenance.locator);
+    if let Some(pointer) = &provenance.pointer {
+        described.push_str(&format!(" via {pointer}"));
+    }
+    described
+}
+
 fn describe_template_locator(locator: &TemplateLocator) -> String {
     match locator {
         TemplateLocator::FilePath(path) => path.display().to_string(),
@@ -0,0 +1,110 @@
+//! ANSI highlighting for markdown rendered to a terminal.
+
+const RESET: &str = "\x1b[0m";
+const HEADING: &str = "\x1b[1;36m";
+const CONSTRAINT: &str = "\x1b[1;33m";
+const KEYWORD: &str = "\x1b[1;31m";
+const CODE: &str = "\x1b[32m";
+const LINK: &str = "\x1b[4;34m";
+
+const RFC_2119_KEYWORDS: &[&str] = &[
+    "MUST",
+    "NOT",
+    "REQUIRED",
+    "SHALL",
+    "SHOULD",
+    "RECOMMENDED",
+    "MAY",
+    "OPTIONAL",
+];
+
+/// Colors headings, constraint group identifiers, RFC 2119 keywords, code, and links.
+pub fn highlight_markdown(markdown: &str) -> String {
+    let mut out = String::with_capacity(markdown.len() * 2);
+    let mut in_fence = false;
+    for line in markdown.split_inclusive('\n') {
+        let (body, newline) = match line.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (line, ""),
+        };
+        let trimmed = body.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            paint(&mut out, CODE, body);
+        } else if in_fence {
+            paint(&mut out, CODE, body);
+        } else if is_heading(trimmed) {
+            paint(&mut out, HEADING, body);
+        } else if trimmed.starts_with('!') && trimmed.ends_with(':') {
+            paint(&mut out, CONSTRAINT, body);
+        } else {
+            highlight_inline(&mut out, body);
+        }
+        out.push_str(newline);
+    }
+    out
+}
+
+fn is_heading(line: &str) -> bool {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    (1..=6).contains(&level) && line[level..].starts_with(' ')
+}
+
+fn highlight_inline(out: &mut String, line: &str) {
+    let mut rest = line;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('`')
+            && let Some(end) = after.find('`')
+        {
+            paint(out, CODE, &rest[..end + 2]);
+            rest = &rest[end + 2..];
+            continue;
+        }
+        if rest.starts_with('[')
+            && let Some(close) = rest.find("](")
+            && let Some(end) = rest[close..].find(')')
+        {
+            let len = close + end + 1;
+            paint(out, LINK, &rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+
+        let word_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        if word_len > 0 {
+            let word = &rest[..word_len];
+            if RFC_2119_KEYWORDS.contains(&word) {
+                paint(out, KEYWORD, word);
+            } else {
+                out.push_str(word);
+            }
+            rest = &rest[word_len..];
+        } else if let Some(ch) = rest.chars().next() {
+            out.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+}
+
+fn paint(out: &mut String, style: &str, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    out.push_str(style);
+    out.push_str(text);
+    out.push_str(RESET);
+}
+
+#[cfg(test)]
+#[test]
+fn highlights_structure_and_keywords() {
+    let rendered = highlight_markdown(
+        "## Concept: Roots\n\n!concept-roots.walk:\n- Clients MUST call `walk()` per [root](#roots).\n",
+    );
+    assert_eq!(
+        rendered,
+        "\x1b[1;36m## Concept: Roots\x1b[0m\n\n\x1b[1;33m!concept-roots.walk:\x1b[0m\n- Clients \x1b[1;31mMUST\x1b[0m call \x1b[32m`walk()`\x1b[0m per \x1b[4;34m[root](#roots)\x1b[0m.\n"
+    );
+}
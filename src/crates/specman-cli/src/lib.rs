@@ -3,6 +3,7 @@ mod commands;
 mod context;
 mod error;
 mod formatter;
+mod highlight;
 mod templates;
 mod util;
 
@@ -40,6 +40,14 @@ impl TemplateCatalog {
             .remove_pointer(kind.scenario())
             .map_err(CliError::from)
     }
+
+    pub fn resolve(&self, scenario: TemplateScenario) -> Result<ResolvedTemplate, CliError> {
+        self.catalog.resolve(scenario).map_err(CliError::from)
+    }
+
+    pub fn scenarios(&self) -> Result<Vec<TemplateScenario>, CliError> {
+        self.catalog.scenarios().map_err(CliError::from)
+    }
 }
 
 impl TemplateKind {
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn spec_bump_major_lists_broken_dependents() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "bump", "core", "--major"]);
+
+    cmd.assert()
+        .success()
+        .stdout(contains("Bumped specification 'core' from 3.2.1 to 4.0.0"))
+        .stdout(contains("Specification://api (requires `^3`)"));
+
+    let core = fs::read_to_string(root.join("spec/core/spec.md"))?;
+    assert!(core.contains("version: 4.0.0"), "{core}");
+    Ok(())
+}
+
+#[test]
+fn spec_bump_dry_run_leaves_the_spec_untouched() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+    let before = fs::read_to_string(root.join("spec/core/spec.md"))?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "bump", "core", "--major", "--dry-run"]);
+    cmd.assert()
+        .success()
+        .stdout(contains(
+            "Dry run: would bump specification 'core' from 3.2.1 to 4.0.0",
+        ))
+        .stdout(contains("Specification://api (requires `^3`)"));
+
+    assert_eq!(fs::read_to_string(root.join("spec/core/spec.md"))?, before);
+    Ok(())
+}
+
+#[test]
+fn spec_bump_requires_a_single_level() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "bump", "core", "--major", "--minor"]);
+    cmd.assert().failure();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "bump", "core", "--patch"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("from 3.2.1 to 3.2.2"))
+        .stdout(contains(
+            "All dependent version requirements are still satisfied.",
+        ));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".specman"))?;
+
+    write_file(
+        root.join("spec/core/spec.md"),
+        r#"---
+name: core
+version: "3.2.1"
+---
+# Core
+"#,
+    )?;
+
+    write_file(
+        root.join("spec/api/spec.md"),
+        r#"---
+name: api
+version: "1.0.0"
+dependencies:
+  - ref: ../core/spec.md
+    version: "^3"
+---
+# API
+"#,
+    )?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
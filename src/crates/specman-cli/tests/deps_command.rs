@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+
+const REMOTE: &str = "https://deps.invalid/specs/base.md";
+const REMOTE_BODY: &str = "---\nname: base\nversion: \"2.0.0\"\n---\n# Base\n";
+
+#[test]
+fn locked_dependencies_resolve_without_network() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_locked_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["graph", "--root", "spec://local"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("[label=\"base 2.0.0\""));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["deps", "verify"]);
+    cmd.assert().success().stdout(contains(
+        ".specman/deps.lock: 1 remote dependencies verified",
+    ));
+
+    // Every entry is already pinned, so locking again never reaches the unroutable host.
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["deps", "lock"]);
+    cmd.assert().success().stdout(contains(
+        "Locked 1 remote dependencies in .specman/deps.lock",
+    ));
+    Ok(())
+}
+
+#[test]
+fn deps_verify_flags_tampered_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_locked_workspace()?;
+    let root = workspace.path();
+    fs::write(root.join(cached_path()), "# Tampered\n")?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["deps", "verify"]);
+    cmd.assert()
+        .failure()
+        .stdout(contains("1 issue(s), 0 verified"))
+        .stdout(contains(
+            "[HashMismatch] locked copy of https://deps.invalid/specs/base.md",
+        ));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn sha256() -> String {
+    hex::encode(Sha256::digest(REMOTE_BODY.as_bytes()))
+}
+
+fn cached_path() -> String {
+    format!(".specman/cache/deps/{}.md", sha256())
+}
+
+fn scaffold_locked_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+
+    write_file(
+        root.join("spec/local/spec.md"),
+        &format!(
+            "---\nname: local\nversion: \"1.0.0\"\ndependencies:\n  - {REMOTE}\n---\n# Local\n"
+        ),
+    )?;
+    write_file(root.join(cached_path()), REMOTE_BODY)?;
+    write_file(
+        root.join(".specman/deps.lock"),
+        &format!(
+            "{{\n  \"version\": 1,\n  \"dependencies\": [\n    {{\n      \"url\": \"{REMOTE}\",\n      \"sha256\": \"{}\",\n      \"fetched_at\": \"2024-03-01T12:00:00Z\",\n      \"cached\": \"{}\"\n    }}\n  ]\n}}\n",
+            sha256(),
+            cached_path()
+        ),
+    )?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn spec_diff_reports_changed_constraint_groups() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = TempDir::new()?;
+    let root = workspace.path();
+    fs::create_dir_all(root.join(".specman"))?;
+
+    write_file(
+        root.join("old/spec.md"),
+        r#"---
+name: core
+version: "1.0.0"
+---
+# Core
+
+## Concept: Alpha
+
+!concept-alpha.one:
+- MUST exist.
+
+!concept-alpha.two:
+- MUST be fast.
+"#,
+    )?;
+    write_file(
+        root.join("spec/core/spec.md"),
+        r#"---
+name: core
+version: "1.1.0"
+---
+# Core
+
+## Concept: Alpha
+
+!concept-alpha.one:
+- MUST exist.
+
+!concept-alpha.two:
+- MUST be faster.
+
+!concept-alpha.three:
+- SHOULD log.
+"#,
+    )?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "diff", "core", "--old"])
+        .arg(root.join("old/spec.md"));
+
+    cmd.assert()
+        .success()
+        .stdout(contains("Constraint groups (2):"))
+        .stdout(contains("+ concept-alpha.three (#concept-alpha)"))
+        .stdout(contains("~ concept-alpha.two (#concept-alpha)"))
+        .stdout(contains("concept-alpha.one").not());
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
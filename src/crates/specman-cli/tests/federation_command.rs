@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn graph_and_status_traverse_into_peer_workspaces() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_federation("^1.0")?;
+    let root = temp.path().join("payments");
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(&root)
+        .args(["graph", "--root", "spec://billing"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("[label=\"platform:auth 1.2.0\""))
+        .stdout(contains("\"spec://billing\" -> \"spec://platform:auth\""));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(&root).args([
+        "--json",
+        "spec",
+        "dependencies",
+        "billing",
+        "--upstream",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(contains("\"workspace\":\"platform\""));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(&root).arg("status");
+    cmd.assert()
+        .success()
+        .stdout(contains("Global Status: PASS"));
+    Ok(())
+}
+
+#[test]
+fn status_checks_requirements_and_links_against_peers() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_federation("^2.0")?;
+    let root = temp.path().join("payments");
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(&root).arg("status");
+    cmd.assert().failure().stdout(contains(
+        "Version requirement not met: Specification://billing requires Specification://platform:auth `^2.0`, found 1.2.0",
+    ));
+
+    // Peers must be declared; without the federation file the handle no longer resolves.
+    fs::remove_file(root.join(".specman/workspaces.yaml"))?;
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(&root).arg("status");
+    cmd.assert()
+        .failure()
+        .stdout(contains("unknown peer workspace `platform`"));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_federation(requirement: &str) -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let payments = temp.path().join("payments");
+    let platform = temp.path().join("platform");
+    fs::create_dir_all(payments.join(".specman"))?;
+    fs::create_dir_all(platform.join(".specman"))?;
+
+    write_file(
+        payments.join(".specman/workspaces.yaml"),
+        "workspaces:\n  platform: ../platform\n",
+    )?;
+    write_file(
+        payments.join("spec/billing/spec.md"),
+        &format!(
+            "---\nname: billing\nversion: \"1.0.0\"\ndependencies:\n  - ref: spec://platform:auth\n    version: \"{requirement}\"\n---\n# Billing\n\n## Overview\n\nSee [auth](../../../platform/spec/auth/spec.md#overview).\n"
+        ),
+    )?;
+    write_file(
+        platform.join("spec/auth/spec.md"),
+        "---\nname: auth\nversion: \"1.2.0\"\n---\n# Auth\n\n## Overview\n\nAuth details.\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
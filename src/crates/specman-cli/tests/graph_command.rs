@@ -0,0 +1,86 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn graph_exports_workspace_inventory_as_dot() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).arg("graph");
+
+    cmd.assert()
+        .success()
+        .stdout(contains("digraph specman {"))
+        .stdout(contains("\"spec://core\" [label=\"core 1.0.0\", shape=box"))
+        .stdout(contains("\"impl://lib\" [label=\"lib 1.0.0\", shape=component"))
+        .stdout(contains(
+            "\"spec://api\" -> \"spec://core\" [color=black, style=solid];",
+        ))
+        .stdout(contains("\"impl://lib\" -> \"spec://api\""));
+    Ok(())
+}
+
+#[test]
+fn graph_root_limits_export_to_one_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["graph", "--format", "mermaid", "--root", "spec://core"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("flowchart LR"))
+        .stdout(contains("[\"api 1.0.0\"]:::specification"))
+        .stdout(contains("lib").not());
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["graph", "--format", "graphml"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("<graphml"))
+        .stdout(contains("<node id=\"impl://lib\">"));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/core/spec.md"),
+        "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n",
+    )?;
+    write_file(
+        root.join("spec/api/spec.md"),
+        "---\nname: api\nversion: \"1.0.0\"\ndependencies:\n  - ../core/spec.md\n---\n# API\n",
+    )?;
+    write_file(
+        root.join("impl/lib/impl.md"),
+        "---\nspec: spec://api\nname: lib\nversion: 1.0.0\nlocation: ../../src\n---\n# Lib\n",
+    )?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
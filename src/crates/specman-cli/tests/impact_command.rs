@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command as Process;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const CORE_V1: &str = r#"---
+name: core
+version: "1.0.0"
+---
+# Core
+
+## Concept: Alpha
+
+!concept-alpha.one:
+- MUST exist.
+
+!concept-alpha.two:
+- MUST be fast.
+"#;
+
+#[test]
+fn spec_impact_lists_direct_and_transitive_tag_locations() -> Result<(), Box<dyn std::error::Error>>
+{
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    git(root, &["init", "-q"])?;
+    git(root, &["add", "-A"])?;
+    git(root, &["commit", "-q", "-m", "init"])?;
+    fs::write(
+        root.join("spec/core/spec.md"),
+        CORE_V1.replace("MUST be fast.", "MUST be faster."),
+    )?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "impact", "core"]);
+
+    cmd.assert()
+        .success()
+        .stdout(contains("Changed constraint groups (1):"))
+        .stdout(contains("~ concept-alpha.two"))
+        .stdout(contains("Implementations (2):"))
+        .stdout(contains("  - core-rs (direct)"))
+        .stdout(contains("concept-alpha.two at src/core/lib.rs:2"))
+        .stdout(contains("  - api-rs (via api)"))
+        .stdout(contains("concept-alpha.two at src/api/lib.rs:1"))
+        .stdout(contains("concept-alpha.one at").not());
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn git(root: &Path, args: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
+    let status = Process::new("git")
+        .args(["-c", "user.name=specman", "-c", "user.email=specman@example.com"])
+        .args(args)
+        .current_dir(root)
+        .status()?;
+    assert!(status.success(), "git {args:?} failed");
+    Ok(())
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(root.join("spec/core/spec.md"), CORE_V1)?;
+    write_file(
+        root.join("spec/api/spec.md"),
+        r#"---
+name: api
+version: "1.0.0"
+dependencies:
+  - ../core/spec.md
+---
+# API
+"#,
+    )?;
+    write_file(
+        root.join("impl/core-rs/impl.md"),
+        "---\nspec: spec://core\nname: core-rs\nversion: 1.0.0\nlocation: ../../src/core\n---\n# Core\n",
+    )?;
+    write_file(
+        root.join("impl/api-rs/impl.md"),
+        "---\nspec: spec://api\nname: api-rs\nversion: 1.0.0\nlocation: ../../src/api\n---\n# API\n",
+    )?;
+    write_file(
+        root.join("src/core/lib.rs"),
+        "// [ENSURES: concept-alpha.one]\n// [ENSURES: concept-alpha.two]\n",
+    )?;
+    write_file(
+        root.join("src/api/lib.rs"),
+        "// [ENSURES: concept-alpha.two]\n",
+    )?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn lint_reports_warnings_until_configured_as_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).arg("lint");
+    cmd.assert()
+        .success()
+        .stdout(contains(
+            "spec/core/spec.md:9: warning[rfc2119-keywords] constraint group `concept-roots.rules`",
+        ))
+        .stdout(contains("spec/core/spec.md:12: warning[no-handles-in-body]"))
+        .stdout(contains("1 specification(s) linted: 0 error(s), 2 warning(s)"));
+
+    write_file(
+        root.join(".specman/lint.yaml"),
+        "rules:\n  rfc2119-keywords: error\n  no-handles-in-body: off\n",
+    )?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["--json", "lint"]);
+    cmd.assert()
+        .failure()
+        .code(65)
+        .stdout(contains("\"rule\":\"rfc2119-keywords\""))
+        .stdout(contains("\"level\":\"error\""))
+        .stdout(contains("no-handles-in-body").not());
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["status", "--local"]);
+    cmd.assert()
+        .failure()
+        .code(65)
+        .stdout(contains("[FAIL] core (Specification)"))
+        .stdout(contains(
+            "[Lint] error[rfc2119-keywords] constraint group `concept-roots.rules` contains no RFC 2119 keyword:9",
+        ));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/core/spec.md"),
+        "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Roots\n\n!concept-roots.rules:\n- Roots are directories.\n\nUse spec://core when scripting.\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn plan_orders_specs_and_lists_ready_work() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).arg("plan");
+
+    cmd.assert()
+        .success()
+        .stdout(contains("Implementation plan (3 specifications):"))
+        .stdout(contains("1. core [implemented]"))
+        .stdout(contains("core-rs: 100.0% (1/1 constraint groups)"))
+        .stdout(contains("2. api [ready]"))
+        .stdout(contains("3. cli [blocked]"))
+        .stdout(contains("depends on: api"))
+        .stdout(contains("Next: api"));
+    Ok(())
+}
+
+#[test]
+fn plan_json_reports_steps_in_dependency_order() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    let output = cmd
+        .arg("--workspace")
+        .arg(root)
+        .args(["--json", "plan"])
+        .output()?;
+    assert!(output.status.success());
+
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let names: Vec<&str> = value["plan"]["steps"]
+        .as_array()
+        .expect("steps array")
+        .iter()
+        .filter_map(|step| step["specification"]["name"].as_str())
+        .collect();
+    assert_eq!(names, ["core", "api", "cli"]);
+    assert_eq!(value["plan"]["steps"][1]["upstream_implemented"], true);
+    assert_eq!(value["plan"]["steps"][2]["upstream_implemented"], false);
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/core/spec.md"),
+        "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Core\n\n!concept-core.one:\n- MUST exist.\n",
+    )?;
+    write_file(
+        root.join("spec/api/spec.md"),
+        "---\nname: api\nversion: \"1.0.0\"\ndependencies:\n  - ../core/spec.md\n---\n# API\n",
+    )?;
+    write_file(
+        root.join("spec/cli/spec.md"),
+        "---\nname: cli\nversion: \"1.0.0\"\ndependencies:\n  - ../api/spec.md\n---\n# CLI\n",
+    )?;
+    write_file(
+        root.join("impl/core-rs/impl.md"),
+        "---\nspec: spec://core\nname: core-rs\nversion: 1.0.0\nlocation: ../../src\n---\n# Core\n",
+    )?;
+    write_file(root.join("src/lib.rs"), "// [ENSURES: concept-core.one]\n")?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
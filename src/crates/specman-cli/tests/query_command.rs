@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn query_prints_tables_and_json() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args([
+        "query",
+        "constraints where under = concept-lifecycle-automation and external_references > 0",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(contains("identifier"))
+        .stdout(contains(
+            "spec/workflow/spec.md!concept-lifecycle-automation.hooks",
+        ))
+        .stdout(contains("concept-lifecycle-automation.local").not())
+        .stdout(contains("1 row(s)"));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args([
+        "--json",
+        "query",
+        "relationships where to_artifact ^= spec/specman-core and external = true select from_artifact",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(contains("\"columns\":[\"from_artifact\"]"))
+        .stdout(contains("\"rows\":[[\"spec/workflow/spec.md\"]]"));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["query", "headings where level = high"]);
+    cmd.assert()
+        .failure()
+        .code(64)
+        .stderr(contains("`level` is numeric"));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/specman-core/spec.md"),
+        "---\nname: specman-core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Artifacts\n\nArtifacts are markdown.\n",
+    )?;
+    write_file(
+        root.join("spec/workflow/spec.md"),
+        "---\nname: workflow\nversion: \"1.0.0\"\n---\n# Workflow\n\n## Concept: Lifecycle Automation\n\n!concept-lifecycle-automation.hooks:\n- MUST emit [artifacts](../specman-core/spec.md#concept-artifacts).\n\n!concept-lifecycle-automation.local:\n- MUST log each run.\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command as Process;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const API_SPEC: &str = "---\nname: api\nversion: \"1.0.0\"\n---\n# API\n\nFollows [the guide](../../docs/old-guide.md#install) and [roots](../core/spec.md#concept-rots).\n";
+
+#[test]
+fn references_fix_rewrites_fragments_and_renamed_files() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+    git(root, &["init", "-q"])?;
+    git(root, &["add", "."])?;
+    git(root, &["commit", "-q", "-m", "initial"])?;
+    git(root, &["mv", "docs/old-guide.md", "docs/guide.md"])?;
+    git(root, &["commit", "-q", "-m", "rename guide"])?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["status", "--local"]);
+    cmd.assert()
+        .failure()
+        .stdout(contains(
+            "Suggestion: `../../docs/guide.md#install` (`docs/old-guide.md` was renamed to `docs/guide.md`)",
+        ))
+        .stdout(contains(
+            "Suggestion: `../core/spec.md#concept-roots` (closest heading `concept-roots`, edit distance 1)",
+        ));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["references", "fix", "--dry-run"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("Would fix 2 reference(s)"))
+        .stdout(contains(
+            "spec/api/spec.md:7 `../../docs/old-guide.md#install` -> `../../docs/guide.md#install`",
+        ));
+    assert_eq!(fs::read_to_string(root.join("spec/api/spec.md"))?, API_SPEC);
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["references", "fix"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("Fixed 2 reference(s)"))
+        .stdout(contains("unresolved").not());
+    assert_eq!(
+        fs::read_to_string(root.join("spec/api/spec.md"))?,
+        API_SPEC
+            .replace("old-guide.md", "guide.md")
+            .replace("#concept-rots", "#concept-roots")
+    );
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["status", "--local"]);
+    cmd.assert().success();
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn git(root: &Path, args: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
+    let status = Process::new("git")
+        .args([
+            "-c",
+            "user.name=specman",
+            "-c",
+            "user.email=specman@example.com",
+        ])
+        .args(args)
+        .current_dir(root)
+        .status()?;
+    assert!(status.success(), "git {args:?} failed");
+    Ok(())
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/core/spec.md"),
+        "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Roots\n\nRoots are directories.\n",
+    )?;
+    write_file(root.join("spec/api/spec.md"), API_SPEC)?;
+    write_file(
+        root.join("docs/old-guide.md"),
+        "# Guide\n\n## Install\n\nRun it.\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
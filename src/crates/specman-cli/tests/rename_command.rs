@@ -0,0 +1,125 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn spec_rename_rewrites_dependents() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["spec", "rename", "core", "platform-core"]);
+
+    cmd.assert()
+        .success()
+        .stdout(contains("Renamed specification 'core' to 'platform-core'"))
+        .stdout(contains("Reference rewrites: 2"));
+
+    assert!(!root.join("spec/core").exists());
+    assert!(root.join("spec/platform-core/spec.md").is_file());
+    let api = fs::read_to_string(root.join("spec/api/spec.md"))?;
+    assert!(api.contains("../platform-core/spec.md"), "{api}");
+    let implementation = fs::read_to_string(root.join("impl/core-rs/impl.md"))?;
+    assert!(
+        implementation.contains("spec://platform-core"),
+        "{implementation}"
+    );
+    Ok(())
+}
+
+#[test]
+fn impl_rename_dry_run_leaves_workspace_untouched() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["impl", "rename", "core-rs", "core-rust", "--dry-run"]);
+
+    cmd.assert()
+        .success()
+        .stdout(contains(
+            "Dry run: would rename implementation 'core-rs' to 'core-rust'",
+        ))
+        .stdout(contains("notes [target]"));
+
+    assert!(root.join("impl/core-rs/impl.md").is_file());
+    assert!(!root.join("impl/core-rust").exists());
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".specman/scratchpad/notes"))?;
+    fs::create_dir_all(root.join("spec/core"))?;
+    fs::create_dir_all(root.join("spec/api"))?;
+    fs::create_dir_all(root.join("impl/core-rs"))?;
+
+    write_file(
+        root.join("spec/core/spec.md"),
+        r#"---
+name: core
+version: "1.0.0"
+dependencies: []
+---
+# Core
+"#,
+    )?;
+
+    write_file(
+        root.join("spec/api/spec.md"),
+        r#"---
+name: api
+version: "1.0.0"
+dependencies:
+  - ../core/spec.md
+---
+# API
+"#,
+    )?;
+
+    write_file(
+        root.join("impl/core-rs/impl.md"),
+        r#"---
+name: core-rs
+spec: spec://core
+location: src/core
+---
+# Core (Rust)
+"#,
+    )?;
+
+    write_file(
+        root.join(".specman/scratchpad/notes/scratch.md"),
+        r#"---
+name: notes
+target: impl/core-rs/impl.md
+work_type:
+  feat: {}
+---
+# Notes
+"#,
+    )?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn search_ranks_constraints_and_persists_index() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args([
+        "search",
+        "retry",
+        "--kind",
+        "spec",
+        "--constraints-only",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(contains("1 result(s) for \"retry\""))
+        .stdout(contains(
+            "[constraint] spec/payments/spec.md!payments.retry",
+        ))
+        .stdout(contains("MUST retry failed captures").and(contains("impl/").not()));
+    assert!(root.join(".specman/cache/index/search.v1.json").is_file());
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["--json", "search", "retry", "captures"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("\"target\":\"heading\""))
+        .stdout(contains(
+            "\"identifier\":\"impl/payments-impl/impl.md#retries\"",
+        ));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["search", "zebra"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("No results for \"zebra\""));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/payments/spec.md"),
+        "---\nname: payments\nversion: \"1.0.0\"\n---\n# Payments\n\n## Capture\n\n!payments.retry:\n- MUST retry failed captures with backoff\n",
+    )?;
+    write_file(
+        root.join("impl/payments-impl/impl.md"),
+        "---\nspec: spec://payments\nname: payments-impl\nversion: \"1.0.0\"\nlocation: src/payments\n---\n# Payments Impl\n\n## Retries\n\nCaptures retry three times before surfacing an error.\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn show_lists_backlinks_into_a_section() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args([
+        "show",
+        "spec://core#concept-workspace-discovery",
+        "--backlinks",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(contains("3 backlink(s) to spec/core/spec.md#concept-workspace-discovery"))
+        .stdout(contains(
+            "[constraint] spec/cli/spec.md!concept-cli.discovery -> spec/core/spec.md#concept-workspace-discovery",
+        ))
+        .stdout(contains(
+            "[heading] spec/cli/spec.md#overview -> spec/core/spec.md#roots",
+        ))
+        .stdout(contains(
+            "[implementation] impl/runner/impl.md#startup -> spec/core/spec.md#concept-workspace-discovery",
+        ))
+        .stdout(contains("[heading] spec/cli/spec.md#concept-cli").not());
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["--json", "show", "spec://core#roots", "--backlinks"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("\"type\":\"backlinks\""))
+        .stdout(contains("\"source\":\"heading\""));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["show", "spec://core#concept-workspace-discovery"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("## Concept: Workspace Discovery"))
+        .stdout(contains("### Roots"));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["show", "spec://core#missing", "--backlinks"]);
+    cmd.assert()
+        .failure()
+        .stderr(contains("heading 'missing' not found"));
+    Ok(())
+}
+
+#[test]
+fn show_renders_constraint_groups_in_each_format() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["show", "spec://cli/constraints/concept-cli.discovery"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("!concept-cli.discovery:"))
+        .stdout(contains("## Concept: Workspace Discovery"))
+        .stdout(contains("\x1b[").not());
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args([
+        "show",
+        "spec://cli/constraints/concept-cli.discovery",
+        "--no-expand",
+        "--format",
+        "ansi",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(contains("\x1b[1;33m!concept-cli.discovery:\x1b[0m"))
+        .stdout(contains("\x1b[1;31mMUST\x1b[0m"))
+        .stdout(contains("Workspace Discovery").not());
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["show", "spec://core#roots", "--format", "json"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("\"type\":\"shown\""))
+        .stdout(contains("\"kind\":\"heading\""))
+        .stdout(contains("\"expanded\":true"))
+        .stdout(contains("\"markdown\":\"### Roots\\n"));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["show", "spec://cli/constraints/concept-cli.missing"]);
+    cmd.assert()
+        .failure()
+        .stderr(contains("constraint group 'concept-cli.missing' not found"));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    fs::create_dir_all(root.join(".specman"))?;
+    write_file(
+        root.join("spec/core/spec.md"),
+        "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Workspace Discovery\n\nSee [roots](#roots).\n\n### Roots\n\nRoots hold `.specman`.\n",
+    )?;
+    write_file(
+        root.join("spec/cli/spec.md"),
+        "---\nname: cli\nversion: \"1.0.0\"\n---\n# CLI\n\n## Overview\n\nStarts from [roots](../core/spec.md#roots).\n\n## Concept: CLI\n\n!concept-cli.discovery:\n- MUST use [discovery](../core/spec.md#concept-workspace-discovery).\n",
+    )?;
+    write_file(
+        root.join("impl/runner/impl.md"),
+        "---\nspec: spec://core\nname: runner\nversion: \"1.0.0\"\nlocation: src/runner\n---\n# Runner\n\n## Startup\n\nImplements [discovery](../../spec/core/spec.md#concept-workspace-discovery).\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
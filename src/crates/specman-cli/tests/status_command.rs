@@ -147,3 +147,186 @@ version: 1.0.0
 
     Ok(())
 }
+
+/// A `specman status --watch` process, killed when dropped.
+struct WatchProcess {
+    child: std::process::Child,
+    lines: std::sync::mpsc::Receiver<String>,
+}
+
+impl WatchProcess {
+    fn spawn(root: &std::path::Path, args: &[&str]) -> Result<Self, Box<dyn std::error::Error>> {
+        use std::io::{BufRead, BufReader};
+        use std::process::Stdio;
+
+        let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_specman"))
+            .current_dir(root)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdout = child.stdout.take().unwrap();
+        let (lines_tx, lines) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if lines_tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Self { child, lines })
+    }
+
+    fn next_line(&self) -> String {
+        self.lines
+            .recv_timeout(std::time::Duration::from_secs(20))
+            .expect("watch output line")
+    }
+
+    /// Collects output up to and including the next line starting with `prefix`.
+    fn read_until(&self, prefix: &str) -> Vec<String> {
+        let mut seen = Vec::new();
+        loop {
+            let line = self
+                .lines
+                .recv_timeout(std::time::Duration::from_secs(20))
+                .unwrap_or_else(|_| panic!("no `{prefix}` line in {seen:?}"));
+            let done = line.starts_with(prefix);
+            seen.push(line);
+            if done {
+                return seen;
+            }
+        }
+    }
+}
+
+impl Drop for WatchProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn status_watch_reports_introduced_and_resolved_issues() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    init_workspace(temp.path());
+    let spec_dir = temp.path().join("spec/my-spec");
+    fs::create_dir_all(&spec_dir)?;
+    let valid = "---\nname: my-spec\nversion: 1.0.0\n---\n# My Spec\n";
+    fs::write(spec_dir.join("spec.md"), valid)?;
+
+    let watch = WatchProcess::spawn(temp.path(), &["status", "--local", "--watch"])?;
+    watch.read_until("Global Status: PASS");
+
+    fs::write(
+        spec_dir.join("spec.md"),
+        format!("{valid}\n[Broken Link](missing.md)\n"),
+    )?;
+    watch.read_until("Global Status: PASS -> FAIL");
+    let introduced =
+        watch.read_until("  + [References] my-spec (Specification): missing filesystem target");
+    assert!(introduced.iter().all(|line| line.starts_with("  + ")));
+
+    fs::write(spec_dir.join("spec.md"), valid)?;
+    watch.read_until("Global Status: FAIL -> PASS");
+    let resolved = watch.next_line();
+    assert!(resolved.starts_with("  - [References] my-spec"));
+    Ok(())
+}
+
+#[test]
+fn status_watch_honors_format_json() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    init_workspace(temp.path());
+    let spec_dir = temp.path().join("spec/my-spec");
+    fs::create_dir_all(&spec_dir)?;
+    let valid = "---\nname: my-spec\nversion: 1.0.0\n---\n# My Spec\n";
+    fs::write(spec_dir.join("spec.md"), valid)?;
+
+    let watch = WatchProcess::spawn(
+        temp.path(),
+        &["status", "--local", "--watch", "--format", "json"],
+    )?;
+    let report: serde_json::Value = serde_json::from_str(&watch.next_line())?;
+    assert_eq!(report["type"], "status");
+    assert_eq!(report["global_status"], "Pass");
+    assert_eq!(report["artifacts"][0][0]["name"], "my-spec");
+
+    fs::write(
+        spec_dir.join("spec.md"),
+        format!("{valid}\n[Broken Link](missing.md)\n"),
+    )?;
+    let diff: serde_json::Value = serde_json::from_str(&watch.next_line())?;
+    assert_eq!(diff["type"], "status_diff");
+    assert_eq!(diff["global_status"], "Fail");
+    assert_eq!(diff["introduced"][0]["check"], "references");
+    Ok(())
+}
+
+#[test]
+fn status_format_sarif_emits_sarif_log() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    init_workspace(temp.path());
+
+    let spec_dir = temp.path().join("spec/my-spec");
+    fs::create_dir_all(&spec_dir)?;
+    fs::write(
+        spec_dir.join("spec.md"),
+        "---\nname: my-spec\nversion: 1.0.0\n---\n# My Spec\n\n[Broken Link](missing.md)\n",
+    )?;
+
+    let output = cli()
+        .current_dir(temp.path())
+        .args(["status", "--local", "--format", "sarif"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(65));
+
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(log["version"], "2.1.0");
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    let missing = results
+        .iter()
+        .find(|result| result["ruleId"] == "specman/reference/file-missing")
+        .expect("file-missing result");
+    assert_eq!(missing["level"], "error");
+    let location = &missing["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "spec/my-spec/spec.md");
+    assert_eq!(location["region"]["startLine"], 7);
+    Ok(())
+}
+
+#[test]
+fn status_format_junit_reports_compliance_cases() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    init_workspace(temp.path());
+
+    fs::create_dir_all(temp.path().join("spec/core"))?;
+    fs::write(
+        temp.path().join("spec/core/spec.md"),
+        "---\nname: core\nversion: 1.0.0\n---\n# Core\n\n## Concept: Alpha\n\n!core.alpha:\n- MUST exist.\n\n!core.beta:\n- MUST also exist.\n",
+    )?;
+    fs::create_dir_all(temp.path().join("impl/core-rs"))?;
+    fs::write(
+        temp.path().join("impl/core-rs/impl.md"),
+        "---\nspec: spec://core\nname: core-rs\nversion: 1.0.0\nlocation: ../../src\n---\n# Core\n",
+    )?;
+    fs::create_dir_all(temp.path().join("src"))?;
+    fs::write(
+        temp.path().join("src/lib.rs"),
+        "// [ENSURES: core.alpha:TEST]\n",
+    )?;
+
+    let output = cli()
+        .current_dir(temp.path())
+        .args(["status", "--local", "--format", "junit"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(65));
+
+    let xml = String::from_utf8(output.stdout)?;
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("<testsuite name=\"core-rs\" tests=\"2\" failures=\"1\" errors=\"0\""));
+    assert!(xml.contains("name=\"core.alpha\""));
+    assert!(xml.contains("type=\"missing\""));
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const SPEC_OVERRIDE: &str =
+    "---\nname: {{output_name}}\ntitle: {{title}}\n---\n\n# {{title}}\n\nOwned by {{owner}}.\n";
+
+#[test]
+fn template_list_and_show_report_resolution() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["template", "list"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("Templates (7):"))
+        .stdout(contains(
+            "  - spec: WorkspaceOverride .specman/templates/spec.md",
+        ))
+        .stdout(contains("  - impl: EmbeddedDefault embedded://impl"))
+        .stdout(contains(
+            "  - spike: PointerFile docs/templates/spike.md via work-types.yaml",
+        ));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["template", "show", "spec"]);
+    cmd.assert().success().stdout(SPEC_OVERRIDE);
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["template", "tokens", "spec"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("3 token(s) in spec template"))
+        .stdout(contains("  - output_name (line 2)"))
+        .stdout(contains("  - owner (line 8) [not provided]"))
+        .stdout(contains("  - title (lines 3, 6)"));
+    Ok(())
+}
+
+#[test]
+fn template_validate_renders_sample_contexts() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = scaffold_workspace()?;
+    let root = temp.path();
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["template", "validate", "spike"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("[OK] spike"))
+        .stdout(contains("1 template(s) validated: 0 failed"));
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["template", "validate"]);
+    cmd.assert()
+        .code(65)
+        .stdout(contains(
+            "[FAIL] spec (WorkspaceOverride .specman/templates/spec.md)",
+        ))
+        .stdout(contains(
+            "token `owner` at line 8 is not provided for spec templates and renders empty",
+        ))
+        .stdout(contains("[OK] feat"))
+        .stdout(contains("7 template(s) validated: 1 failed"));
+
+    fs::write(
+        root.join(".specman/templates/spec.md"),
+        SPEC_OVERRIDE.replace("Owned by {{owner}}.", "Owned by the core team."),
+    )?;
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["template", "validate", "spec"]);
+    cmd.assert()
+        .code(65)
+        .stdout(contains("rendered front matter is not valid YAML"));
+    Ok(())
+}
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_specman"))
+}
+
+fn scaffold_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    write_file(root.join(".specman/templates/spec.md"), SPEC_OVERRIDE)?;
+    write_file(
+        root.join(".specman/work-types.yaml"),
+        "spike:\n  template: docs/templates/spike.md\n",
+    )?;
+    write_file(
+        root.join("docs/templates/spike.md"),
+        "---\ntarget: {{target_path}}\nwork_type:\n  {{work_type}}: {}\n---\n\n# Spike: {{output_name}}\n",
+    )?;
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+
+const REMOTE: &str = "https://deps.invalid/specs/base.md";
+const REMOTE_BODY: &str = "---\nname: base\nversion: \"2.0.0\"\n---\n# Base\n";
+const VENDORED: &str = ".specman/vendor/deps.invalid/specs/base.md";
+
+#[test]
+fn vendor_fills_directory_that_deny_policy_resolves_from() -> Result<(), Box<dyn std::error::Error>>
+{
+    let workspace = scaffold_locked_workspace()?;
+    let root = workspace.path();
+    write_file(root.join(".specman/network.yaml"), "policy: cache-only\n")?;
+
+    // Cache-only vendors from the lock cache without touching the network.
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).arg("vendor");
+    cmd.assert().success().stdout(contains(
+        "Vendored 1 remote dependencies and 0 template(s) into .specman/vendor (network policy: cache-only)",
+    ));
+    assert_eq!(fs::read_to_string(root.join(VENDORED))?, REMOTE_BODY);
+
+    // Under deny, only the vendored copy remains reachable.
+    write_file(root.join(".specman/network.yaml"), "policy: deny\n")?;
+    fs::remove_file(root.join(".specman/deps.lock"))?;
+    fs::remove_dir_all(root.join(".specman/cache/deps"))?;
+
+    let mut cmd = cli();
+    cmd.arg("--workspace")
+        .arg(root)
+        .args(["graph", "--root", "spec://local"]);
+    cmd.assert()
+        .success()
+        .stdout(contains("[label=\"base 2.0.0\""));
+
+    fs::remove_file(root.join(VENDORED))?;
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).args(["deps", "lock"]);
+    cmd.assert().failure().stderr(contains(
+        "https://deps.invalid/specs/base.md is not vendored and the `deny` network policy forbids fetching it",
+    ));
+    Ok(())
+}
+
+#[test]
+fn environment_policy_overrides_workspace_file() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_locked_workspace()?;
+    let root = workspace.path();
+    write_file(root.join(".specman/network.yaml"), "policy: allow\n")?;
+
+    let mut cmd = cli();
+    cmd.env("SPECMAN_NETWORK", "deny")
+        .arg("--workspace")
+        .arg(root)
+        .arg("vendor");
+    cmd.assert()
+        .failure()
+        .stderr(contains("the `deny` network policy forbids fetching it"));
+    Ok(())
+}
+
+#[test]
+fn invalid_policy_settings_are_reported() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = scaffold_locked_workspace()?;
+    let root = workspace.path();
+
+    let mut cmd = cli();
+    cmd.env("SPECMAN_NETWORK", "offline")
+        .arg("--workspace")
+        .arg(root)
+        .arg("vendor");
+    cmd.assert()
+        .failure()
+        .stderr(contains("invalid SPECMAN_NETWORK value `offline`"));
+
+    write_file(root.join(".specman/network.yaml"), "policy: sometimes\n")?;
+    let mut cmd = cli();
+    cmd.arg("--workspace").arg(root).arg("vendor");
+    cmd.assert().failure().stderr(contains("network.yaml"));
+    Ok(())
+}
+
+fn cli() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_specman"));
+    cmd.env_remove("SPECMAN_NETWORK");
+    cmd
+}
+
+fn scaffold_locked_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let root = temp.path();
+    let sha256 = hex::encode(Sha256::digest(REMOTE_BODY.as_bytes()));
+    let cached = format!(".specman/cache/deps/{sha256}.md");
+
+    write_file(
+        root.join("spec/local/spec.md"),
+        &format!(
+            "---\nname: local\nversion: \"1.0.0\"\ndependencies:\n  - {REMOTE}\n---\n# Local\n"
+        ),
+    )?;
+    write_file(root.join(&cached), REMOTE_BODY)?;
+    write_file(
+        root.join(".specman/deps.lock"),
+        &format!(
+            "{{\n  \"version\": 1,\n  \"dependencies\": [\n    {{\n      \"url\": \"{REMOTE}\",\n      \"sha256\": \"{sha256}\",\n      \"fetched_at\": \"2024-03-01T12:00:00Z\",\n      \"cached\": \"{cached}\"\n    }}\n  ]\n}}\n"
+        ),
+    )?;
+
+    Ok(temp)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
@@ -8,7 +8,8 @@ mod matching;
 mod serialization;
 
 use contracts::HandleScope;
-use index::{build_index, complete_constraint_ids};
+use index::complete_constraint_ids;
+pub(crate) use index::{CompletionIndex, build_index};
 use matching::{filter_handles_fuzzy, filter_slugs_fuzzy};
 
 pub(crate) use serialization::capability_descriptor_metadata;
@@ -18,21 +19,29 @@ pub(crate) struct CompletionOutcome {
     pub(crate) warnings: Vec<String>,
 }
 
+#[cfg(test)]
 pub(crate) fn complete_request(
     workspace: &WorkspacePaths,
     request: &CompleteRequestParams,
 ) -> CompletionOutcome {
-    let mut index = build_index(workspace);
+    complete_with_index(&build_index(workspace), workspace, request)
+}
 
+/// Completes against a prebuilt index, e.g. one kept current by the workspace watcher.
+pub(crate) fn complete_with_index(
+    index: &CompletionIndex,
+    workspace: &WorkspacePaths,
+    request: &CompleteRequestParams,
+) -> CompletionOutcome {
     let values = match &request.r#ref {
         Reference::Prompt(prompt_ref) => complete_prompt(
-            &index,
+            index,
             &prompt_ref.name,
             &request.argument,
             request.context.as_ref(),
         ),
         Reference::Resource(resource_ref) => complete_resource(
-            &index,
+            index,
             workspace,
             &resource_ref.uri,
             &request.argument,
@@ -42,7 +51,7 @@ pub(crate) fn complete_request(
 
     CompletionOutcome {
         values,
-        warnings: std::mem::take(&mut index.warnings),
+        warnings: index.warnings.clone(),
     }
 }
 
@@ -29,9 +29,11 @@ mod tests {
     use rmcp::model::PromptMessageContent;
     use rmcp::model::{PromptMessage, ResourceContents};
     use specman::{
-        ArtifactId, ArtifactKind, ArtifactSummary, DependencyTree, WorkspaceLocator, WorkspacePaths,
+        ArtifactId, ArtifactKind, ArtifactSummary, DependencyTree, FileChange, FileChangeKind,
+        ManualFileWatcher, WorkspaceLocator, WorkspacePaths, WorkspaceWatcher,
     };
     use std::io::Write;
+    use std::sync::Arc;
     use tempfile::TempDir;
 
     use crate::prompts::{ResolvedTarget, dependency_lines};
@@ -99,6 +101,42 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn read_resource_plan_orders_specifications() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let workspace = TestWorkspace::create()?;
+        let inventory = workspace.server.inventory().await?;
+        let uris: Vec<String> = resources_from_inventory(&inventory)
+            .into_iter()
+            .map(|r| r.raw.uri)
+            .collect();
+        assert!(uris.contains(&"specman://plan".to_string()));
+
+        let plan = workspace
+            .server
+            .read_resource_contents("specman://plan")
+            .await?;
+
+        match plan {
+            ResourceContents::TextResourceContents {
+                mime_type, text, ..
+            } => {
+                assert_eq!(mime_type.as_deref(), Some("application/json"));
+                let value: serde_json::Value = serde_json::from_str(&text)?;
+                let names: Vec<&str> = value["steps"]
+                    .as_array()
+                    .expect("steps array")
+                    .iter()
+                    .filter_map(|step| step["specification"]["name"].as_str())
+                    .collect();
+                assert!(names.contains(&"testspec"), "{names:?}");
+            }
+            other => panic!("unexpected variant: {other:?}"),
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn read_resource_constraints_index_returns_json() -> Result<(), Box<dyn std::error::Error>>
     {
@@ -869,6 +907,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn declared_work_types_get_create_tools() -> Result<(), Box<dyn std::error::Error>> {
+        let temp = tempfile::tempdir()?;
+        create_workspace_files(temp.path())?;
+        fs::write(
+            temp.path().join(".specman/work-types.yaml"),
+            "security-review:\n  description: Review a change for security impact.\n  required_metadata: [threat_model]\nfix:\n  description: Shadows a builtin\n",
+        )?;
+        // Built-in names are rejected, so the declarations are ignored as a whole.
+        let server = SpecmanMcpServer::new_with_root(temp.path())?;
+        assert!(!server.tool_router.has_route("create_security_review"));
+
+        fs::write(
+            temp.path().join(".specman/work-types.yaml"),
+            "security-review:\n  description: Review a change for security impact.\n  required_metadata: [threat_model]\n",
+        )?;
+        let server = SpecmanMcpServer::new_with_root(temp.path())?;
+        let tool = server
+            .tool_router
+            .list_all()
+            .into_iter()
+            .find(|tool| tool.name == "create_security_review")
+            .expect("custom work type tool is listed");
+        let description = tool.description.as_deref().unwrap_or_default();
+        assert!(description.contains("Review a change for security impact."));
+        assert!(description.contains("Required metadata: threat_model."));
+        assert!(
+            tool.input_schema
+                .get("properties")
+                .and_then(|props| props.get("metadata"))
+                .is_some()
+        );
+        assert!(server.tool_router.has_route("create_fix"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn create_feature_normalizes_scratchpad_target() -> Result<(), Box<dyn std::error::Error>>
     {
@@ -1234,6 +1308,183 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn rename_artifact_rewrites_dependents() -> Result<(), Box<dyn std::error::Error>> {
+        let workspace = TestWorkspace::create()?;
+        let root = workspace._temp.path();
+
+        let Json(preview) = workspace
+            .server
+            .rename_artifact(rmcp::handler::server::wrapper::Parameters(
+                crate::tools::RenameArtifactArgs {
+                    locator: "spec://testspec".to_string(),
+                    new_name: "renamed".to_string(),
+                    mode: crate::tools::PersistenceMode::Preview,
+                },
+            ))
+            .await?;
+
+        assert!(!preview.persisted);
+        assert!(root.join("spec/testspec/spec.md").is_file());
+        assert!(
+            preview
+                .rewrites
+                .iter()
+                .any(|r| r.handle == "impl://testimpl" && r.after == "spec://renamed"),
+            "{preview:?}"
+        );
+
+        let Json(result) = workspace
+            .server
+            .rename_artifact(rmcp::handler::server::wrapper::Parameters(
+                crate::tools::RenameArtifactArgs {
+                    locator: "spec://testspec".to_string(),
+                    new_name: "renamed".to_string(),
+                    mode: crate::tools::PersistenceMode::Persist,
+                },
+            ))
+            .await?;
+
+        assert!(result.persisted);
+        assert_eq!(result.handle, "spec://renamed");
+        assert_eq!(result.path, "spec/renamed/spec.md");
+        let implementation = fs::read_to_string(root.join("impl/testimpl/impl.md"))?;
+        assert!(implementation.contains("spec: spec://renamed"), "{implementation}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn constraints_index_reflects_external_edits() -> Result<(), Box<dyn std::error::Error>> {
+        let workspace = TestWorkspace::create()?;
+        let root = workspace._temp.path();
+        let manual = Arc::new(ManualFileWatcher::new());
+        let watcher = WorkspaceWatcher::new(
+            workspace.server.workspace.workspace()?,
+            workspace.server.dependency_mapper.graph_handle(),
+            Box::new(manual.clone()),
+        )?;
+        *workspace.server.watcher.lock().unwrap() =
+            crate::server::WatcherState::Running(Arc::new(watcher));
+
+        let constraint_count = |contents: ResourceContents| -> usize {
+            match contents {
+                ResourceContents::TextResourceContents { text, .. } => {
+                    let value: serde_json::Value =
+                        serde_json::from_str(&text).expect("constraints index json");
+                    value["constraints"].as_array().map_or(0, Vec::len)
+                }
+                other => panic!("unexpected variant: {other:?}"),
+            }
+        };
+
+        let before = workspace
+            .server
+            .read_resource_contents("spec://empty/constraints")
+            .await?;
+        assert_eq!(constraint_count(before), 0);
+
+        fs::write(
+            root.join("spec/empty/spec.md"),
+            "---\nname: empty\nversion: \"1.0.0\"\n---\n# Empty\n\n## Concept: Added\n\n!concept-added.group:\n- MUST appear.\n",
+        )?;
+
+        manual.push(FileChange::new(
+            root.join("spec/empty/spec.md"),
+            FileChangeKind::Modified,
+        ));
+        let after = workspace
+            .server
+            .read_resource_contents("spec://empty/constraints")
+            .await?;
+        let count = constraint_count(after);
+        assert_eq!(count, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_tool_returns_ranked_constraint_identifiers()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let workspace = TestWorkspace::create()?;
+
+        let Json(result) = workspace
+            .server
+            .search(rmcp::handler::server::wrapper::Parameters(
+                crate::tools::SearchArgs {
+                    query: "discoverable".to_string(),
+                    kind: Some("spec".to_string()),
+                    constraints_only: Some(true),
+                    limit: None,
+                },
+            ))
+            .await?;
+
+        assert_eq!(result.hits.len(), 1, "{result:?}");
+        assert_eq!(
+            result.hits[0].identifier,
+            "spec/testspec/spec.md!concept-test.other"
+        );
+        assert_eq!(result.hits[0].snippet, "- MUST be discoverable");
+
+        let err = workspace
+            .server
+            .search(rmcp::handler::server::wrapper::Parameters(
+                crate::tools::SearchArgs {
+                    query: "discoverable".to_string(),
+                    kind: Some("docs".to_string()),
+                    constraints_only: None,
+                    limit: None,
+                },
+            ))
+            .await
+            .err()
+            .expect("unknown kind is rejected");
+        assert!(err.message.contains("unknown artifact kind"), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_tool_returns_constraint_rows() -> Result<(), Box<dyn std::error::Error>> {
+        let workspace = TestWorkspace::create()?;
+
+        let Json(result) = workspace
+            .server
+            .query(rmcp::handler::server::wrapper::Parameters(
+                crate::tools::QueryArgs {
+                    expression: "constraints where artifact ^= spec/ select identifier, kind"
+                        .to_string(),
+                },
+            ))
+            .await?;
+
+        assert_eq!(result.table.columns, vec!["identifier", "kind"]);
+        let identifiers: Vec<String> = result
+            .table
+            .rows
+            .iter()
+            .map(|row| row[0].to_string())
+            .collect();
+        assert_eq!(
+            identifiers,
+            vec![
+                "spec/testspec/spec.md!concept-test.group",
+                "spec/testspec/spec.md!concept-test.other",
+            ]
+        );
+
+        let err = workspace
+            .server
+            .query(rmcp::handler::server::wrapper::Parameters(
+                crate::tools::QueryArgs {
+                    expression: "constraints where colour = red".to_string(),
+                },
+            ))
+            .await
+            .err()
+            .expect("unknown field is rejected");
+        assert!(err.message.contains("unknown field `colour`"), "{err:?}");
+        Ok(())
+    }
+
     fn create_workspace_files(root: &Path) -> Result<(), Box<dyn std::error::Error>> {
         let spec_dir = root.join("spec/testspec");
         let empty_spec_dir = root.join("spec/empty");
@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 
 use specman::{
     ArtifactId, ArtifactKey, ArtifactKind, ArtifactSummary, ConstraintIdentifier,
-    FilesystemStructureIndexer, SemVer, SpecmanError, StructureQuery, WorkspaceLocator,
+    SemVer, SpecmanError, StructureQuery, WorkspaceLocator,
     WorkspacePaths,
 };
 
@@ -166,8 +166,12 @@ fn artifact_record(summary: &ArtifactSummary, workspace: &WorkspacePaths) -> Art
     }
 }
 
+/// Workspace-wide implementation plan; not tied to a single artifact.
+pub(crate) const PLAN_URI: &str = "specman://plan";
+
 #[derive(Debug, PartialEq)]
 enum ParsedResourceRequest {
+    Plan,
     ArtifactContent(String),
     Dependencies(String),
     ComplianceReport(String),
@@ -186,6 +190,10 @@ impl std::str::FromStr for ParsedResourceRequest {
         }
         let normalized = normalize_resource_uri(uri);
         let uri = normalized.as_str();
+        if uri == PLAN_URI {
+            return Ok(ParsedResourceRequest::Plan);
+        }
+
         // Dependencies
         if let Some(base) = uri.strip_suffix("/dependencies") {
             return Ok(ParsedResourceRequest::Dependencies(base.to_string()));
@@ -221,6 +229,7 @@ impl std::str::FromStr for ParsedResourceRequest {
 impl ParsedResourceRequest {
     fn artifact_handle(&self) -> &str {
         match self {
+            ParsedResourceRequest::Plan => PLAN_URI,
             ParsedResourceRequest::ArtifactContent(h) => h,
             ParsedResourceRequest::Dependencies(h) => h,
             ParsedResourceRequest::ComplianceReport(h) => h,
@@ -276,10 +285,7 @@ impl SpecmanMcpServer {
             )))
         })?;
 
-        let indexer = FilesystemStructureIndexer::new(self.workspace.clone());
-        let index = indexer
-            .build_once_with_workspace(&workspace)
-            .map_err(to_mcp_error)?;
+        let index = self.structure_index(&workspace)?;
 
         if !index.artifacts.contains_key(&ArtifactKey {
             kind: ArtifactKind::Specification,
@@ -356,10 +362,7 @@ impl SpecmanMcpServer {
             )))
         })?;
 
-        let indexer = FilesystemStructureIndexer::new(self.workspace.clone());
-        let index = indexer
-            .build_once_with_workspace(&workspace)
-            .map_err(to_mcp_error)?;
+        let index = self.structure_index(&workspace)?;
 
         let key = ConstraintIdentifier {
             artifact: ArtifactKey {
@@ -412,6 +415,27 @@ impl SpecmanMcpServer {
         })
     }
 
+    async fn read_plan(&self, uri: &str) -> Result<ResourceContents, McpError> {
+        info!(uri = %uri, "building implementation plan");
+        let workspace = self.workspace.workspace().map_err(to_mcp_error)?;
+        let root = workspace.root().to_path_buf();
+
+        let plan = tokio::task::spawn_blocking(move || specman::plan_workspace(root))
+            .await
+            .map_err(|e| to_mcp_error(SpecmanError::Io(std::io::Error::other(e))))?
+            .map_err(to_mcp_error)?;
+
+        let json = serde_json::to_string(&plan)
+            .map_err(|err| to_mcp_error(SpecmanError::Serialization(err.to_string())))?;
+
+        Ok(ResourceContents::TextResourceContents {
+            uri: uri.to_string(),
+            mime_type: Some("application/json".to_string()),
+            text: json,
+            meta: None,
+        })
+    }
+
     async fn collect_artifacts(
         &self,
         kind: ArtifactKind,
@@ -445,6 +469,7 @@ impl SpecmanMcpServer {
     }
 
     pub(crate) async fn inventory(&self) -> Result<ArtifactInventory, McpError> {
+        self.sync_workspace_changes()?;
         let workspace = self.workspace.workspace().map_err(to_mcp_error)?;
 
         let specs = self
@@ -504,7 +529,9 @@ impl SpecmanMcpServer {
         }
 
         match &request {
+            ParsedResourceRequest::Plan => self.read_plan(uri).await,
             ParsedResourceRequest::Dependencies(handle) => {
+                self.sync_workspace_changes()?;
                 let tree = self
                     .dependency_mapper
                     .dependency_tree_from_locator(handle)
@@ -604,6 +631,23 @@ pub(crate) fn resources_from_inventory(inventory: &ArtifactInventory) -> Vec<Res
         ));
     }
 
+    resources.push(Resource {
+        raw: RawResource {
+            uri: PLAN_URI.to_string(),
+            name: "plan".to_string(),
+            title: Some("Implementation plan".to_string()),
+            description: Some(
+                "Specifications in dependency order with implementation coverage and what can be built next"
+                    .to_string(),
+            ),
+            mime_type: Some("application/json".to_string()),
+            size: None,
+            icons: None,
+            meta: None,
+        },
+        annotations: None,
+    });
+
     resources
 }
 
@@ -725,7 +769,8 @@ impl ServerHandler for SpecmanMcpServer {
         context: RequestContext<RoleServer>,
     ) -> Result<CompleteResult, McpError> {
         let workspace = self.workspace.workspace().map_err(to_mcp_error)?;
-        let outcome = completion::complete_request(&workspace, &request);
+        let index = self.completion_index(&workspace)?;
+        let outcome = completion::complete_with_index(&index, &workspace, &request);
 
         for warning in outcome.warnings {
             let notification = LoggingMessageNotificationParam {
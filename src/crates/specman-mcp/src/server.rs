@@ -1,23 +1,42 @@
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use rmcp::handler::server::{router::Router, router::prompt::PromptRouter, tool::ToolRouter};
 use rmcp::service::ServerInitializeError;
 use rmcp::{service::ServiceExt, transport};
 
-use specman::{FilesystemDependencyMapper, FilesystemWorkspaceLocator, SpecmanError};
+use specman::{
+    FilesystemDependencyMapper, FilesystemStructureIndexer, FilesystemWorkspaceLocator,
+    NetworkPolicy, SpecmanError, WorkTypeRegistry, WorkspaceIndex, WorkspaceLocator,
+    WorkspacePaths, WorkspaceWatcher, load_work_types,
+};
 
-use tracing::info;
+use tracing::{info, warn};
 
-use crate::error::to_mcp_error;
+use crate::completion::{self, CompletionIndex};
+use crate::error::{McpError, to_mcp_error};
 use crate::prompts::build_prompt_router;
 use crate::telemetry::init_tracing;
 use crate::tools::build_tool_router;
 
+type LiveWorkspace = WorkspaceWatcher<Arc<FilesystemWorkspaceLocator>>;
+type CompletionCache = Mutex<Option<(u64, Arc<CompletionIndex>)>>;
+
+/// Lifecycle of the lazily started workspace watcher.
+pub(crate) enum WatcherState {
+    NotStarted,
+    Running(Arc<LiveWorkspace>),
+    /// Starting failed once; requests rescan from disk instead of retrying.
+    Unavailable,
+}
+
 #[derive(Clone)]
 pub struct SpecmanMcpServer {
     pub(crate) workspace: Arc<FilesystemWorkspaceLocator>,
     pub(crate) dependency_mapper: Arc<FilesystemDependencyMapper<Arc<FilesystemWorkspaceLocator>>>,
+    /// Started lazily on first read so servers launched outside a workspace still come up.
+    pub(crate) watcher: Arc<Mutex<WatcherState>>,
+    pub(crate) completion_cache: Arc<CompletionCache>,
     pub(crate) tool_router: ToolRouter<Self>,
     pub(crate) prompt_router: PromptRouter<Self>,
 }
@@ -33,15 +52,105 @@ impl SpecmanMcpServer {
         info!(root = %root.display(), "initializing Specman MCP server");
         let workspace = Arc::new(FilesystemWorkspaceLocator::new(root));
         let dependency_mapper = Arc::new(FilesystemDependencyMapper::new(workspace.clone()));
+        // Custom work type tools are fixed at startup; edits to the declarations need a restart.
+        let work_types = match workspace.workspace() {
+            Ok(paths) => {
+                if let Err(err) = NetworkPolicy::for_workspace(&paths) {
+                    warn!(error = %err, "invalid network policy; remote fetches are denied");
+                }
+                load_work_types(&paths).unwrap_or_else(|err| {
+                    warn!(error = %err, "ignoring invalid work type declarations");
+                    WorkTypeRegistry::default()
+                })
+            }
+            Err(_) => WorkTypeRegistry::default(),
+        };
 
         Ok(Self {
             workspace,
             dependency_mapper,
-            tool_router: build_tool_router(),
+            watcher: Arc::new(Mutex::new(WatcherState::NotStarted)),
+            completion_cache: Arc::new(Mutex::new(None)),
+            tool_router: build_tool_router(&work_types),
             prompt_router: build_prompt_router(),
         })
     }
 
+    /// Returns the workspace watcher, starting it on first use.
+    ///
+    /// When the platform watcher cannot be started, callers fall back to rebuilding from disk
+    /// for the rest of the session.
+    fn live_workspace(&self) -> Option<Arc<LiveWorkspace>> {
+        let mut slot = self.watcher.lock().unwrap();
+        if matches!(*slot, WatcherState::NotStarted) {
+            let started = self.workspace.workspace().and_then(|workspace| {
+                WorkspaceWatcher::with_notify(workspace, self.dependency_mapper.graph_handle())
+            });
+            *slot = match started {
+                Ok(watcher) => WatcherState::Running(Arc::new(watcher)),
+                Err(err) => {
+                    warn!(error = %err, "workspace watcher unavailable; rescanning per request");
+                    WatcherState::Unavailable
+                }
+            };
+        }
+        match &*slot {
+            WatcherState::Running(watcher) => Some(watcher.clone()),
+            WatcherState::NotStarted | WatcherState::Unavailable => None,
+        }
+    }
+
+    /// Applies pending filesystem changes to the shared dependency inventory.
+    pub(crate) fn sync_workspace_changes(&self) -> Result<(), McpError> {
+        if let Some(watcher) = self.live_workspace() {
+            watcher.sync().map_err(to_mcp_error)?;
+        }
+        Ok(())
+    }
+
+    /// Structure index reflecting the latest observed edits.
+    pub(crate) fn structure_index(
+        &self,
+        workspace: &WorkspacePaths,
+    ) -> Result<Arc<WorkspaceIndex>, McpError> {
+        if let Some(watcher) = self.live_workspace() {
+            return watcher.index().map_err(to_mcp_error);
+        }
+        FilesystemStructureIndexer::new(self.workspace.clone())
+            .build_once_with_workspace(workspace)
+            .map(Arc::new)
+            .map_err(to_mcp_error)
+    }
+
+    /// Completion index, rebuilt only after the watcher has seen workspace changes.
+    pub(crate) fn completion_index(
+        &self,
+        workspace: &WorkspacePaths,
+    ) -> Result<Arc<CompletionIndex>, McpError> {
+        let Some(watcher) = self.live_workspace() else {
+            return Ok(Arc::new(completion::build_index(workspace)));
+        };
+
+        let generation = watcher.generation().map_err(to_mcp_error)?;
+        let mut cache = self.completion_cache.lock().unwrap();
+        if let Some((cached_generation, index)) = cache.as_ref()
+            && *cached_generation == generation
+        {
+            return Ok(index.clone());
+        }
+
+        let index = Arc::new(completion::build_index(workspace));
+        *cache = Some((generation, index.clone()));
+        Ok(index)
+    }
+
+    /// Drops watcher state after this server mutated the workspace itself.
+    pub(crate) fn mark_workspace_stale(&self) {
+        if let WatcherState::Running(watcher) = &*self.watcher.lock().unwrap() {
+            watcher.mark_stale();
+        }
+    }
+
     /// Start a stdio-based MCP server and wait until the transport closes.
     pub async fn run_stdio(self) -> Result<(), ServerInitializeError> {
         let tools = self.tool_router.clone();
@@ -1,14 +1,20 @@
-use rmcp::handler::server::tool::ToolRouter;
+use std::collections::BTreeMap;
+
+use rmcp::handler::server::tool::{
+    IntoCallToolResult, ToolCallContext, ToolRoute, ToolRouter, parse_json_object, schema_for_type,
+};
 use rmcp::handler::server::wrapper::{Json, Parameters};
+use rmcp::model::Tool;
 use rmcp::schemars::JsonSchema;
 use rmcp::{tool, tool_router};
 use serde::{Deserialize, Serialize};
 
 use specman::{
-    ArtifactId, ArtifactKind, FrontMatterUpdate, IdentityUpdate, ImplementationUpdate,
-    PersistedArtifact, ScratchFixMetadata, ScratchRefactorMetadata, ScratchRevisionMetadata,
-    ScratchUpdate, ScratchWorkType, ScratchWorkloadExtras, SpecificationUpdate, SpecmanEnv,
-    WorkspaceLocator, apply_front_matter_update,
+    ArtifactId, ArtifactKind, CustomWorkType, FrontMatterUpdate, IdentityUpdate,
+    ImplementationUpdate, PersistedArtifact, ScratchFixMetadata, ScratchRefactorMetadata,
+    ScratchRevisionMetadata, ScratchUpdate, ScratchWorkType, ScratchWorkloadExtras,
+    SpecificationUpdate, SpecmanEnv, WorkTypeDefinition, WorkTypeRegistry, WorkspaceLocator,
+    apply_front_matter_update,
 };
 
 use crate::error::{McpError, invalid_params, to_mcp_error};
@@ -16,7 +22,7 @@ use crate::resources::{
     artifact_handle, artifact_path, resolved_path_or_artifact_path, workspace_relative_path,
 };
 use crate::server::SpecmanMcpServer;
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
 // ── Create tool input types ──────────────────────────────────────────
 
@@ -49,6 +55,22 @@ pub struct CreateScratchPadArgs {
     pub target: String,
 }
 
+/// Args for the `create_<type>` tools generated from `.specman/work-types.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateCustomScratchPadArgs {
+    #[schemars(description = "Scratch pad name (lowercase, digits, hyphens, <=4 words).")]
+    pub name: String,
+    #[schemars(
+        description = "Target artifact locator (spec://, impl://, scratch://, or a workspace-relative path)."
+    )]
+    pub target: String,
+    #[serde(default)]
+    #[schemars(
+        description = "Work type metadata written under `work_type.<type>`; must include the type's required fields."
+    )]
+    pub metadata: BTreeMap<String, serde_json::Value>,
+}
+
 // ── Update tool input types ──────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -133,6 +155,47 @@ pub struct UpdateScratchPadArgs {
     pub dependencies: Option<Vec<specman::DependencyEntry>>,
 }
 
+// ── Rename tool input types ──────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RenameArtifactArgs {
+    #[schemars(
+        description = "Artifact locator: spec://..., impl://..., scratch://... handle, or workspace-relative path."
+    )]
+    pub locator: String,
+    #[schemars(description = "New artifact slug/name (lowercase, digits, hyphens).")]
+    pub new_name: String,
+    #[schemars(
+        description = "Use 'preview' to report the planned rewrites without touching disk, or 'persist' to apply them."
+    )]
+    pub mode: PersistenceMode,
+}
+
+// ── Search and query tool input types ────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchArgs {
+    #[schemars(description = "Words to search for in headings and constraint groups.")]
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Only search artifacts of this kind: 'spec', 'impl', or 'scratch'.")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Only return constraint groups.")]
+    pub constraints_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Maximum number of results (default 20).")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct QueryArgs {
+    #[schemars(
+        description = "Query over the structure index: '<headings|constraints|relationships> [where <condition>] [select <field>, ...] [limit <n>]'. Conditions combine 'field op value' with and/or/not and parentheses; operators are =, !=, ~ (contains), ^= (starts with), <, <=, >, >=. Example: 'constraints where under = concept-x and external_references > 0'."
+    )]
+    pub expression: String,
+}
+
 // ── Result types ─────────────────────────────────────────────────────
 
 /// Result payload returned by all create tools.
@@ -160,6 +223,47 @@ pub struct UpdateArtifactResult {
     pub persisted: bool,
 }
 
+/// Result payload returned by the rename tool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameArtifactResult {
+    pub id: ArtifactId,
+    #[schemars(description = "Canonical handle of the artifact after the rename.")]
+    pub handle: String,
+    #[schemars(description = "Workspace-relative path of the artifact after the rename.")]
+    pub path: String,
+    #[schemars(description = "Front-matter references rewritten in dependent artifacts.")]
+    pub rewrites: Vec<RenameRewrite>,
+    pub persisted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RenameRewrite {
+    pub handle: String,
+    pub path: String,
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Result payload returned by the search tool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchResult {
+    pub query: String,
+    #[schemars(
+        description = "Hits ranked by BM25 score; identifiers are '<path>#<heading-slug>' or '<path>!<constraint-group>'."
+    )]
+    pub hits: Vec<specman::SearchHit>,
+}
+
+/// Result payload returned by the query tool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct QueryResult {
+    pub expression: String,
+    #[schemars(description = "Selected columns and one row of values per matching record.")]
+    pub table: specman::QueryTable,
+}
+
 /// Structured workspace data exposed over MCP tools.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WorkspaceInfo {
@@ -177,8 +281,67 @@ pub struct WorkspaceInfo {
 
 // ── Router ───────────────────────────────────────────────────────────
 
-pub(crate) fn build_tool_router() -> ToolRouter<SpecmanMcpServer> {
-    SpecmanMcpServer::tool_router()
+/// Static tools plus one `create_<type>` tool per declared custom work type. Hyphens in the
+/// type name become underscores in the tool name.
+pub(crate) fn build_tool_router(work_types: &WorkTypeRegistry) -> ToolRouter<SpecmanMcpServer> {
+    let mut router = SpecmanMcpServer::tool_router();
+    for (work_type, definition) in work_types.iter() {
+        let tool_name = format!("create_{}", work_type.replace('-', "_"));
+        if router.has_route(&tool_name) {
+            warn!(%work_type, %tool_name, "work type tool shadows a built-in tool; skipping");
+            continue;
+        }
+        router.add_route(work_type_route(tool_name, work_type, definition));
+    }
+    router
+}
+
+fn work_type_route(
+    tool_name: String,
+    work_type: &str,
+    definition: &WorkTypeDefinition,
+) -> ToolRoute<SpecmanMcpServer> {
+    let mut description = format!("Create a `{work_type}` scratch pad (custom work type).");
+    if let Some(summary) = &definition.description {
+        description.push(' ');
+        description.push_str(summary);
+    }
+    if !definition.required_metadata.is_empty() {
+        description.push_str(&format!(
+            " Required metadata: {}.",
+            definition.required_metadata.join(", ")
+        ));
+    }
+    let tool = Tool::new(
+        tool_name,
+        description,
+        schema_for_type::<CreateCustomScratchPadArgs>(),
+    )
+    .with_output_schema::<CreateArtifactResult>();
+
+    let work_type = work_type.to_string();
+    ToolRoute::new_dyn(
+        tool,
+        move |context: ToolCallContext<'_, SpecmanMcpServer>| {
+            let work_type = work_type.clone();
+            Box::pin(async move {
+                let args: CreateCustomScratchPadArgs =
+                    parse_json_object(context.arguments.unwrap_or_default())?;
+                context
+                    .service
+                    .create_scratch_pad(
+                        &args.name,
+                        &args.target,
+                        ScratchWorkType::Custom(CustomWorkType {
+                            name: work_type,
+                            metadata: args.metadata,
+                        }),
+                    )
+                    .await
+                    .into_call_tool_result()
+            })
+        },
+    )
 }
 
 // ── Tool methods ─────────────────────────────────────────────────────
@@ -323,6 +486,116 @@ impl SpecmanMcpServer {
         .await
     }
 
+    // ── Rename tools ─────────────────────────────────────────────
+
+    #[tool(
+        name = "rename_artifact",
+        description = "Rename a specification, implementation, or scratch pad and rewrite front-matter references held by dependent artifacts. Supports preview and persist modes."
+    )]
+    pub(crate) async fn rename_artifact(
+        &self,
+        Parameters(args): Parameters<RenameArtifactArgs>,
+    ) -> Result<Json<RenameArtifactResult>, McpError> {
+        info!("rename_artifact request received");
+        validate_slug(&args.new_name, "artifact")?;
+        let summary = self.normalize_locator_to_handle(&args.locator)?;
+        let persist = matches!(args.mode, PersistenceMode::Persist);
+
+        let env = self.build_env()?;
+        let result = specman::rename_artifact(
+            &env,
+            &summary.id,
+            args.new_name.trim(),
+            specman::RenameOptions { dry_run: !persist },
+        )
+        .map_err(to_mcp_error)?;
+
+        if persist {
+            self.invalidate_dependency_inventory();
+        }
+        let workspace = self.workspace.workspace().map_err(to_mcp_error)?;
+        let plan = result.plan();
+        let relative = |path: &std::path::Path| {
+            workspace_relative_path(workspace.root(), path)
+                .unwrap_or_else(|| path.display().to_string())
+        };
+        let r = RenameArtifactResult {
+            id: plan.to.clone(),
+            handle: handle_for_id(&plan.to),
+            path: relative(&plan.destination),
+            rewrites: plan
+                .rewrites
+                .iter()
+                .map(|rewrite| RenameRewrite {
+                    handle: handle_for_id(&rewrite.artifact),
+                    path: relative(&rewrite.path),
+                    field: rewrite.field.clone(),
+                    before: rewrite.before.clone(),
+                    after: rewrite.after.clone(),
+                })
+                .collect(),
+            persisted: persist,
+        };
+        info!(handle = %r.handle, rewrites = r.rewrites.len(), persisted = persist, "rename_artifact completed");
+        Ok(Json(r))
+    }
+
+    // ── Search tools ─────────────────────────────────────────────
+
+    #[tool(
+        name = "search",
+        description = "Full-text search over the headings and constraint groups of every workspace artifact, ranked with BM25. Returns heading/constraint identifiers with snippets."
+    )]
+    pub(crate) async fn search(
+        &self,
+        Parameters(args): Parameters<SearchArgs>,
+    ) -> Result<Json<SearchResult>, McpError> {
+        let kind = args
+            .kind
+            .as_deref()
+            .map(str::parse::<ArtifactKind>)
+            .transpose()
+            .map_err(|err| invalid_params(err.to_string()))?;
+        let defaults = specman::SearchOptions::default();
+        let options = specman::SearchOptions {
+            kind,
+            constraints_only: args.constraints_only.unwrap_or(defaults.constraints_only),
+            limit: args.limit.unwrap_or(defaults.limit),
+        };
+
+        let workspace = self.workspace.workspace().map_err(to_mcp_error)?;
+        let index = self.structure_index(&workspace)?;
+        let search = specman::SearchIndexCache::new(&workspace)
+            .load_or_build(&index)
+            .map_err(to_mcp_error)?;
+        let hits = search.search(&args.query, &options);
+        info!(query = %args.query, hits = hits.len(), "search completed");
+        Ok(Json(SearchResult {
+            query: args.query,
+            hits,
+        }))
+    }
+
+    #[tool(
+        name = "query",
+        description = "Run a structured query over the headings, constraint groups, and relationships of the workspace structure index. Returns a table of columns and rows."
+    )]
+    pub(crate) async fn query(
+        &self,
+        Parameters(args): Parameters<QueryArgs>,
+    ) -> Result<Json<QueryResult>, McpError> {
+        let query = specman::IndexQuery::parse(&args.expression)
+            .map_err(|err| invalid_params(err.to_string()))?;
+        let workspace = self.workspace.workspace().map_err(to_mcp_error)?;
+        let index = self.structure_index(&workspace)?;
+        let table = query.run(&index);
+        info!(expression = %args.expression, rows = table.rows.len(), "query completed");
+        Ok(Json(QueryResult {
+            expression: args.expression,
+            table,
+        }))
+    }
+
     // ── Update tools ─────────────────────────────────────────────
 
     #[tool(
@@ -619,15 +892,18 @@ fn identity_from_args(
     }
 }
 
+fn handle_for_id(id: &ArtifactId) -> String {
+    match id.kind {
+        ArtifactKind::Specification => format!("spec://{}", id.name),
+        ArtifactKind::Implementation => format!("impl://{}", id.name),
+        ArtifactKind::ScratchPad => format!("scratch://{}", id.name),
+    }
+}
+
 fn create_artifact_result(persisted: &PersistedArtifact) -> CreateArtifactResult {
-    let handle = match persisted.artifact.kind {
-        ArtifactKind::Specification => format!("spec://{}", persisted.artifact.name),
-        ArtifactKind::Implementation => format!("impl://{}", persisted.artifact.name),
-        ArtifactKind::ScratchPad => format!("scratch://{}", persisted.artifact.name),
-    };
     CreateArtifactResult {
         id: persisted.artifact.clone(),
-        handle,
+        handle: handle_for_id(&persisted.artifact),
         path: persisted
             .path
             .strip_prefix(persisted.workspace.root())
@@ -742,6 +1018,7 @@ impl SpecmanMcpServer {
         self.dependency_mapper
             .dependency_graph()
             .invalidate_inventory();
+        self.mark_workspace_stale();
     }
 
     fn workspace_relative_artifact_path(
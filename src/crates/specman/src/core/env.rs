@@ -1,6 +1,7 @@
 use crate::storage::adapter::DataModelAdapter;
-use crate::graph::tree::{DependencyMapping, FilesystemDependencyMapper};
+use crate::graph::tree::{DependencyGraphServices, DependencyMapping, FilesystemDependencyMapper};
 use crate::core::error::SpecmanError;
+use crate::core::network::NetworkPolicy;
 use crate::storage::persistence::WorkspacePersistence;
 use crate::templates::engine::{MarkdownTemplateEngine, TemplateEngine};
 use crate::templates::catalog::TemplateCatalog;
@@ -17,7 +18,11 @@ pub struct SpecmanEnv {
     pub catalog: TemplateCatalog,
     pub persistence: DefaultPersistence,
     pub mapping: Arc<dyn DependencyMapping>,
+    /// Graph services backing `mapping`, exposed for inventory-level queries.
+    pub graph: Arc<DependencyGraphServices<DefaultWorkspaceLocator>>,
     pub templates: Arc<dyn TemplateEngine>,
+    /// Network policy every remote fetch made through this environment honors.
+    pub network: NetworkPolicy,
 }
 
 impl SpecmanEnv {
@@ -33,17 +38,30 @@ impl SpecmanEnv {
         Self::new(locator, None)
     }
 
-    /// Create a new environment with explicit locator and optional data adapter.
+    /// Create a new environment with explicit locator and optional data adapter, using the
+    /// workspace network policy.
     pub fn new(
         locator: DefaultWorkspaceLocator,
         adapter: Option<Arc<dyn DataModelAdapter>>,
+    ) -> Result<Self, SpecmanError> {
+        let network = NetworkPolicy::for_workspace(&locator.workspace()?)?;
+        Self::with_network_policy(locator, adapter, network)
+    }
+
+    /// Create a new environment whose dependency mapping and template catalog follow `network`
+    /// instead of the workspace setting.
+    pub fn with_network_policy(
+        locator: DefaultWorkspaceLocator,
+        adapter: Option<Arc<dyn DataModelAdapter>>,
+        network: NetworkPolicy,
     ) -> Result<Self, SpecmanError> {
         let workspace = locator.workspace()?;
 
-        let mapper = FilesystemDependencyMapper::new(locator.clone());
+        let mapper = FilesystemDependencyMapper::with_network_policy(locator.clone(), network);
         let inventory = mapper.inventory_handle();
+        let graph = mapper.graph_handle();
 
-        let catalog = TemplateCatalog::new(workspace);
+        let catalog = TemplateCatalog::with_network_policy(workspace, network);
         let persistence = if let Some(a) = adapter {
             WorkspacePersistence::with_inventory_and_adapter(locator.clone(), inventory, a)
         } else {
@@ -55,7 +73,9 @@ impl SpecmanEnv {
             catalog,
             persistence,
             mapping: Arc::new(mapper),
+            graph,
             templates: Arc::new(templates),
+            network,
         })
     }
 }
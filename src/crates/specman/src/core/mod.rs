@@ -1,3 +1,6 @@
+pub mod calendar;
 pub mod env;
 pub mod error;
+pub mod network;
 pub mod shared;
+pub mod xml;
@@ -0,0 +1,366 @@
+//! Workspace network policy and the `.specman/vendor` directory that serves remote content when
+//! network access is turned off.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::core::error::SpecmanError;
+use crate::graph::lock::{LockedFetcher, walk_remote_dependencies};
+use crate::graph::tree::{ContentFetcher, HttpFetcher};
+use crate::templates::catalog::TemplateCatalog;
+use crate::workspace::WorkspacePaths;
+
+/// Workspace policy file, relative to `.specman/`.
+pub const NETWORK_POLICY_FILE: &str = "network.yaml";
+/// Environment variable that overrides the workspace policy file.
+pub const NETWORK_POLICY_ENV: &str = "SPECMAN_NETWORK";
+/// Vendored remote content, relative to `.specman/`.
+pub const VENDOR_DIR: &str = "vendor";
+
+/// Governs every remote fetch: dependency graphs, template pointers, and reference reachability.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum NetworkPolicy {
+    /// Fetch over HTTPS, preferring copies pinned in `.specman/deps.lock`.
+    #[default]
+    Allow,
+    /// Never fetch; serve `.specman/vendor`, then the lock and template caches.
+    CacheOnly,
+    /// Never fetch; serve `.specman/vendor` only.
+    Deny,
+}
+
+impl NetworkPolicy {
+    pub fn allows_network(self) -> bool {
+        self == NetworkPolicy::Allow
+    }
+
+    pub fn allows_caches(self) -> bool {
+        self != NetworkPolicy::Deny
+    }
+
+    /// Resolves the policy from `SPECMAN_NETWORK`, then `.specman/network.yaml`, defaulting to
+    /// `allow`. An unreadable or invalid setting is an error naming the value or file at fault.
+    pub fn for_workspace(workspace: &WorkspacePaths) -> Result<Self, SpecmanError> {
+        if let Ok(value) = std::env::var(NETWORK_POLICY_ENV) {
+            return value.parse().map_err(|_| {
+                SpecmanError::Workspace(format!(
+                    "invalid {NETWORK_POLICY_ENV} value `{}` (expected allow, cache-only, or deny)",
+                    value.trim()
+                ))
+            });
+        }
+        let path = workspace.dot_specman().join(NETWORK_POLICY_FILE);
+        if !path.is_file() {
+            return Ok(NetworkPolicy::Allow);
+        }
+        let content = fs::read_to_string(&path).map_err(|err| {
+            SpecmanError::Workspace(format!("failed to read {}: {err}", path.display()))
+        })?;
+        serde_yaml::from_str::<NetworkPolicyFile>(&content)
+            .map(|file| file.policy)
+            .map_err(|err| {
+                SpecmanError::Serialization(format!("invalid {}: {err}", path.display()))
+            })
+    }
+
+    /// [`Self::for_workspace`] for constructors that cannot report errors: an invalid setting
+    /// resolves to `deny` so a typo never turns the network back on.
+    pub(crate) fn for_workspace_or_deny(workspace: &WorkspacePaths) -> Self {
+        Self::for_workspace(workspace).unwrap_or(NetworkPolicy::Deny)
+    }
+}
+
+#[derive(Deserialize)]
+struct NetworkPolicyFile {
+    policy: NetworkPolicy,
+}
+
+impl FromStr for NetworkPolicy {
+    type Err = SpecmanError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "allow" => Ok(NetworkPolicy::Allow),
+            "cache-only" => Ok(NetworkPolicy::CacheOnly),
+            "deny" => Ok(NetworkPolicy::Deny),
+            other => Err(SpecmanError::Workspace(format!(
+                "unknown network policy `{other}` (expected allow, cache-only, or deny)"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for NetworkPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NetworkPolicy::Allow => "allow",
+            NetworkPolicy::CacheOnly => "cache-only",
+            NetworkPolicy::Deny => "deny",
+        })
+    }
+}
+
+/// `.specman/vendor`, mirroring each URL as `<host>/<path>`.
+#[derive(Debug, Clone)]
+pub struct VendorDirectory {
+    root: PathBuf,
+}
+
+impl VendorDirectory {
+    pub fn new(workspace: &WorkspacePaths) -> Self {
+        Self {
+            root: workspace.dot_specman().join(VENDOR_DIR),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Location of the vendored copy of `url`, whether or not it exists yet.
+    pub fn path_for(&self, url: &Url) -> PathBuf {
+        let mut path = self.root.join(url.host_str().unwrap_or("_"));
+        if let Some(port) = url.port() {
+            path.as_mut_os_string().push(format!("_{port}"));
+        }
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+                    .collect()
+            })
+            .unwrap_or_default();
+        for segment in &segments {
+            path.push(segment);
+        }
+        if segments.is_empty() || url.path().ends_with('/') {
+            path.push("index.md");
+        }
+        if let Some(query) = url.query() {
+            let digest = crate::graph::lock::sha256_hex(query);
+            path.as_mut_os_string().push(format!("_{}", &digest[..12]));
+        }
+        path
+    }
+
+    /// Vendored copy of `url`, if one exists.
+    pub fn existing(&self, url: &Url) -> Option<PathBuf> {
+        let path = self.path_for(url);
+        path.is_file().then_some(path)
+    }
+
+    pub fn write(&self, url: &Url, content: &str) -> Result<PathBuf, SpecmanError> {
+        let path = self.path_for(url);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+}
+
+/// Outcome of filling `.specman/vendor`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct VendorReport {
+    /// Workspace-relative vendor directory.
+    pub vendor_dir: String,
+    pub policy: NetworkPolicy,
+    /// Remote dependency URLs written to the vendor directory.
+    pub dependencies: Vec<String>,
+    /// Remote template pointer URLs written to the vendor directory.
+    pub templates: Vec<String>,
+}
+
+/// Copies every remote dependency and remote template pointer of the workspace into
+/// `.specman/vendor`. Content comes from the network (through `.specman/deps.lock`) under
+/// `allow`, from the lock cache under `cache-only`, and cannot be fetched under `deny`.
+pub fn vendor_workspace(workspace: &WorkspacePaths) -> Result<VendorReport, SpecmanError> {
+    let policy = NetworkPolicy::for_workspace(workspace)?;
+    vendor_workspace_with_fetcher(workspace, upstream_fetcher(workspace, policy))
+}
+
+pub fn vendor_workspace_with_fetcher(
+    workspace: &WorkspacePaths,
+    fetcher: Arc<dyn ContentFetcher>,
+) -> Result<VendorReport, SpecmanError> {
+    let vendor = VendorDirectory::new(workspace);
+    let template_urls = TemplateCatalog::new(workspace.clone()).remote_pointers()?;
+    let mut report = VendorReport {
+        vendor_dir: vendor
+            .root()
+            .strip_prefix(workspace.root())
+            .unwrap_or(vendor.root())
+            .to_string_lossy()
+            .replace('\\', "/"),
+        policy: NetworkPolicy::for_workspace(workspace)?,
+        ..Default::default()
+    };
+
+    walk_remote_dependencies(workspace, |url| {
+        let content = fetcher.fetch(url)?;
+        vendor.write(url, &content)?;
+        report.dependencies.push(url.to_string());
+        Ok(Some(content))
+    })?;
+    for url in &template_urls {
+        vendor.write(url, &fetcher.fetch(url)?)?;
+        report.templates.push(url.to_string());
+    }
+
+    report.dependencies.sort();
+    report.templates.sort();
+    Ok(report)
+}
+
+/// Fetcher for remote content under `policy`; it never opens a socket unless the policy is
+/// `allow`.
+pub(crate) fn governed_fetcher(
+    workspace: &WorkspacePaths,
+    policy: NetworkPolicy,
+) -> Arc<dyn ContentFetcher> {
+    let upstream = upstream_fetcher(workspace, policy);
+    if policy.allows_network() {
+        return upstream;
+    }
+    Arc::new(VendoredFetcher {
+        vendor: VendorDirectory::new(workspace),
+        fallback: upstream,
+    })
+}
+
+/// Source of content that is not vendored: the lock then the network for `allow`, the lock
+/// alone for `cache-only`, and nothing for `deny`.
+pub(crate) fn upstream_fetcher(
+    workspace: &WorkspacePaths,
+    policy: NetworkPolicy,
+) -> Arc<dyn ContentFetcher> {
+    match policy {
+        NetworkPolicy::Allow => {
+            Arc::new(LockedFetcher::new(workspace.clone(), Arc::new(HttpFetcher)))
+        }
+        NetworkPolicy::CacheOnly => Arc::new(LockedFetcher::new(
+            workspace.clone(),
+            Arc::new(OfflineFetcher(policy)),
+        )),
+        NetworkPolicy::Deny => Arc::new(OfflineFetcher(policy)),
+    }
+}
+
+struct VendoredFetcher {
+    vendor: VendorDirectory,
+    fallback: Arc<dyn ContentFetcher>,
+}
+
+impl ContentFetcher for VendoredFetcher {
+    fn fetch(&self, url: &Url) -> Result<String, SpecmanError> {
+        match self.vendor.existing(url) {
+            Some(path) => Ok(fs::read_to_string(path)?),
+            None => self.fallback.fetch(url),
+        }
+    }
+}
+
+/// Refuses every fetch.
+pub(crate) struct OfflineFetcher(pub(crate) NetworkPolicy);
+
+impl ContentFetcher for OfflineFetcher {
+    fn fetch(&self, url: &Url) -> Result<String, SpecmanError> {
+        Err(SpecmanError::Dependency(format!(
+            "{url} is not vendored and the `{}` network policy forbids fetching it; run `specman vendor` where the network is reachable",
+            self.0
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn workspace() -> (TempDir, WorkspacePaths) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        let paths = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        (temp, paths)
+    }
+
+    #[test]
+    fn policy_file_is_read_and_invalid_settings_fail_closed() {
+        let (_temp, workspace) = workspace();
+        assert_eq!(
+            NetworkPolicy::for_workspace(&workspace).unwrap(),
+            NetworkPolicy::Allow
+        );
+
+        let file = workspace.dot_specman().join(NETWORK_POLICY_FILE);
+        fs::write(&file, "policy: cache-only\n").unwrap();
+        assert_eq!(
+            NetworkPolicy::for_workspace(&workspace).unwrap(),
+            NetworkPolicy::CacheOnly
+        );
+
+        fs::write(&file, "policy: sometimes\n").unwrap();
+        let err = NetworkPolicy::for_workspace(&workspace).unwrap_err();
+        assert!(err.to_string().contains("network.yaml"), "{err}");
+        assert_eq!(
+            NetworkPolicy::for_workspace_or_deny(&workspace),
+            NetworkPolicy::Deny
+        );
+    }
+
+    #[test]
+    fn vendor_paths_mirror_urls() {
+        let (_temp, workspace) = workspace();
+        let vendor = VendorDirectory::new(&workspace);
+        let path = |url: &str| {
+            vendor
+                .path_for(&Url::parse(url).unwrap())
+                .strip_prefix(vendor.root())
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        };
+
+        assert_eq!(
+            path("https://example.com/specs/base.md"),
+            "example.com/specs/base.md"
+        );
+        assert_eq!(
+            path("https://example.com:8443/docs/"),
+            "example.com_8443/docs/index.md"
+        );
+        assert_eq!(
+            path("https://example.com/../etc/spec.md"),
+            "example.com/etc/spec.md"
+        );
+        assert!(path("https://example.com/spec.md?rev=2").starts_with("example.com/spec.md_"));
+    }
+
+    #[test]
+    fn denied_fetches_resolve_from_vendor_only() {
+        let (_temp, workspace) = workspace();
+        let vendored = Url::parse("https://example.com/specs/base.md").unwrap();
+        let missing = Url::parse("https://example.com/specs/other.md").unwrap();
+        VendorDirectory::new(&workspace)
+            .write(&vendored, "# Base\n")
+            .unwrap();
+
+        let fetcher = governed_fetcher(&workspace, NetworkPolicy::Deny);
+        assert_eq!(fetcher.fetch(&vendored).unwrap(), "# Base\n");
+        let err = fetcher.fetch(&missing).unwrap_err().to_string();
+        assert!(
+            err.contains("`deny` network policy forbids fetching it"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! Renders dependency graphs as Graphviz DOT, Mermaid flowcharts, or GraphML so they can be
+//! embedded in design documents and review threads.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use crate::core::error::SpecmanError;
+use crate::core::xml::escape as escape_xml;
+use crate::graph::tree::{
+    ArtifactId, ArtifactKind, ArtifactSummary, DependencyEdge, DependencyRelation, DependencyTree,
+    WorkspaceInventorySnapshot,
+};
+
+/// Output format for [`DependencyGraphExport::render`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    GraphMl,
+}
+
+impl FromStr for GraphFormat {
+    type Err = SpecmanError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "dot" => Ok(Self::Dot),
+            "mermaid" => Ok(Self::Mermaid),
+            "graphml" => Ok(Self::GraphMl),
+            other => Err(SpecmanError::Workspace(format!(
+                "unsupported graph format `{other}` (expected dot, mermaid, or graphml)"
+            ))),
+        }
+    }
+}
+
+/// Nodes and edges of a dependency graph, deduplicated by artifact identity. Edges point from
+/// the dependent artifact to its dependency.
+#[derive(Clone, Debug, Default)]
+pub struct DependencyGraphExport {
+    pub nodes: Vec<ArtifactSummary>,
+    pub edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraphExport {
+    /// Every artifact in the workspace inventory and the dependencies between them.
+    pub fn from_inventory(snapshot: &WorkspaceInventorySnapshot) -> Self {
+        let nodes = snapshot
+            .entries
+            .iter()
+            .map(|entry| entry.summary.clone())
+            .collect();
+        Self::new(nodes, snapshot.edges())
+    }
+
+    /// The root of `tree` with its upstream and downstream edges.
+    pub fn from_tree(tree: &DependencyTree) -> Self {
+        Self::new(vec![tree.root.clone()], tree.aggregate.clone())
+    }
+
+    fn new(nodes: Vec<ArtifactSummary>, mut edges: Vec<DependencyEdge>) -> Self {
+        let mut unique: BTreeMap<ArtifactId, ArtifactSummary> = BTreeMap::new();
+        for summary in nodes.into_iter().chain(
+            edges
+                .iter()
+                .flat_map(|edge| [edge.from.clone(), edge.to.clone()]),
+        ) {
+            unique.entry(summary.id.clone()).or_insert(summary);
+        }
+        edges.sort();
+        edges.dedup();
+        Self {
+            nodes: unique.into_values().collect(),
+            edges,
+        }
+    }
+
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+            GraphFormat::GraphMl => self.to_graphml(),
+        }
+    }
+
+    /// Specifications are blue boxes, implementations green components, and scratch pads
+    /// yellow notes. Downstream edges are grey; optional edges are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph specman {\n  rankdir=LR;\n  node [style=filled];\n");
+        for node in &self.nodes {
+            let (shape, color) = match node.id.kind {
+                ArtifactKind::Specification => ("box", "lightblue"),
+                ArtifactKind::Implementation => ("component", "palegreen"),
+                ArtifactKind::ScratchPad => ("note", "lightyellow"),
+            };
+            let _ = writeln!(
+                out,
+                "  \"{}\" [label=\"{}\", shape={shape}, fillcolor={color}];",
+                escape_dot(&handle(&node.id)),
+                escape_dot(&label(node))
+            );
+        }
+        for edge in &self.edges {
+            let color = match edge.relation {
+                DependencyRelation::Upstream => "black",
+                DependencyRelation::Downstream => "gray40",
+            };
+            let style = if edge.optional { "dashed" } else { "solid" };
+            let _ = writeln!(
+                out,
+                "  \"{}\" -> \"{}\" [color={color}, style={style}];",
+                escape_dot(&handle(&edge.from.id)),
+                escape_dot(&handle(&edge.to.id))
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Nodes use one class per artifact kind. Optional edges are dotted and downstream edges
+    /// are recoloured with `linkStyle`.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+        let ids: BTreeMap<&ArtifactId, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (&node.id, format!("n{idx}")))
+            .collect();
+        for node in &self.nodes {
+            let _ = writeln!(
+                out,
+                "  {}[\"{}\"]:::{}",
+                ids[&node.id],
+                label(node).replace('"', "#quot;"),
+                kind_class(node.id.kind)
+            );
+        }
+        let mut downstream = Vec::new();
+        for (idx, edge) in self.edges.iter().enumerate() {
+            let arrow = if edge.optional { "-.->" } else { "-->" };
+            let _ = writeln!(out, "  {} {arrow} {}", ids[&edge.from.id], ids[&edge.to.id]);
+            if edge.relation == DependencyRelation::Downstream {
+                downstream.push(idx.to_string());
+            }
+        }
+        out.push_str("  classDef specification fill:#add8e6,stroke:#333\n");
+        out.push_str("  classDef implementation fill:#98fb98,stroke:#333\n");
+        out.push_str("  classDef scratch_pad fill:#ffffe0,stroke:#333\n");
+        if !downstream.is_empty() {
+            let _ = writeln!(out, "  linkStyle {} stroke:#666", downstream.join(","));
+        }
+        out
+    }
+
+    /// Kind, version, and path are node attributes; relation and optionality are edge
+    /// attributes.
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (id, target, name, kind) in [
+            ("name", "node", "name", "string"),
+            ("kind", "node", "kind", "string"),
+            ("version", "node", "version", "string"),
+            ("path", "node", "path", "string"),
+            ("relation", "edge", "relation", "string"),
+            ("optional", "edge", "optional", "boolean"),
+        ] {
+            let _ = writeln!(
+                out,
+                "  <key id=\"{id}\" for=\"{target}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>"
+            );
+        }
+        out.push_str("  <graph id=\"specman\" edgedefault=\"directed\">\n");
+        for node in &self.nodes {
+            let _ = writeln!(out, "    <node id=\"{}\">", escape_xml(&handle(&node.id)));
+            let _ = writeln!(
+                out,
+                "      <data key=\"name\">{}</data>",
+                escape_xml(&node.id.name)
+            );
+            let _ = writeln!(
+                out,
+                "      <data key=\"kind\">{}</data>",
+                kind_class(node.id.kind)
+            );
+            if let Some(version) = &node.version {
+                let _ = writeln!(out, "      <data key=\"version\">{version}</data>");
+            }
+            if let Some(path) = &node.resolved_path {
+                let _ = writeln!(out, "      <data key=\"path\">{}</data>", escape_xml(path));
+            }
+            out.push_str("    </node>\n");
+        }
+        for edge in &self.edges {
+            let relation = match edge.relation {
+                DependencyRelation::Upstream => "upstream",
+                DependencyRelation::Downstream => "downstream",
+            };
+            let _ = writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\">",
+                escape_xml(&handle(&edge.from.id)),
+                escape_xml(&handle(&edge.to.id))
+            );
+            let _ = writeln!(out, "      <data key=\"relation\">{relation}</data>");
+            let _ = writeln!(out, "      <data key=\"optional\">{}</data>", edge.optional);
+            out.push_str("    </edge>\n");
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+/// Resource handle used as the node identifier, e.g. `spec://core`.
+fn handle(id: &ArtifactId) -> String {
+    let scheme = match id.kind {
+        ArtifactKind::Specification => "spec",
+        ArtifactKind::Implementation => "impl",
+        ArtifactKind::ScratchPad => "scratch",
+    };
+    format!("{scheme}://{}", id.name)
+}
+
+fn label(summary: &ArtifactSummary) -> String {
+    match &summary.version {
+        Some(version) => format!("{} {version}", summary.id.name),
+        None => summary.id.name.clone(),
+    }
+}
+
+fn kind_class(kind: ArtifactKind) -> &'static str {
+    match kind {
+        ArtifactKind::Specification => "specification",
+        ArtifactKind::Implementation => "implementation",
+        ArtifactKind::ScratchPad => "scratch_pad",
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::shared::SemVer;
+
+    fn summary(kind: ArtifactKind, name: &str) -> ArtifactSummary {
+        ArtifactSummary {
+            id: ArtifactId {
+                kind,
+                name: name.to_string(),
+            },
+            version: Some(SemVer::new(1, 0, 0)),
+            ..Default::default()
+        }
+    }
+
+    fn sample() -> DependencyGraphExport {
+        let core = summary(ArtifactKind::Specification, "core");
+        let api = summary(ArtifactKind::Specification, "api");
+        let lib = summary(ArtifactKind::Implementation, "lib");
+        DependencyGraphExport::new(
+            vec![core.clone()],
+            vec![
+                DependencyEdge {
+                    from: api.clone(),
+                    to: core.clone(),
+                    relation: DependencyRelation::Upstream,
+                    optional: true,
+                },
+                DependencyEdge {
+                    from: lib,
+                    to: core,
+                    relation: DependencyRelation::Downstream,
+                    optional: false,
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn dot_styles_nodes_by_kind_and_edges_by_relation() {
+        let dot = sample().to_dot();
+        assert!(dot.contains("\"spec://core\" [label=\"core 1.0.0\", shape=box"));
+        assert!(dot.contains("\"impl://lib\" [label=\"lib 1.0.0\", shape=component"));
+        assert!(dot.contains("\"spec://api\" -> \"spec://core\" [color=black, style=dashed];"));
+        assert!(dot.contains("\"impl://lib\" -> \"spec://core\" [color=gray40, style=solid];"));
+    }
+
+    #[test]
+    fn mermaid_and_graphml_include_every_node_and_edge() {
+        let graph = sample();
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert_eq!(mermaid.matches(":::specification").count(), 2);
+        assert!(mermaid.contains("-.->"));
+        assert!(mermaid.contains("linkStyle 1 stroke:#666"));
+
+        let graphml = graph.to_graphml();
+        assert_eq!(graphml.matches("<node id=").count(), 3);
+        assert_eq!(graphml.matches("<edge source=").count(), 2);
+        assert!(graphml.contains("<data key=\"relation\">downstream</data>"));
+    }
+}
@@ -0,0 +1,572 @@
+//! `.specman/deps.lock`: pinned copies of remote (`https://`) dependencies so graph building and
+//! reference validation resolve the same content on every run, including offline.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::core::calendar::{civil_from_days, unix_seconds};
+use crate::core::error::SpecmanError;
+use crate::core::network::{NetworkPolicy, governed_fetcher};
+use crate::graph::tree::{
+    ContentFetcher, HttpFetcher, WorkspaceInventorySnapshot, remote_dependency_urls,
+};
+use crate::workspace::{WorkspaceFederation, WorkspacePaths};
+
+/// Lock file name, relative to `.specman/`.
+pub const DEPS_LOCK_FILE: &str = "deps.lock";
+
+const LOCK_VERSION: u32 = 1;
+
+/// Pinned remote dependencies, persisted as JSON in `.specman/deps.lock`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DependencyLock {
+    pub version: u32,
+    /// Sorted by URL.
+    pub dependencies: Vec<LockedDependency>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LockedDependency {
+    pub url: String,
+    /// Hex-encoded SHA-256 of the fetched content.
+    pub sha256: String,
+    /// UTC fetch time, e.g. `2024-03-01T12:00:00Z`.
+    pub fetched_at: String,
+    /// Workspace-relative path of the cached copy.
+    pub cached: String,
+}
+
+impl Default for DependencyLock {
+    fn default() -> Self {
+        Self {
+            version: LOCK_VERSION,
+            dependencies: Vec::new(),
+        }
+    }
+}
+
+impl DependencyLock {
+    pub fn path(workspace: &WorkspacePaths) -> PathBuf {
+        workspace.dot_specman().join(DEPS_LOCK_FILE)
+    }
+
+    /// Reads `.specman/deps.lock`; a missing file yields `None`.
+    pub fn load(workspace: &WorkspacePaths) -> Result<Option<Self>, SpecmanError> {
+        let path = Self::path(workspace);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)?;
+        let lock: Self = serde_json::from_str(&content).map_err(|err| {
+            SpecmanError::Serialization(format!("invalid {}: {err}", path.display()))
+        })?;
+        if lock.version != LOCK_VERSION {
+            return Err(SpecmanError::Serialization(format!(
+                "unsupported {} version {}",
+                path.display(),
+                lock.version
+            )));
+        }
+        Ok(Some(lock))
+    }
+
+    pub fn save(&self, workspace: &WorkspacePaths) -> Result<(), SpecmanError> {
+        let mut payload = serde_json::to_string_pretty(self)?;
+        payload.push('\n');
+        fs::write(Self::path(workspace), payload)?;
+        Ok(())
+    }
+
+    pub fn get(&self, url: &str) -> Option<&LockedDependency> {
+        self.dependencies.iter().find(|entry| entry.url == url)
+    }
+}
+
+impl LockedDependency {
+    /// Reads the cached copy, failing when it is missing or no longer matches `sha256`.
+    pub fn read(&self, workspace: &WorkspacePaths) -> Result<String, SpecmanError> {
+        self.check(workspace)
+            .map_err(|(_, message)| SpecmanError::Dependency(message))
+    }
+
+    fn check(&self, workspace: &WorkspacePaths) -> Result<String, (LockIssueKind, String)> {
+        let path = workspace.root().join(&self.cached);
+        let content = fs::read_to_string(&path).map_err(|err| {
+            (
+                LockIssueKind::MissingCache,
+                format!(
+                    "locked copy of {} is unreadable at {}: {err}",
+                    self.url,
+                    path.display()
+                ),
+            )
+        })?;
+        let actual = sha256_hex(&content);
+        if actual != self.sha256 {
+            return Err((
+                LockIssueKind::HashMismatch,
+                format!(
+                    "locked copy of {} does not match {DEPS_LOCK_FILE} (expected sha256 {}, found {actual})",
+                    self.url, self.sha256
+                ),
+            ));
+        }
+        Ok(content)
+    }
+}
+
+/// Serves URLs pinned in `.specman/deps.lock` from the workspace cache and fetches everything
+/// else through `fallback`. The lock is re-read on every fetch so long-running hosts pick up
+/// `specman deps lock` without restarting.
+pub struct LockedFetcher {
+    workspace: WorkspacePaths,
+    fallback: Arc<dyn ContentFetcher>,
+}
+
+impl LockedFetcher {
+    pub fn new(workspace: WorkspacePaths, fallback: Arc<dyn ContentFetcher>) -> Self {
+        Self {
+            workspace,
+            fallback,
+        }
+    }
+}
+
+impl ContentFetcher for LockedFetcher {
+    fn fetch(&self, url: &Url) -> Result<String, SpecmanError> {
+        if let Some(lock) = DependencyLock::load(&self.workspace)?
+            && let Some(entry) = lock.get(url.as_str())
+        {
+            return entry.read(&self.workspace);
+        }
+        self.fallback.fetch(url)
+    }
+}
+
+/// Outcome of `lock` or `update`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DependencyLockReport {
+    /// Workspace-relative path of the lock file.
+    pub lock_file: String,
+    pub added: Vec<String>,
+    /// URLs whose content changed since they were last locked.
+    pub changed: Vec<String>,
+    pub unchanged: Vec<String>,
+    /// URLs no longer referenced by the workspace.
+    pub removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LockIssueKind {
+    /// Referenced by the workspace but absent from the lock.
+    Unlocked,
+    /// The cached copy is missing or unreadable.
+    MissingCache,
+    /// The cached copy no longer matches the recorded hash.
+    HashMismatch,
+    /// Locked but no longer referenced by the workspace.
+    Unused,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LockIssue {
+    pub url: String,
+    pub kind: LockIssueKind,
+    pub message: String,
+}
+
+/// Outcome of `verify`; no network access is performed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DependencyLockVerification {
+    pub lock_file: String,
+    pub verified: Vec<String>,
+    pub issues: Vec<LockIssue>,
+}
+
+impl DependencyLockVerification {
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Creates, refreshes, and checks `.specman/deps.lock` for a workspace.
+pub struct DependencyLocker {
+    workspace: WorkspacePaths,
+    fetcher: Arc<dyn ContentFetcher>,
+}
+
+impl DependencyLocker {
+    /// Fetches over HTTPS when the workspace network policy allows it and from
+    /// `.specman/vendor` otherwise.
+    pub fn new(workspace: WorkspacePaths) -> Self {
+        let fetcher: Arc<dyn ContentFetcher> =
+            match NetworkPolicy::for_workspace_or_deny(&workspace) {
+                NetworkPolicy::Allow => Arc::new(HttpFetcher),
+                policy => governed_fetcher(&workspace, policy),
+            };
+        Self::with_fetcher(workspace, fetcher)
+    }
+
+    pub fn with_fetcher(workspace: WorkspacePaths, fetcher: Arc<dyn ContentFetcher>) -> Self {
+        Self { workspace, fetcher }
+    }
+
+    /// Pins every remote dependency that is not locked yet and drops entries that are no longer
+    /// referenced. Existing entries with an intact cached copy are kept as they are.
+    pub fn lock(&self) -> Result<DependencyLockReport, SpecmanError> {
+        self.relock(false)
+    }
+
+    /// Re-fetches every remote dependency and records the new content.
+    pub fn update(&self) -> Result<DependencyLockReport, SpecmanError> {
+        self.relock(true)
+    }
+
+    /// Checks that every remote dependency is locked and every cached copy is intact.
+    pub fn verify(&self) -> Result<DependencyLockVerification, SpecmanError> {
+        let lock = DependencyLock::load(&self.workspace)?.ok_or_else(|| {
+            SpecmanError::Dependency(format!(
+                "{} not found; run `specman deps lock` first",
+                self.lock_file()
+            ))
+        })?;
+
+        let mut verification = DependencyLockVerification {
+            lock_file: self.lock_file(),
+            ..Default::default()
+        };
+        let mut referenced = BTreeSet::new();
+        walk_remote_dependencies(&self.workspace, |url| {
+            referenced.insert(url.to_string());
+            let Some(entry) = lock.get(url.as_str()) else {
+                verification.issues.push(LockIssue {
+                    url: url.to_string(),
+                    kind: LockIssueKind::Unlocked,
+                    message: format!("{url} is not pinned in {DEPS_LOCK_FILE}"),
+                });
+                return Ok(None);
+            };
+            match entry.check(&self.workspace) {
+                Ok(content) => {
+                    verification.verified.push(url.to_string());
+                    Ok(Some(content))
+                }
+                Err((kind, message)) => {
+                    verification.issues.push(LockIssue {
+                        url: url.to_string(),
+                        kind,
+                        message,
+                    });
+                    Ok(None)
+                }
+            }
+        })?;
+
+        for entry in &lock.dependencies {
+            if !referenced.contains(&entry.url) {
+                verification.issues.push(LockIssue {
+                    url: entry.url.clone(),
+                    kind: LockIssueKind::Unused,
+                    message: format!("{} is no longer referenced by the workspace", entry.url),
+                });
+            }
+        }
+        verification.verified.sort();
+        Ok(verification)
+    }
+
+    fn relock(&self, refresh: bool) -> Result<DependencyLockReport, SpecmanError> {
+        let previous = DependencyLock::load(&self.workspace)?.unwrap_or_default();
+        let cache_dir = self.cache_dir();
+        fs::create_dir_all(&cache_dir)?;
+
+        let mut report = DependencyLockReport {
+            lock_file: self.lock_file(),
+            ..Default::default()
+        };
+        let mut locked = BTreeMap::new();
+        walk_remote_dependencies(&self.workspace, |url| {
+            let existing = previous.get(url.as_str());
+            if !refresh
+                && let Some(entry) = existing
+                && let Ok(content) = entry.read(&self.workspace)
+            {
+                report.unchanged.push(url.to_string());
+                locked.insert(url.to_string(), entry.clone());
+                return Ok(Some(content));
+            }
+
+            let content = self.fetcher.fetch(url)?;
+            let sha256 = sha256_hex(&content);
+            let cached_path = cache_dir.join(format!("{sha256}.md"));
+            fs::write(&cached_path, &content)?;
+            match existing {
+                None => report.added.push(url.to_string()),
+                Some(entry) if entry.sha256 != sha256 => report.changed.push(url.to_string()),
+                Some(_) => report.unchanged.push(url.to_string()),
+            }
+            locked.insert(
+                url.to_string(),
+                LockedDependency {
+                    url: url.to_string(),
+                    sha256,
+                    fetched_at: utc_timestamp(SystemTime::now()),
+                    cached: workspace_relative(self.workspace.root(), &cached_path),
+                },
+            );
+            Ok(Some(content))
+        })?;
+
+        report.removed = previous
+            .dependencies
+            .iter()
+            .filter(|entry| !locked.contains_key(&entry.url))
+            .map(|entry| entry.url.clone())
+            .collect();
+
+        let lock = DependencyLock {
+            version: LOCK_VERSION,
+            dependencies: locked.into_values().collect(),
+        };
+        lock.save(&self.workspace)?;
+        prune_cache(&cache_dir, &lock)?;
+
+        report.added.sort();
+        report.changed.sort();
+        report.unchanged.sort();
+        Ok(report)
+    }
+
+    fn cache_dir(&self) -> PathBuf {
+        self.workspace.dot_specman().join("cache").join("deps")
+    }
+
+    fn lock_file(&self) -> String {
+        workspace_relative(
+            self.workspace.root(),
+            &DependencyLock::path(&self.workspace),
+        )
+    }
+}
+
+/// Visits every remote dependency reachable from the workspace, following the dependencies of
+/// each document `visit` returns content for.
+pub(crate) fn walk_remote_dependencies(
+    workspace: &WorkspacePaths,
+    mut visit: impl FnMut(&Url) -> Result<Option<String>, SpecmanError>,
+) -> Result<(), SpecmanError> {
+    // Workspace artifacts are read from disk, so the inventory never uses this fetcher.
+    let fetcher = governed_fetcher(workspace, NetworkPolicy::for_workspace(workspace)?);
+    let inventory = WorkspaceInventorySnapshot::build(workspace, fetcher)?;
+    let federation = WorkspaceFederation::load(workspace)?;
+    let mut queue: VecDeque<Url> = inventory.remote_urls().into_iter().collect();
+    let mut seen: BTreeSet<Url> = queue.iter().cloned().collect();
+    while let Some(url) = queue.pop_front() {
+        let Some(content) = visit(&url)? else {
+            continue;
+        };
+        for next in remote_dependency_urls(&url, &content, workspace, &federation)? {
+            if seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes cached copies that no lock entry points at anymore.
+fn prune_cache(cache_dir: &Path, lock: &DependencyLock) -> Result<(), SpecmanError> {
+    let keep: BTreeSet<String> = lock
+        .dependencies
+        .iter()
+        .map(|entry| format!("{}.md", entry.sha256))
+        .collect();
+    for entry in fs::read_dir(cache_dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if path.is_file() && !keep.contains(name) {
+            fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn sha256_hex(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+fn workspace_relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn utc_timestamp(time: SystemTime) -> String {
+    let secs = unix_seconds(time);
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let seconds = secs % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::{Duration, UNIX_EPOCH};
+    use tempfile::TempDir;
+
+    struct StubFetcher {
+        documents: Mutex<BTreeMap<String, String>>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl StubFetcher {
+        fn new(documents: &[(&str, &str)]) -> Arc<Self> {
+            Arc::new(Self {
+                documents: Mutex::new(
+                    documents
+                        .iter()
+                        .map(|(url, body)| (url.to_string(), body.to_string()))
+                        .collect(),
+                ),
+                calls: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn set(&self, url: &str, body: &str) {
+            self.documents
+                .lock()
+                .unwrap()
+                .insert(url.to_string(), body.to_string());
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.lock().unwrap().len()
+        }
+    }
+
+    impl ContentFetcher for StubFetcher {
+        fn fetch(&self, url: &Url) -> Result<String, SpecmanError> {
+            self.calls.lock().unwrap().push(url.to_string());
+            self.documents
+                .lock()
+                .unwrap()
+                .get(url.as_str())
+                .cloned()
+                .ok_or_else(|| SpecmanError::Dependency(format!("no stub for {url}")))
+        }
+    }
+
+    const BASE: &str = "https://example.com/specs/base.md";
+    const SHARED: &str = "https://example.com/specs/shared.md";
+
+    fn workspace() -> (TempDir, WorkspacePaths) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        let spec_dir = root.join("spec/local");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(
+            spec_dir.join("spec.md"),
+            format!(
+                "---\nname: local\nversion: \"1.0.0\"\ndependencies:\n  - {BASE}\n---\n# Local\n"
+            ),
+        )
+        .unwrap();
+        let paths = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        (temp, paths)
+    }
+
+    fn stub() -> Arc<StubFetcher> {
+        StubFetcher::new(&[
+            (
+                BASE,
+                "---\nname: base\nversion: \"1.0.0\"\ndependencies:\n  - shared.md\n---\n# Base\n",
+            ),
+            (
+                SHARED,
+                "---\nname: shared\nversion: \"1.0.0\"\n---\n# Shared\n",
+            ),
+        ])
+    }
+
+    #[test]
+    fn lock_pins_transitive_remote_dependencies_and_serves_them_offline() {
+        let (_temp, workspace) = workspace();
+        let fetcher = stub();
+        let locker = DependencyLocker::with_fetcher(workspace.clone(), fetcher.clone());
+
+        let report = locker.lock().unwrap();
+        assert_eq!(report.added, vec![BASE.to_string(), SHARED.to_string()]);
+        assert_eq!(report.lock_file, ".specman/deps.lock");
+
+        let lock = DependencyLock::load(&workspace).unwrap().unwrap();
+        let base = lock.get(BASE).unwrap();
+        assert!(base.cached.starts_with(".specman/cache/deps/"));
+        assert!(base.fetched_at.ends_with('Z'));
+        assert!(base.read(&workspace).unwrap().contains("# Base"));
+
+        // A second lock reuses the cached copies.
+        let calls = fetcher.calls();
+        let report = locker.lock().unwrap();
+        assert_eq!(report.unchanged.len(), 2);
+        assert_eq!(fetcher.calls(), calls);
+
+        let offline = LockedFetcher::new(workspace.clone(), StubFetcher::new(&[]));
+        let content = offline.fetch(&Url::parse(SHARED).unwrap()).unwrap();
+        assert!(content.contains("# Shared"));
+    }
+
+    #[test]
+    fn update_refetches_and_verify_reports_tampered_copies() {
+        let (_temp, workspace) = workspace();
+        let fetcher = stub();
+        let locker = DependencyLocker::with_fetcher(workspace.clone(), fetcher.clone());
+        locker.lock().unwrap();
+        assert!(locker.verify().unwrap().is_clean());
+
+        fetcher.set(
+            SHARED,
+            "---\nname: shared\nversion: \"1.1.0\"\n---\n# Shared v2\n",
+        );
+        let report = locker.update().unwrap();
+        assert_eq!(report.changed, vec![SHARED.to_string()]);
+        assert_eq!(report.unchanged, vec![BASE.to_string()]);
+        let cached = fs::read_dir(workspace.dot_specman().join("cache/deps"))
+            .unwrap()
+            .count();
+        assert_eq!(cached, 2, "superseded copies are pruned");
+
+        let lock = DependencyLock::load(&workspace).unwrap().unwrap();
+        let shared = lock.get(SHARED).unwrap();
+        fs::write(workspace.root().join(&shared.cached), "tampered").unwrap();
+        let verification = locker.verify().unwrap();
+        assert_eq!(verification.verified, vec![BASE.to_string()]);
+        assert_eq!(verification.issues.len(), 1);
+        assert_eq!(verification.issues[0].kind, LockIssueKind::HashMismatch);
+    }
+
+    #[test]
+    fn utc_timestamp_formats_rfc3339() {
+        let time = UNIX_EPOCH + Duration::from_secs(19_782 * 86_400 + 3_723);
+        assert_eq!(utc_timestamp(time), "2024-02-29T01:02:03Z");
+    }
+}
@@ -1 +1,3 @@
+pub mod export;
+pub mod lock;
 pub mod tree;
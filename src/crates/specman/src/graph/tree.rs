@@ -1,18 +1,24 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use schemars::JsonSchema;
+use semver::VersionReq;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::core::error::SpecmanError;
+use crate::core::network::{NetworkPolicy, OfflineFetcher, governed_fetcher};
 
-use crate::metadata::frontmatter::{self, ArtifactFrontMatter, DependencyEntry, FrontMatterKind};
 use crate::core::shared::SemVer;
-use crate::workspace::{normalize_workspace_path, WorkspaceLocator, WorkspacePaths};
+use crate::metadata::frontmatter::{self, ArtifactFrontMatter, DependencyEntry, FrontMatterKind};
+use crate::workspace::{
+    PeerWorkspace, WORKSPACES_FILE, WorkspaceFederation, WorkspaceLocator, WorkspacePaths,
+    normalize_workspace_path,
+};
 use std::fmt;
 
 /// Fetches remote artifact content (e.g., HTTPS markdown documents).
@@ -22,7 +28,7 @@ pub trait ContentFetcher: Send + Sync {
 
 /// Default HTTPS fetcher backed by `ureq`.
 #[derive(Default)]
-struct HttpFetcher;
+pub(crate) struct HttpFetcher;
 
 impl ContentFetcher for HttpFetcher {
     fn fetch(&self, url: &Url) -> Result<String, SpecmanError> {
@@ -78,6 +84,22 @@ pub enum ArtifactKind {
     ScratchPad,
 }
 
+impl std::str::FromStr for ArtifactKind {
+    type Err = SpecmanError;
+
+    /// Parses the short kind names used by handles and CLI flags: `spec`, `impl`, `scratch`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "spec" => Ok(ArtifactKind::Specification),
+            "impl" => Ok(ArtifactKind::Implementation),
+            "scratch" => Ok(ArtifactKind::ScratchPad),
+            other => Err(SpecmanError::Workspace(format!(
+                "unknown artifact kind `{other}` (expected spec, impl, or scratch)"
+            ))),
+        }
+    }
+}
+
 /// Tracks how a locator was resolved so callers can distinguish strict paths from
 /// best-effort fallbacks that preserve context without guaranteeing mutability.
 #[derive(
@@ -194,6 +216,14 @@ impl<L: WorkspaceLocator> FilesystemDependencyMapper<L> {
         }
     }
 
+    pub fn with_network_policy(workspace: L, policy: NetworkPolicy) -> Self {
+        Self {
+            graph: Arc::new(DependencyGraphServices::with_network_policy(
+                workspace, policy,
+            )),
+        }
+    }
+
     /// Returns the shared dependency graph services so callers can opt into
     /// read-only snapshots without rebuilding traversal state.
     pub fn dependency_graph(&self) -> &DependencyGraphServices<L> {
@@ -270,12 +300,23 @@ pub struct DependencyGraphServices<L: WorkspaceLocator> {
 }
 
 impl<L: WorkspaceLocator> DependencyGraphServices<L> {
+    /// Fetches remote dependencies under the workspace network policy, serving URLs pinned in
+    /// `.specman/deps.lock` from the workspace cache.
     pub fn new(workspace: L) -> Self {
-        Self {
-            workspace,
-            fetcher: Arc::new(HttpFetcher),
-            inventory_cache: Mutex::new(None),
-        }
+        let policy = workspace
+            .workspace()
+            .map(|paths| NetworkPolicy::for_workspace_or_deny(&paths))
+            .unwrap_or_default();
+        Self::with_network_policy(workspace, policy)
+    }
+
+    pub fn with_network_policy(workspace: L, policy: NetworkPolicy) -> Self {
+        let fetcher = match workspace.workspace() {
+            Ok(paths) => governed_fetcher(&paths, policy),
+            Err(_) if policy.allows_network() => Arc::new(HttpFetcher),
+            Err(_) => Arc::new(OfflineFetcher(policy)),
+        };
+        Self::with_fetcher(workspace, fetcher)
     }
 
     pub fn with_fetcher(workspace: L, fetcher: Arc<dyn ContentFetcher>) -> Self {
@@ -292,11 +333,13 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
     ) -> Result<DependencyTree, SpecmanError> {
         // [ENSURES: concept-dependency-mapping-services.requirements:CHECK]
         let workspace = self.workspace_paths()?;
-        let locator = ArtifactLocator::from_path(path.as_ref(), &workspace, None)?;
+        let federation = WorkspaceFederation::load(&workspace)?;
+        let locator = ArtifactLocator::from_path(path.as_ref(), &workspace, &federation, None)?;
         self.build_tree_with_workspace(
             locator,
             ResolutionProvenance::Strict,
             workspace,
+            federation,
             DependencyResolutionMode::Strict,
         )
     }
@@ -306,11 +349,13 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
         reference: &str,
     ) -> Result<DependencyTree, SpecmanError> {
         let workspace = self.workspace_paths()?;
-        let locator = ArtifactLocator::from_reference(reference, &workspace)?;
+        let federation = WorkspaceFederation::load(&workspace)?;
+        let locator = ArtifactLocator::from_reference(reference, &workspace, &federation)?;
         self.build_tree_with_workspace(
             locator,
             ResolutionProvenance::Strict,
             workspace,
+            federation,
             DependencyResolutionMode::Strict,
         )
     }
@@ -323,15 +368,18 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
         reference: &str,
     ) -> Result<DependencyTree, SpecmanError> {
         let workspace = self.workspace_paths()?;
-        let (locator, resolution) = match ArtifactLocator::from_reference(reference, &workspace) {
-            Ok(locator) => (locator, ResolutionProvenance::Strict),
-            Err(err) => best_effort_locator(reference, &workspace).ok_or(err)?,
-        };
+        let federation = WorkspaceFederation::load(&workspace)?;
+        let (locator, resolution) =
+            match ArtifactLocator::from_reference(reference, &workspace, &federation) {
+                Ok(locator) => (locator, ResolutionProvenance::Strict),
+                Err(err) => best_effort_locator(reference, &workspace, &federation).ok_or(err)?,
+            };
 
         self.build_tree_with_workspace(
             locator,
             resolution,
             workspace,
+            federation,
             DependencyResolutionMode::BestEffort,
         )
     }
@@ -345,11 +393,13 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
         root: &ArtifactId,
     ) -> Result<DependencyTree, SpecmanError> {
         let workspace = self.workspace_paths()?;
-        let locator = self.locator_for_artifact(root, &workspace)?;
+        let federation = WorkspaceFederation::load(&workspace)?;
+        let locator = self.locator_for_artifact(root, &workspace, &federation)?;
         self.build_tree_with_workspace(
             locator,
             ResolutionProvenance::Strict,
             workspace,
+            federation,
             DependencyResolutionMode::Strict,
         )
     }
@@ -359,35 +409,41 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
         self.inventory_with_workspace(&workspace)
     }
 
-    pub fn detect_cycles(&self) -> Result<Vec<String>, SpecmanError> {
+    /// Finds every dependency cycle in the workspace inventory with a single
+    /// strongly-connected-components pass instead of one tree build per artifact.
+    pub fn detect_cycles(&self) -> Result<Vec<DependencyCycle>, SpecmanError> {
         // [ENSURES: concept-dependency-mapping-services.cycle-detection:CHECK]
-        let inventory = self.inventory_snapshot()?;
-        let mut cycles = BTreeSet::new();
-
-        for entry in inventory.entries.iter() {
-            match self.dependency_tree_for_artifact(&entry.summary.id) {
-                Ok(_) => {}
-                Err(SpecmanError::Dependency(msg)) if msg.contains("dependency cycle detected") => {
-                    // Extract the cycle description part before any serialization data
-                    let clean_msg = msg
-                        .split(';')
-                        .next()
-                        .unwrap_or(&msg)
-                        .trim()
-                        .replace("dependency cycle detected: ", "");
-                    cycles.insert(clean_msg);
-                }
-                Err(_) => {}
-            }
-        }
-
-        Ok(cycles.into_iter().collect())
+        Ok(self.inventory_snapshot()?.cycles())
     }
 
     pub fn invalidate_inventory(&self) {
         self.inventory_cache.lock().unwrap().take();
     }
 
+    /// Applies per-path changes to the cached inventory instead of discarding it.
+    ///
+    /// Paths may name artifact files or directories containing them. Nothing happens when no
+    /// inventory has been built yet; the next traversal builds it from scratch. If a changed
+    /// artifact fails to load, the cache is dropped and the error returned.
+    pub fn refresh_inventory_paths(&self, paths: &[PathBuf]) -> Result<(), SpecmanError> {
+        let mut cache = self.inventory_cache.lock().unwrap();
+        let Some(snapshot) = cache.as_ref() else {
+            return Ok(());
+        };
+
+        let workspace = self.workspace_paths()?;
+        match snapshot.refreshed(&workspace, self.fetcher.clone(), paths) {
+            Ok(refreshed) => {
+                *cache = Some(refreshed);
+                Ok(())
+            }
+            Err(err) => {
+                cache.take();
+                Err(err)
+            }
+        }
+    }
+
     fn workspace_paths(&self) -> Result<WorkspacePaths, SpecmanError> {
         self.workspace.workspace()
     }
@@ -397,9 +453,11 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
         root_locator: ArtifactLocator,
         root_resolution: ResolutionProvenance,
         workspace: WorkspacePaths,
+        federation: WorkspaceFederation,
         mode: DependencyResolutionMode,
     ) -> Result<DependencyTree, SpecmanError> {
-        let mut traversal = Traversal::new(workspace.clone(), self.fetcher.clone(), mode);
+        let mut traversal =
+            Traversal::new(workspace.clone(), federation, self.fetcher.clone(), mode);
         let root = traversal.visit(&root_locator, root_resolution)?;
         let mut aggregate: BTreeSet<_> = traversal.edges.clone();
 
@@ -440,6 +498,7 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
         &self,
         root: &ArtifactId,
         workspace: &WorkspacePaths,
+        federation: &WorkspaceFederation,
     ) -> Result<ArtifactLocator, SpecmanError> {
         let base = match root.kind {
             ArtifactKind::Specification => workspace.spec_dir().join(&root.name).join("spec.md"),
@@ -450,7 +509,7 @@ impl<L: WorkspaceLocator> DependencyGraphServices<L> {
                 .join("scratch.md"),
         };
 
-        ArtifactLocator::from_path(base, workspace, None)
+        ArtifactLocator::from_path(base, workspace, federation, None)
     }
 
     fn inventory_with_workspace(
@@ -533,6 +592,12 @@ pub struct ArtifactDependency {
     pub locator: ArtifactLocator,
     pub optional: bool,
     pub resolution: ResolutionProvenance,
+    /// Semver requirement declared on the dependency entry, if any.
+    pub requirement: Option<VersionReq>,
+    /// Front-matter field declaring the dependency, e.g. `dependencies` or `spec`.
+    pub field: &'static str,
+    /// Reference as written in the front matter.
+    pub reference: String,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -557,10 +622,12 @@ pub enum ArtifactLocator {
 }
 
 /// Canonical parser for `spec://`, `impl://`, and `scratch://` resource handles as defined by
-/// SpecMan Core's Dependency Mapping Services concept.
+/// SpecMan Core's Dependency Mapping Services concept. A `<peer>:` prefix on the identifier
+/// (`spec://platform:auth`) addresses a peer declared in `.specman/workspaces.yaml`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct ResourceHandle {
     kind: ArtifactKind,
+    workspace: Option<String>,
     slug: String,
 }
 
@@ -595,8 +662,16 @@ impl ResourceHandle {
     }
 
     fn new(kind: ArtifactKind, raw_slug: &str) -> Result<Self, SpecmanError> {
+        let (workspace, raw_slug) = match raw_slug.split_once(':') {
+            Some((peer, slug)) => (Some(Self::canonical_slug(peer)?), slug),
+            None => (None, raw_slug),
+        };
         let slug = Self::canonical_slug(raw_slug)?;
-        Ok(Self { kind, slug })
+        Ok(Self {
+            kind,
+            workspace,
+            slug,
+        })
     }
 
     fn canonical_slug(raw: &str) -> Result<String, SpecmanError> {
@@ -637,8 +712,25 @@ impl ResourceHandle {
         }
     }
 
-    fn into_locator(self, workspace: &WorkspacePaths) -> Result<ArtifactLocator, SpecmanError> {
-        ArtifactLocator::from_path(self.to_path(workspace), workspace, Some(workspace.root()))
+    /// Workspace the handle points into: `workspace` itself, or the named peer.
+    fn home(
+        &self,
+        workspace: &WorkspacePaths,
+        federation: &WorkspaceFederation,
+    ) -> Result<WorkspacePaths, SpecmanError> {
+        match &self.workspace {
+            Some(peer) => Ok(federation.peer(peer)?.paths().clone()),
+            None => Ok(workspace.clone()),
+        }
+    }
+
+    fn into_locator(
+        self,
+        workspace: &WorkspacePaths,
+        federation: &WorkspaceFederation,
+    ) -> Result<ArtifactLocator, SpecmanError> {
+        let path = self.to_path(&self.home(workspace, federation)?);
+        ArtifactLocator::from_path(path, workspace, federation, Some(workspace.root()))
     }
 }
 
@@ -646,16 +738,21 @@ impl ArtifactLocator {
     fn from_path(
         path: impl AsRef<Path>,
         workspace: &WorkspacePaths,
+        federation: &WorkspaceFederation,
         base: Option<&Path>,
     ) -> Result<Self, SpecmanError> {
-        let resolved = resolve_workspace_path(path.as_ref(), base, workspace)?;
+        let resolved = resolve_workspace_path(path.as_ref(), base, workspace, federation)?;
         Ok(Self::File(resolved))
     }
 
     /// Parses any supported dependency reference, including workspace paths, HTTPS URLs, and
     /// SpecMan resource handles (`spec://`, `impl://`, `scratch://`). Relative paths are
     /// interpreted from the workspace root.
-    fn from_reference(reference: &str, workspace: &WorkspacePaths) -> Result<Self, SpecmanError> {
+    fn from_reference(
+        reference: &str,
+        workspace: &WorkspacePaths,
+        federation: &WorkspaceFederation,
+    ) -> Result<Self, SpecmanError> {
         if reference.starts_with("http://") {
             return Err(SpecmanError::Dependency(format!(
                 "unsupported url scheme in {reference}; use https"
@@ -667,10 +764,10 @@ impl ArtifactLocator {
         }
 
         if let Some(handle) = ResourceHandle::parse(reference)? {
-            return handle.into_locator(workspace);
+            return handle.into_locator(workspace, federation);
         }
 
-        ArtifactLocator::from_path(reference, workspace, Some(workspace.root()))
+        ArtifactLocator::from_path(reference, workspace, federation, Some(workspace.root()))
     }
 
     fn from_url(url: &str) -> Result<Self, SpecmanError> {
@@ -723,6 +820,7 @@ impl ArtifactLocator {
 
 struct Traversal {
     workspace: WorkspacePaths,
+    federation: WorkspaceFederation,
     edges: BTreeSet<DependencyEdge>,
     visited: HashMap<String, ArtifactSummary>,
     stack: Vec<String>,
@@ -733,11 +831,13 @@ struct Traversal {
 impl Traversal {
     fn new(
         workspace: WorkspacePaths,
+        federation: WorkspaceFederation,
         fetcher: Arc<dyn ContentFetcher>,
         mode: DependencyResolutionMode,
     ) -> Self {
         Self {
             workspace,
+            federation,
             edges: BTreeSet::new(),
             visited: HashMap::new(),
             stack: Vec::new(),
@@ -804,6 +904,7 @@ impl Traversal {
         let document = ArtifactDocument::load(
             locator,
             &self.workspace,
+            &self.federation,
             self.fetcher.as_ref(),
             self.mode,
             resolution,
@@ -835,23 +936,29 @@ impl Traversal {
 #[derive(Clone)]
 pub struct WorkspaceInventorySnapshot {
     pub entries: Arc<Vec<InventoryEntry>>,
+    /// Read-only artifacts from federated peers that workspace artifacts depend on, directly
+    /// or transitively. They are consulted for requirement checks but never reported as part
+    /// of this workspace.
+    pub peers: Arc<Vec<InventoryEntry>>,
 }
 
 impl WorkspaceInventorySnapshot {
-    fn build(
+    pub(crate) fn build(
         workspace: &WorkspacePaths,
         fetcher: Arc<dyn ContentFetcher>,
     ) -> Result<Self, SpecmanError> {
+        let federation = WorkspaceFederation::load(workspace)?;
         let mut files = gather_workspace_artifacts(workspace)?;
         files.sort();
         files.dedup();
 
         let mut entries = Vec::new();
         for file in files {
-            let locator = ArtifactLocator::from_path(&file, workspace, None)?;
+            let locator = ArtifactLocator::from_path(&file, workspace, &federation, None)?;
             let document = ArtifactDocument::load(
                 &locator,
                 workspace,
+                &federation,
                 fetcher.as_ref(),
                 DependencyResolutionMode::BestEffort,
                 ResolutionProvenance::Strict,
@@ -862,11 +969,95 @@ impl WorkspaceInventorySnapshot {
             });
         }
 
+        let peers = load_peer_entries(workspace, &federation, fetcher.as_ref(), &entries)?;
         Ok(Self {
             entries: Arc::new(entries),
+            peers: Arc::new(peers),
         })
     }
 
+    /// URLs of remote dependencies declared directly by workspace artifacts.
+    pub(crate) fn remote_urls(&self) -> BTreeSet<Url> {
+        self.entries
+            .iter()
+            .flat_map(|entry| entry.dependencies.iter())
+            .filter_map(|dependency| match &dependency.locator {
+                ArtifactLocator::Url(url) => Some(url.clone()),
+                ArtifactLocator::File(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns a copy of this snapshot with the entries at or under `paths` reloaded from disk.
+    fn refreshed(
+        &self,
+        workspace: &WorkspacePaths,
+        fetcher: Arc<dyn ContentFetcher>,
+        paths: &[PathBuf],
+    ) -> Result<Self, SpecmanError> {
+        let mut entries: BTreeMap<PathBuf, InventoryEntry> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let path = entry.summary.resolved_path.as_ref()?;
+                Some((PathBuf::from(path), entry.clone()))
+            })
+            .collect();
+
+        let layouts = [
+            (workspace.spec_dir(), "spec.md"),
+            (workspace.impl_dir(), "impl.md"),
+            (workspace.scratchpad_dir(), "scratch.md"),
+        ];
+
+        let federation = WorkspaceFederation::load(workspace)?;
+        let mut files = Vec::new();
+        for path in paths {
+            let path = &normalize_workspace_path(path);
+            entries.retain(|existing, _| !existing.starts_with(path));
+
+            for (root, file_name) in &layouts {
+                if path.is_dir() {
+                    if path.starts_with(root) {
+                        collect_named_files(path, file_name, &mut files)?;
+                    } else if root.starts_with(path) {
+                        collect_named_files(root, file_name, &mut files)?;
+                    }
+                } else if path.is_file()
+                    && path.starts_with(root)
+                    && path.file_name() == Some(OsStr::new(file_name))
+                {
+                    files.push(path.clone());
+                }
+            }
+        }
+
+        for file in files {
+            let locator = ArtifactLocator::from_path(&file, workspace, &federation, None)?;
+            let document = ArtifactDocument::load(
+                &locator,
+                workspace,
+                &federation,
+                fetcher.as_ref(),
+                DependencyResolutionMode::BestEffort,
+                ResolutionProvenance::Strict,
+            )?;
+            entries.insert(
+                PathBuf::from(locator.describe()),
+                InventoryEntry {
+                    summary: document.summary,
+                    dependencies: document.dependencies,
+                },
+            );
+        }
+
+        let entries: Vec<InventoryEntry> = entries.into_values().collect();
+        let peers = load_peer_entries(workspace, &federation, fetcher.as_ref(), &entries)?;
+        Ok(Self {
+            entries: Arc::new(entries),
+            peers: Arc::new(peers),
+        })
+    }
 
     pub fn dependents_of(&self, target: &Path) -> Vec<InventoryDependent> {
         let mut dependents = Vec::new();
@@ -891,6 +1082,258 @@ impl WorkspaceInventorySnapshot {
 
         dependents
     }
+
+    /// Version requirements declared by `entry` that their targets do not satisfy. Targets
+    /// missing from the inventory are skipped; dependency resolution reports those.
+    pub fn unmet_requirements(&self, entry: &InventoryEntry) -> Vec<VersionMismatch> {
+        self.requirements_failing(entry, |target| Some(target.summary.version.clone()))
+    }
+
+    /// Requirements on the artifact at `path` that hold at `previous` but would fail once it
+    /// declares `version`. Requirements that already fail are left to `unmet_requirements`.
+    pub fn mismatches_at_version(
+        &self,
+        path: &Path,
+        previous: &SemVer,
+        version: &SemVer,
+    ) -> Vec<VersionMismatch> {
+        let at = |candidate: &InventoryEntry, version: &SemVer| {
+            let resolved = candidate.summary.resolved_path.as_deref()?;
+            (Path::new(resolved) == path).then(|| Some(version.clone()))
+        };
+        self.entries
+            .iter()
+            .flat_map(|entry| {
+                let before = self.requirements_failing(entry, |target| at(target, previous));
+                self.requirements_failing(entry, |target| at(target, version))
+                    .into_iter()
+                    .filter(move |mismatch| {
+                        !before.iter().any(|failed| {
+                            failed.target == mismatch.target
+                                && failed.requirement == mismatch.requirement
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    /// Requirements declared by `entry` whose targets fail them. `version_of` gives the version
+    /// to check a target against, or `None` to skip the target.
+    fn requirements_failing(
+        &self,
+        entry: &InventoryEntry,
+        version_of: impl Fn(&InventoryEntry) -> Option<Option<SemVer>>,
+    ) -> Vec<VersionMismatch> {
+        let mut mismatches = Vec::new();
+        for dependency in &entry.dependencies {
+            let Some(requirement) = &dependency.requirement else {
+                continue;
+            };
+            let ArtifactLocator::File(path) = &dependency.locator else {
+                continue;
+            };
+            let Some(target) = self
+                .entries
+                .iter()
+                .chain(self.peers.iter())
+                .find(|candidate| {
+                    candidate
+                        .summary
+                        .resolved_path
+                        .as_deref()
+                        .is_some_and(|resolved| Path::new(resolved) == path)
+                })
+            else {
+                continue;
+            };
+            let Some(found) = version_of(target) else {
+                continue;
+            };
+            if found
+                .as_ref()
+                .is_some_and(|version| requirement.matches(version))
+            {
+                continue;
+            }
+            mismatches.push(VersionMismatch {
+                dependent: entry.summary.id.clone(),
+                target: target.summary.id.clone(),
+                requirement: requirement.to_string(),
+                found,
+            });
+        }
+        mismatches
+    }
+
+    /// Dependency edges between inventory entries, pointing from each dependent to its
+    /// dependency. Dependencies that are remote or missing from the inventory are skipped.
+    pub fn edges(&self) -> Vec<DependencyEdge> {
+        let mut edges = BTreeSet::new();
+        for entry in self.entries.iter() {
+            for dependency in &entry.dependencies {
+                let ArtifactLocator::File(path) = &dependency.locator else {
+                    continue;
+                };
+                let Some(target) = self.entries.iter().find(|candidate| {
+                    candidate
+                        .summary
+                        .resolved_path
+                        .as_deref()
+                        .is_some_and(|resolved| Path::new(resolved) == path)
+                }) else {
+                    continue;
+                };
+                edges.insert(DependencyEdge {
+                    from: entry.summary.clone(),
+                    to: target.summary.clone(),
+                    relation: DependencyRelation::Upstream,
+                    optional: dependency.optional,
+                });
+            }
+        }
+        edges.into_iter().collect()
+    }
+
+    /// Dependency cycles between inventory entries, one per strongly connected component,
+    /// ordered by their first artifact. Each cycle starts at the component's smallest artifact
+    /// and follows the shortest path back to it.
+    pub fn cycles(&self) -> Vec<DependencyCycle> {
+        let entries = &self.entries;
+        let positions: HashMap<&Path, usize> = entries
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, entry)| {
+                Some((Path::new(entry.summary.resolved_path.as_deref()?), idx))
+            })
+            .collect();
+        let adjacency: Vec<Vec<(usize, &ArtifactDependency)>> = entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .dependencies
+                    .iter()
+                    .filter_map(|dependency| match &dependency.locator {
+                        ArtifactLocator::File(path) => {
+                            Some((*positions.get(path.as_path())?, dependency))
+                        }
+                        ArtifactLocator::Url(_) => None,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut cycles = Vec::new();
+        for component in strongly_connected_components(&adjacency) {
+            let Some(&start) = component
+                .iter()
+                .min_by(|a, b| entries[**a].summary.id.cmp(&entries[**b].summary.id))
+            else {
+                continue;
+            };
+            let members: BTreeSet<usize> = component.iter().copied().collect();
+            if let Some(path) = shortest_cycle(&adjacency, &members, start) {
+                cycles.push(self.describe_cycle(&path));
+            }
+        }
+        cycles.sort_by(|a, b| a.artifacts.cmp(&b.artifacts));
+        cycles
+    }
+
+    fn describe_cycle(&self, path: &[(usize, &ArtifactDependency)]) -> DependencyCycle {
+        let mut artifacts = Vec::new();
+        let mut edges = Vec::new();
+        for (idx, (from, dependency)) in path.iter().enumerate() {
+            let (to, _) = path[(idx + 1) % path.len()];
+            let source = &self.entries[*from].summary;
+            let file = source.resolved_path.clone();
+            let line = file.as_deref().and_then(|file| {
+                front_matter_line(Path::new(file), dependency.field, &dependency.reference)
+            });
+            artifacts.push(source.id.clone());
+            edges.push(CycleEdge {
+                from: source.id.clone(),
+                to: self.entries[to].summary.id.clone(),
+                field: dependency.field.to_string(),
+                reference: dependency.reference.clone(),
+                path: file,
+                line,
+            });
+        }
+        DependencyCycle { artifacts, edges }
+    }
+
+    /// Unmet version requirements across every inventory entry.
+    pub fn version_mismatches(&self) -> Vec<VersionMismatch> {
+        self.entries
+            .iter()
+            .flat_map(|entry| self.unmet_requirements(entry))
+            .collect()
+    }
+}
+
+/// Dependency whose semver requirement is not satisfied by the target's declared version.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct VersionMismatch {
+    pub dependent: ArtifactId,
+    pub target: ArtifactId,
+    pub requirement: String,
+    /// Version declared by the target; `None` when it has no valid version.
+    pub found: Option<SemVer>,
+}
+
+impl fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.found {
+            Some(version) => write!(
+                f,
+                "{} requires {} `{}`, found {}",
+                self.dependent, self.target, self.requirement, version
+            ),
+            None => write!(
+                f,
+                "{} requires {} `{}`, but it declares no version",
+                self.dependent, self.target, self.requirement
+            ),
+        }
+    }
+}
+
+/// Dependency cycle found in the workspace inventory.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct DependencyCycle {
+    /// Artifacts in dependency order; the last one depends on the first.
+    pub artifacts: Vec<ArtifactId>,
+    /// `edges[i]` runs from `artifacts[i]` to the next artifact, wrapping around.
+    pub edges: Vec<CycleEdge>,
+}
+
+/// Front-matter entry responsible for one edge of a [`DependencyCycle`].
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CycleEdge {
+    pub from: ArtifactId,
+    pub to: ArtifactId,
+    /// Front-matter field declaring the dependency, e.g. `dependencies` or `spec`.
+    pub field: String,
+    /// Reference as written in the front matter.
+    pub reference: String,
+    /// File declaring the dependency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// 1-based line of the reference within `path`, when it could be located.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for DependencyCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for id in &self.artifacts {
+            write!(f, "{id} -> ")?;
+        }
+        match self.artifacts.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -905,6 +1348,163 @@ pub struct InventoryDependent {
     pub optional: bool,
 }
 
+/// Tarjan's algorithm. Returns components that contain a cycle: more than one node, or a
+/// single node that depends on itself.
+fn strongly_connected_components(
+    adjacency: &[Vec<(usize, &ArtifactDependency)>],
+) -> Vec<Vec<usize>> {
+    struct State {
+        next: usize,
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl State {
+        fn enter(&mut self, node: usize) {
+            self.index[node] = Some(self.next);
+            self.low[node] = self.next;
+            self.next += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+        }
+    }
+
+    // Walks with an explicit stack of `(node, next edge)` frames so long dependency chains
+    // cannot overflow the call stack.
+    fn visit(root: usize, adjacency: &[Vec<(usize, &ArtifactDependency)>], state: &mut State) {
+        state.enter(root);
+        let mut work = vec![(root, 0)];
+        while let Some(frame) = work.last_mut() {
+            let (node, edge) = *frame;
+            if let Some(&(target, _)) = adjacency[node].get(edge) {
+                frame.1 += 1;
+                match state.index[target] {
+                    None => {
+                        state.enter(target);
+                        work.push((target, 0));
+                    }
+                    Some(index) if state.on_stack[target] => {
+                        state.low[node] = state.low[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            work.pop();
+            if let Some(&(parent, _)) = work.last() {
+                state.low[parent] = state.low[parent].min(state.low[node]);
+            }
+            if Some(state.low[node]) == state.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = state.stack.pop() {
+                    state.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                let self_loop = adjacency[node].iter().any(|(target, _)| *target == node);
+                if component.len() > 1 || self_loop {
+                    state.components.push(component);
+                }
+            }
+        }
+    }
+
+    let count = adjacency.len();
+    let mut state = State {
+        next: 0,
+        index: vec![None; count],
+        low: vec![0; count],
+        stack: Vec::new(),
+        on_stack: vec![false; count],
+        components: Vec::new(),
+    };
+    for node in 0..count {
+        if state.index[node].is_none() {
+            visit(node, adjacency, &mut state);
+        }
+    }
+    state.components
+}
+
+/// Breadth-first search for the shortest path from `start` back to itself that stays within
+/// `members`. Each step pairs a node with the dependency leading to the next node.
+fn shortest_cycle<'a>(
+    adjacency: &[Vec<(usize, &'a ArtifactDependency)>],
+    members: &BTreeSet<usize>,
+    start: usize,
+) -> Option<Vec<(usize, &'a ArtifactDependency)>> {
+    let mut previous: HashMap<usize, (usize, &'a ArtifactDependency)> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for &(target, dependency) in &adjacency[node] {
+            if !members.contains(&target) {
+                continue;
+            }
+            if target == start {
+                let mut path = vec![(node, dependency)];
+                let mut current = node;
+                while current != start {
+                    let step = previous[&current];
+                    path.push(step);
+                    current = step.0;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if let Entry::Vacant(slot) = previous.entry(target) {
+                slot.insert((node, dependency));
+                queue.push_back(target);
+            }
+        }
+    }
+    None
+}
+
+/// 1-based line in the front matter of `path` whose scalar under the top-level `field` key is
+/// exactly `reference`.
+fn front_matter_line(path: &Path, field: &str, reference: &str) -> Option<usize> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut lines = content.lines().enumerate();
+    if lines.next()?.1.trim() != "---" {
+        return None;
+    }
+    let reference = reference.trim();
+    let mut in_field = false;
+    for (idx, line) in lines.take_while(|(_, line)| line.trim() != "---") {
+        if !line.trim().is_empty() && !line.starts_with([' ', '\t', '-', '#']) {
+            in_field = line
+                .strip_prefix(field)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'));
+        }
+        if in_field && yaml_scalar(line) == reference {
+            return Some(idx + 1);
+        }
+    }
+    None
+}
+
+/// Plain or quoted scalar on a block-style YAML line, after any list dash and `key:` prefix.
+fn yaml_scalar(line: &str) -> &str {
+    let mut value = line.trim();
+    if let Some(rest) = value.strip_prefix('-') {
+        value = rest.trim_start();
+    }
+    if let Some((key, rest)) = value.split_once(':')
+        && (rest.is_empty() || rest.starts_with([' ', '\t']))
+        && !key.contains(char::is_whitespace)
+    {
+        value = rest.trim_start();
+    }
+    let value = value.split(" #").next().unwrap_or(value).trim_end();
+    value.trim_matches(['"', '\''])
+}
+
 fn gather_workspace_artifacts(workspace: &WorkspacePaths) -> Result<Vec<PathBuf>, SpecmanError> {
     let mut files = Vec::new();
     collect_named_files(&workspace.spec_dir(), "spec.md", &mut files)?;
@@ -939,6 +1539,7 @@ impl ArtifactDocument {
     fn load(
         locator: &ArtifactLocator,
         workspace: &WorkspacePaths,
+        federation: &WorkspaceFederation,
         fetcher: &dyn ContentFetcher,
         mode: DependencyResolutionMode,
         resolution: ResolutionProvenance,
@@ -948,6 +1549,27 @@ impl ArtifactDocument {
         metadata.insert("locator".into(), locator.describe());
         metadata.insert("resolution".into(), format!("{resolution:?}"));
 
+        // Artifacts inside a federated peer keep the peer's own view of the world: their
+        // handles and relative paths resolve against the peer, and their names are qualified.
+        let peer = peer_owning(locator, workspace, federation);
+        if let Some(peer) = &peer {
+            metadata.insert("workspace".into(), peer.name().to_string());
+            metadata.insert(
+                "workspace_root".into(),
+                peer.paths().root().display().to_string(),
+            );
+        }
+        // Peers of a peer are not inherited, so a peer artifact resolves against the peer's
+        // own federation.
+        let peer_federation;
+        let (home, home_federation) = match &peer {
+            Some(peer) => {
+                peer_federation = WorkspaceFederation::load(peer.paths())?;
+                (peer.paths(), &peer_federation)
+            }
+            None => (workspace, federation),
+        };
+
         let (frontmatter, status) = frontmatter::optional_front_matter(&raw);
         if let Some(status) = status {
             metadata.insert("metadata_status".into(), status);
@@ -971,7 +1593,8 @@ impl ArtifactDocument {
                 .name()
                 .map(|value| value.to_string())
                 .unwrap_or_else(|| infer_name(locator));
-            let deps = resolve_dependencies(front, locator, workspace, &mut metadata, mode)?;
+            let deps =
+                resolve_dependencies(front, locator, home, home_federation, &mut metadata, mode)?;
             (name, version, kind, deps)
         } else {
             (
@@ -982,6 +1605,10 @@ impl ArtifactDocument {
             )
         };
 
+        let name = match &peer {
+            Some(peer) => format!("{}:{name}", peer.name()),
+            None => name,
+        };
         let summary = ArtifactSummary {
             id: ArtifactId { kind, name },
             version,
@@ -997,10 +1624,104 @@ impl ArtifactDocument {
     }
 }
 
+/// Loads the peer artifacts reachable from `entries` through file dependencies.
+fn load_peer_entries(
+    workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
+    fetcher: &dyn ContentFetcher,
+    entries: &[InventoryEntry],
+) -> Result<Vec<InventoryEntry>, SpecmanError> {
+    let mut peers = Vec::new();
+    let mut seen = BTreeSet::new();
+    let mut pending: VecDeque<ArtifactLocator> = entries
+        .iter()
+        .flat_map(|entry| entry.dependencies.iter())
+        .map(|dependency| dependency.locator.clone())
+        .collect();
+
+    while let Some(locator) = pending.pop_front() {
+        let ArtifactLocator::File(path) = &locator else {
+            continue;
+        };
+        if !seen.insert(path.clone()) || peer_owning(&locator, workspace, federation).is_none() {
+            continue;
+        }
+        let document = ArtifactDocument::load(
+            &locator,
+            workspace,
+            federation,
+            fetcher,
+            DependencyResolutionMode::BestEffort,
+            ResolutionProvenance::Strict,
+        )?;
+        pending.extend(
+            document
+                .dependencies
+                .iter()
+                .map(|dependency| dependency.locator.clone()),
+        );
+        peers.push(InventoryEntry {
+            summary: document.summary,
+            dependencies: document.dependencies,
+        });
+    }
+
+    Ok(peers)
+}
+
+/// Federated peer containing a file locator that lies outside `workspace`.
+fn peer_owning(
+    locator: &ArtifactLocator,
+    workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
+) -> Option<PeerWorkspace> {
+    let ArtifactLocator::File(path) = locator else {
+        return None;
+    };
+    if normalize_workspace_path(path).starts_with(normalize_workspace_path(workspace.root())) {
+        return None;
+    }
+    federation.owner_of(path).cloned()
+}
+
+/// Parses already-fetched remote artifact content and returns the remote URLs it depends on.
+pub(crate) fn remote_dependency_urls(
+    url: &Url,
+    content: &str,
+    workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
+) -> Result<Vec<Url>, SpecmanError> {
+    struct Preloaded<'a>(&'a str);
+
+    impl ContentFetcher for Preloaded<'_> {
+        fn fetch(&self, _url: &Url) -> Result<String, SpecmanError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    let document = ArtifactDocument::load(
+        &ArtifactLocator::Url(url.clone()),
+        workspace,
+        federation,
+        &Preloaded(content),
+        DependencyResolutionMode::BestEffort,
+        ResolutionProvenance::Strict,
+    )?;
+    Ok(document
+        .dependencies
+        .into_iter()
+        .filter_map(|dependency| match dependency.locator {
+            ArtifactLocator::Url(url) => Some(url),
+            ArtifactLocator::File(_) => None,
+        })
+        .collect())
+}
+
 fn resolve_dependencies(
     front: &ArtifactFrontMatter,
     locator: &ArtifactLocator,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
     metadata: &mut BTreeMap<String, String>,
     mode: DependencyResolutionMode,
 ) -> Result<Vec<ArtifactDependency>, SpecmanError> {
@@ -1014,20 +1735,24 @@ fn resolve_dependencies(
                         (obj.reference.as_str(), obj.optional.unwrap_or(false))
                     }
                 };
-                let locator = match resolve_dependency_locator(reference, locator, workspace) {
-                    Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
-                    Err(err) => {
-                        if mode.is_strict() {
-                            return Err(err);
-                        }
-                        if let Some(best) = best_effort_locator(reference, workspace) {
-                            Some(best)
-                        } else {
-                            record_dependency_error(metadata, reference, &err);
-                            None
+                let requirement = parse_requirement(entry, metadata);
+                let locator =
+                    match resolve_dependency_locator(reference, locator, workspace, federation) {
+                        Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
+                        Err(err) => {
+                            if mode.is_strict() {
+                                return Err(err);
+                            }
+                            if let Some(best) =
+                                best_effort_locator(reference, workspace, federation)
+                            {
+                                Some(best)
+                            } else {
+                                record_dependency_error(metadata, reference, &err);
+                                None
+                            }
                         }
-                    }
-                };
+                    };
                 let Some((locator, resolution)) = locator else {
                     continue;
                 };
@@ -1035,50 +1760,63 @@ fn resolve_dependencies(
                     locator,
                     optional,
                     resolution,
+                    requirement,
+                    field: "dependencies",
+                    reference: reference.to_string(),
                 });
             }
         }
         ArtifactFrontMatter::Implementation(implementation) => {
             if let Some(spec_ref) = implementation.spec.as_deref() {
-                let locator = match resolve_dependency_locator(spec_ref, locator, workspace) {
-                    Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
-                    Err(err) => {
-                        if mode.is_strict() {
-                            return Err(err);
-                        }
-                        if let Some(best) = best_effort_locator(spec_ref, workspace) {
-                            Some(best)
-                        } else {
-                            record_dependency_error(metadata, spec_ref, &err);
-                            None
-                        }
-                    }
-                };
-                if let Some((locator, resolution)) = locator {
-                    deps.push(ArtifactDependency {
-                        locator,
-                        optional: false,
-                        resolution,
-                    });
-                }
-            }
-            for reference in &implementation.references {
                 let locator =
-                    match resolve_dependency_locator(&reference.reference, locator, workspace) {
+                    match resolve_dependency_locator(spec_ref, locator, workspace, federation) {
                         Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
                         Err(err) => {
                             if mode.is_strict() {
                                 return Err(err);
                             }
-                            if let Some(best) = best_effort_locator(&reference.reference, workspace)
+                            if let Some(best) = best_effort_locator(spec_ref, workspace, federation)
                             {
                                 Some(best)
                             } else {
-                                record_dependency_error(metadata, &reference.reference, &err);
+                                record_dependency_error(metadata, spec_ref, &err);
                                 None
                             }
                         }
                     };
+                if let Some((locator, resolution)) = locator {
+                    deps.push(ArtifactDependency {
+                        locator,
+                        optional: false,
+                        resolution,
+                        requirement: None,
+                        field: "spec",
+                        reference: spec_ref.to_string(),
+                    });
+                }
+            }
+            for reference in &implementation.references {
+                let locator = match resolve_dependency_locator(
+                    &reference.reference,
+                    locator,
+                    workspace,
+                    federation,
+                ) {
+                    Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
+                    Err(err) => {
+                        if mode.is_strict() {
+                            return Err(err);
+                        }
+                        if let Some(best) =
+                            best_effort_locator(&reference.reference, workspace, federation)
+                        {
+                            Some(best)
+                        } else {
+                            record_dependency_error(metadata, &reference.reference, &err);
+                            None
+                        }
+                    }
+                };
                 let Some((locator, resolution)) = locator else {
                     continue;
                 };
@@ -1086,30 +1824,37 @@ fn resolve_dependencies(
                     locator,
                     optional: reference.optional.unwrap_or(false),
                     resolution,
+                    requirement: None,
+                    field: "references",
+                    reference: reference.reference.clone(),
                 });
             }
         }
         ArtifactFrontMatter::Scratch(scratch) => {
             if let Some(target) = scratch.target.as_deref() {
-                let locator = match resolve_scratch_target_locator(target, locator, workspace) {
-                    Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
-                    Err(err) => {
-                        if mode.is_strict() {
-                            return Err(err);
-                        }
-                        if let Some(best) = best_effort_locator(target, workspace) {
-                            Some(best)
-                        } else {
-                            record_dependency_error(metadata, target, &err);
-                            None
+                let locator =
+                    match resolve_scratch_target_locator(target, locator, workspace, federation) {
+                        Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
+                        Err(err) => {
+                            if mode.is_strict() {
+                                return Err(err);
+                            }
+                            if let Some(best) = best_effort_locator(target, workspace, federation) {
+                                Some(best)
+                            } else {
+                                record_dependency_error(metadata, target, &err);
+                                None
+                            }
                         }
-                    }
-                };
+                    };
                 if let Some((locator, resolution)) = locator {
                     deps.push(ArtifactDependency {
                         locator,
                         optional: false,
                         resolution,
+                        requirement: None,
+                        field: "target",
+                        reference: target.to_string(),
                     });
                 }
             }
@@ -1120,20 +1865,24 @@ fn resolve_dependencies(
                         (obj.reference.as_str(), obj.optional.unwrap_or(false))
                     }
                 };
-                let locator = match resolve_scratch_dependency_locator(reference, workspace) {
-                    Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
-                    Err(err) => {
-                        if mode.is_strict() {
-                            return Err(err);
-                        }
-                        if let Some(best) = best_effort_locator(reference, workspace) {
-                            Some(best)
-                        } else {
-                            record_dependency_error(metadata, reference, &err);
-                            None
+                let requirement = parse_requirement(entry, metadata);
+                let locator =
+                    match resolve_scratch_dependency_locator(reference, workspace, federation) {
+                        Ok(locator) => Some((locator, ResolutionProvenance::Strict)),
+                        Err(err) => {
+                            if mode.is_strict() {
+                                return Err(err);
+                            }
+                            if let Some(best) =
+                                best_effort_locator(reference, workspace, federation)
+                            {
+                                Some(best)
+                            } else {
+                                record_dependency_error(metadata, reference, &err);
+                                None
+                            }
                         }
-                    }
-                };
+                    };
                 let Some((locator, resolution)) = locator else {
                     continue;
                 };
@@ -1141,6 +1890,9 @@ fn resolve_dependencies(
                     locator,
                     optional,
                     resolution,
+                    requirement,
+                    field: "dependencies",
+                    reference: reference.to_string(),
                 });
             }
         }
@@ -1172,6 +1924,7 @@ fn resolve_workspace_path(
     candidate: &Path,
     base: Option<&Path>,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<PathBuf, SpecmanError> {
     fn lexical_normalize(path: &Path) -> PathBuf {
         use std::path::Component;
@@ -1244,9 +1997,12 @@ fn resolve_workspace_path(
     let normalized_path = normalize_workspace_path(&path);
     let normalized_root = normalize_workspace_path(workspace.root());
 
-    if !normalized_path.starts_with(workspace.root()) && !normalized_path.starts_with(&normalized_root) {
+    if !normalized_path.starts_with(workspace.root())
+        && !normalized_path.starts_with(&normalized_root)
+        && federation.owner_of(&normalized_path).is_none()
+    {
         return Err(SpecmanError::Workspace(format!(
-            "locator {} escapes workspace {}",
+            "locator {} escapes workspace {} and no peer in .specman/{WORKSPACES_FILE} contains it",
             normalized_path.display(),
             workspace.root().display()
         )));
@@ -1271,13 +2027,14 @@ pub fn validate_workspace_reference(
         return Ok(());
     }
 
+    let federation = &WorkspaceFederation::load(workspace)?;
     if let Some(handle) = ResourceHandle::parse(reference)? {
-        handle.into_locator(workspace)?;
+        handle.into_locator(workspace, federation)?;
         return Ok(());
     }
 
     let candidate = Path::new(reference);
-    resolve_workspace_path(candidate, Some(parent), workspace)?;
+    resolve_workspace_path(candidate, Some(parent), workspace, federation)?;
     Ok(())
 }
 
@@ -1305,8 +2062,9 @@ pub fn normalize_persisted_reference(
         return Ok(reference.to_string());
     }
 
+    let federation = &WorkspaceFederation::load(workspace)?;
     let canonical = if let Some(handle) = ResourceHandle::parse(reference)? {
-        match handle.into_locator(workspace)? {
+        match handle.into_locator(workspace, federation)? {
             ArtifactLocator::File(path) => path,
             ArtifactLocator::Url(url) => {
                 return Ok(url.to_string());
@@ -1314,7 +2072,7 @@ pub fn normalize_persisted_reference(
         }
     } else {
         let candidate = Path::new(reference);
-        resolve_workspace_path(candidate, Some(parent), workspace)?
+        resolve_workspace_path(candidate, Some(parent), workspace, federation)?
     };
 
     let canonical_parent = normalize_workspace_path(parent);
@@ -1350,7 +2108,8 @@ pub fn normalize_persisted_reference_for_create(
     }
 
     if let Some(handle) = ResourceHandle::parse(reference)? {
-        let path = handle.to_path(workspace);
+        let federation = WorkspaceFederation::load(workspace)?;
+        let path = handle.to_path(&handle.home(workspace, &federation)?);
         let canonical_parent = normalize_workspace_path(parent);
         let rel = diff_paths(&path, &canonical_parent).ok_or_else(|| {
             SpecmanError::Workspace(format!(
@@ -1365,6 +2124,41 @@ pub fn normalize_persisted_reference_for_create(
     normalize_persisted_reference(reference, parent, workspace)
 }
 
+/// Front-matter field a persisted reference was read from.
+///
+/// Scratch pad targets and dependencies resolve from the workspace root, while every other
+/// field resolves from the containing artifact's directory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ReferenceField {
+    Dependency,
+    ScratchTarget,
+    ScratchDependency,
+}
+
+/// Resolves a persisted front-matter reference to the workspace file it points at using the
+/// same rules as dependency traversal. URLs and unresolvable references yield `None`.
+pub(crate) fn resolve_reference_path(
+    reference: &str,
+    field: ReferenceField,
+    artifact_path: &Path,
+    workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
+) -> Option<PathBuf> {
+    let parent = ArtifactLocator::File(normalize_workspace_path(artifact_path));
+    let resolved = match field {
+        ReferenceField::Dependency => {
+            resolve_dependency_locator(reference, &parent, workspace, federation)
+        }
+        ReferenceField::ScratchTarget => {
+            resolve_scratch_target_locator(reference, &parent, workspace, federation)
+        }
+        ReferenceField::ScratchDependency => {
+            resolve_scratch_dependency_locator(reference, workspace, federation)
+        }
+    };
+    resolved.ok()?.workspace_path().map(Path::to_path_buf)
+}
+
 fn pathbuf_to_forward_slashes(path: &Path) -> String {
     let mut parts: Vec<String> = Vec::new();
     for comp in path.components() {
@@ -1383,7 +2177,7 @@ fn pathbuf_to_forward_slashes(path: &Path) -> String {
 }
 
 // Minimal, dependency-free equivalent of `pathdiff::diff_paths`.
-fn diff_paths(path: &Path, base: &Path) -> Option<PathBuf> {
+pub(crate) fn diff_paths(path: &Path, base: &Path) -> Option<PathBuf> {
     use std::path::Component;
 
     let path_components: Vec<Component<'_>> = path.components().collect();
@@ -1434,14 +2228,14 @@ fn diff_paths(path: &Path, base: &Path) -> Option<PathBuf> {
 fn best_effort_locator(
     reference: &str,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Option<(ArtifactLocator, ResolutionProvenance)> {
     // Attempt to handle resource handles by falling back to docs-based Markdown when the
     // canonical spec/impl/scratch layout is missing.
-    if let Ok(Some(handle)) = ResourceHandle::parse(reference) {
-        let doc_path = workspace
-            .root()
-            .join("docs")
-            .join(format!("{}.md", handle.slug));
+    if let Ok(Some(handle)) = ResourceHandle::parse(reference)
+        && let Ok(home) = handle.home(workspace, federation)
+    {
+        let doc_path = home.root().join("docs").join(format!("{}.md", handle.slug));
         if doc_path.is_file() {
             return Some((
                 ArtifactLocator::File(doc_path),
@@ -1482,6 +2276,7 @@ fn resolve_dependency_locator(
     reference: &str,
     parent: &ArtifactLocator,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<ArtifactLocator, SpecmanError> {
     if reference.starts_with("http://") {
         return Err(SpecmanError::Dependency(format!(
@@ -1494,7 +2289,7 @@ fn resolve_dependency_locator(
     }
 
     if let Some(handle) = ResourceHandle::parse(reference)? {
-        return handle.into_locator(workspace);
+        return handle.into_locator(workspace, federation);
     }
 
     if let ArtifactLocator::Url(url) = parent {
@@ -1505,7 +2300,7 @@ fn resolve_dependency_locator(
     }
 
     let base_dir = parent.base_dir();
-    ArtifactLocator::from_path(reference, workspace, base_dir.as_deref())
+    ArtifactLocator::from_path(reference, workspace, federation, base_dir.as_deref())
 }
 
 /// Resolves scratch-pad target references during scratch creation, supporting workspace paths,
@@ -1514,6 +2309,7 @@ fn resolve_scratch_target_locator(
     reference: &str,
     scratch_locator: &ArtifactLocator,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<ArtifactLocator, SpecmanError> {
     if reference.starts_with("http://") {
         return Err(SpecmanError::Dependency(format!(
@@ -1525,15 +2321,21 @@ fn resolve_scratch_target_locator(
     }
 
     if let Some(handle) = ResourceHandle::parse(reference)? {
-        return handle.into_locator(workspace);
+        return handle.into_locator(workspace, federation);
     }
 
-    let primary = ArtifactLocator::from_path(reference, workspace, Some(workspace.root()));
+    let primary =
+        ArtifactLocator::from_path(reference, workspace, federation, Some(workspace.root()));
     match primary {
         Ok(locator) => Ok(locator),
         Err(SpecmanError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
             if let Some(base_dir) = scratch_locator.base_dir() {
-                ArtifactLocator::from_path(reference, workspace, Some(base_dir.as_path()))
+                ArtifactLocator::from_path(
+                    reference,
+                    workspace,
+                    federation,
+                    Some(base_dir.as_path()),
+                )
             } else {
                 Err(SpecmanError::Io(err))
             }
@@ -1547,6 +2349,7 @@ fn resolve_scratch_target_locator(
 fn resolve_scratch_dependency_locator(
     reference: &str,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<ArtifactLocator, SpecmanError> {
     if reference.starts_with("http://") {
         return Err(SpecmanError::Dependency(format!(
@@ -1558,18 +2361,44 @@ fn resolve_scratch_dependency_locator(
     }
 
     if let Some(handle) = ResourceHandle::parse(reference)? {
-        return handle.into_locator(workspace);
+        return handle.into_locator(workspace, federation);
     }
 
     if reference.contains('/') || reference.contains('\\') {
-        return ArtifactLocator::from_path(reference, workspace, Some(workspace.root()));
+        return ArtifactLocator::from_path(
+            reference,
+            workspace,
+            federation,
+            Some(workspace.root()),
+        );
     }
 
     let slug_path = workspace
         .scratchpad_dir()
         .join(reference)
         .join("scratch.md");
-    ArtifactLocator::from_path(slug_path, workspace, Some(workspace.root()))
+    ArtifactLocator::from_path(slug_path, workspace, federation, Some(workspace.root()))
+}
+
+/// Parses the `version` requirement of a detailed dependency entry, recording malformed
+/// requirements alongside other dependency errors.
+fn parse_requirement(
+    entry: &DependencyEntry,
+    metadata: &mut BTreeMap<String, String>,
+) -> Option<VersionReq> {
+    let DependencyEntry::Detailed(obj) = entry else {
+        return None;
+    };
+    let raw = obj.version.as_deref()?;
+    match VersionReq::parse(raw) {
+        Ok(requirement) => Some(requirement),
+        Err(err) => {
+            let err =
+                SpecmanError::Dependency(format!("invalid version requirement `{raw}`: {err}"));
+            record_dependency_error(metadata, &obj.reference, &err);
+            None
+        }
+    }
 }
 
 fn record_dependency_error(
@@ -1843,9 +2672,11 @@ dependencies:
         let root = temp.path().join("workspace");
         fs::create_dir_all(root.join(".specman")).unwrap();
         let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let no_peers = WorkspaceFederation::default();
 
         let (locator, resolution) =
-            best_effort_locator("spec.commonmark.org", &workspace).expect("domain promoted");
+            best_effort_locator("spec.commonmark.org", &workspace, &no_peers)
+                .expect("domain promoted");
 
         assert_eq!(resolution, ResolutionProvenance::BestMatchUrl);
         match locator {
@@ -1862,17 +2693,18 @@ dependencies:
         let root = temp.path().join("workspace");
         fs::create_dir_all(root.join(".specman")).unwrap();
         let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let no_peers = WorkspaceFederation::default();
 
         assert!(
-            best_effort_locator("docs/missing.md", &workspace).is_none(),
+            best_effort_locator("docs/missing.md", &workspace, &no_peers).is_none(),
             "workspace-ish path with '/' must not be promoted to https"
         );
         assert!(
-            best_effort_locator("../missing.md", &workspace).is_none(),
+            best_effort_locator("../missing.md", &workspace, &no_peers).is_none(),
             "relative workspace-ish path must not be promoted to https"
         );
         assert!(
-            best_effort_locator("./missing.md", &workspace).is_none(),
+            best_effort_locator("./missing.md", &workspace, &no_peers).is_none(),
             "relative workspace-ish path must not be promoted to https"
         );
     }
@@ -1911,15 +2743,18 @@ dependencies:
         let workspace =
             WorkspacePaths::new(root_canonical.clone(), root_canonical.join(".specman"));
 
+        let no_peers = WorkspaceFederation::default();
         let parent = ArtifactLocator::from_path(
             workspace.impl_dir().join("workflow-engine").join("impl.md"),
             &workspace,
+            &no_peers,
             None,
         )
         .expect("parent locator");
 
-        let resolved = resolve_dependency_locator("spec://specman-core", &parent, &workspace)
-            .expect("handle resolves");
+        let resolved =
+            resolve_dependency_locator("spec://specman-core", &parent, &workspace, &no_peers)
+                .expect("handle resolves");
 
         match resolved {
             ArtifactLocator::File(path) => {
@@ -1929,6 +2764,95 @@ dependencies:
         }
     }
 
+    #[test]
+    fn peer_handles_resolve_into_federated_workspaces() {
+        let temp = tempdir().unwrap();
+        let base = temp.path().canonicalize().unwrap();
+        let local = base.join("payments");
+        let peer = base.join("platform");
+        fs::create_dir_all(local.join(".specman")).unwrap();
+        fs::create_dir_all(peer.join(".specman")).unwrap();
+        fs::create_dir_all(local.join("spec/billing")).unwrap();
+        fs::create_dir_all(peer.join("spec/auth")).unwrap();
+        fs::create_dir_all(base.join("elsewhere")).unwrap();
+        fs::write(base.join("elsewhere/spec.md"), "# Elsewhere\n").unwrap();
+        fs::write(
+            local.join(".specman/workspaces.yaml"),
+            "workspaces:\n  platform: ../platform\n",
+        )
+        .unwrap();
+        fs::write(
+            local.join("spec/billing/spec.md"),
+            "---\nname: billing\nversion: \"1.0.0\"\ndependencies:\n  - spec://Platform:Auth\n---\n# Billing\n",
+        )
+        .unwrap();
+        fs::write(
+            peer.join("spec/auth/spec.md"),
+            "---\nname: auth\nversion: \"1.2.0\"\n---\n# Auth\n",
+        )
+        .unwrap();
+        let workspace = WorkspacePaths::new(local.clone(), local.join(".specman"));
+        let federation = WorkspaceFederation::load(&workspace).unwrap();
+
+        let handle = ResourceHandle::parse("spec://Platform:Auth")
+            .unwrap()
+            .expect("handle detected");
+        assert_eq!(handle.workspace.as_deref(), Some("platform"));
+        assert_eq!(handle.slug, "auth");
+
+        let billing = ArtifactLocator::from_path(
+            local.join("spec/billing/spec.md"),
+            &workspace,
+            &federation,
+            None,
+        )
+        .unwrap();
+        let document = ArtifactDocument::load(
+            &billing,
+            &workspace,
+            &federation,
+            &HttpFetcher,
+            DependencyResolutionMode::Strict,
+            ResolutionProvenance::Strict,
+        )
+        .unwrap();
+        let dependency = &document.dependencies[0].locator;
+        assert_eq!(
+            dependency.workspace_path(),
+            Some(peer.join("spec/auth/spec.md").as_path())
+        );
+
+        let auth = ArtifactDocument::load(
+            dependency,
+            &workspace,
+            &federation,
+            &HttpFetcher,
+            DependencyResolutionMode::Strict,
+            ResolutionProvenance::Strict,
+        )
+        .unwrap();
+        assert_eq!(auth.summary.id.name, "platform:auth");
+        assert_eq!(
+            auth.summary.metadata.get("workspace").map(String::as_str),
+            Some("platform")
+        );
+
+        let err = resolve_dependency_locator(
+            "../../../elsewhere/spec.md",
+            &billing,
+            &workspace,
+            &federation,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("escapes workspace"), "{err}");
+        let err =
+            resolve_dependency_locator("spec://infra:auth", &billing, &workspace, &federation)
+                .unwrap_err()
+                .to_string();
+        assert!(err.contains("unknown peer workspace `infra`"), "{err}");
+    }
+
     #[test]
     fn resolve_dependency_locator_rejects_unknown_scheme() {
         let temp = tempdir().unwrap();
@@ -1945,14 +2869,16 @@ dependencies:
         let workspace =
             WorkspacePaths::new(root_canonical.clone(), root_canonical.join(".specman"));
 
+        let no_peers = WorkspaceFederation::default();
         let parent = ArtifactLocator::from_path(
             workspace.impl_dir().join("workflow-engine").join("impl.md"),
             &workspace,
+            &no_peers,
             None,
         )
         .expect("parent locator");
 
-        let err = resolve_dependency_locator("ftp://example", &parent, &workspace)
+        let err = resolve_dependency_locator("ftp://example", &parent, &workspace, &no_peers)
             .expect_err("should reject unsupported scheme");
         if let SpecmanError::Dependency(message) = err {
             assert!(message.contains("unsupported locator scheme"));
@@ -2144,6 +3070,93 @@ dependencies:
         }
     }
 
+    #[test]
+    fn detect_cycles_reports_ordered_artifacts_and_closing_entries() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        for (name, dependency) in [("alpha", "beta"), ("beta", "gamma"), ("gamma", "alpha")] {
+            fs::create_dir_all(root.join("spec").join(name)).unwrap();
+            fs::write(
+                root.join("spec").join(name).join("spec.md"),
+                format!(
+                    "---\nname: {name}\nversion: \"1.0.0\"\ndependencies:\n  - ../{dependency}/spec.md\n---\n# {name}\n"
+                ),
+            )
+            .unwrap();
+        }
+        fs::create_dir_all(root.join("spec/delta")).unwrap();
+        fs::write(
+            root.join("spec/delta/spec.md"),
+            "---\nname: delta\nversion: \"1.0.0\"\ndependencies:\n  - ../alpha/spec.md\n---\n# Delta\n",
+        )
+        .unwrap();
+
+        let graph = DependencyGraphServices::new(FilesystemWorkspaceLocator::new(root.clone()));
+        let cycles = graph.detect_cycles().unwrap();
+        assert_eq!(cycles.len(), 1);
+
+        let cycle = &cycles[0];
+        let names: Vec<_> = cycle.artifacts.iter().map(|id| id.name.as_str()).collect();
+        assert_eq!(names, ["alpha", "beta", "gamma"]);
+        assert_eq!(
+            cycle.to_string(),
+            "Specification://alpha -> Specification://beta -> Specification://gamma -> Specification://alpha"
+        );
+
+        let closing = cycle.edges.last().unwrap();
+        assert_eq!(closing.from.name, "gamma");
+        assert_eq!(closing.to.name, "alpha");
+        assert_eq!(closing.field, "dependencies");
+        assert_eq!(closing.reference, "../alpha/spec.md");
+        assert_eq!(closing.line, Some(5));
+        assert!(
+            closing
+                .path
+                .as_deref()
+                .unwrap()
+                .ends_with("spec/gamma/spec.md")
+        );
+    }
+
+    #[test]
+    fn front_matter_line_matches_the_whole_scalar_under_its_field() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("spec.md");
+        fs::write(
+            &path,
+            "---\nname: gamma\ntitle: Mirrors ../alpha/spec.md\ndependencies:\n  - ../alpha/spec.md.orig\n  - ref: \"../alpha/spec.md\" # closes the loop\n    optional: true\n---\n# Gamma\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            front_matter_line(&path, "dependencies", "../alpha/spec.md"),
+            Some(6)
+        );
+        assert_eq!(front_matter_line(&path, "spec", "../alpha/spec.md"), None);
+    }
+
+    #[test]
+    fn strongly_connected_components_handle_long_chains() {
+        let dependency = ArtifactDependency {
+            locator: ArtifactLocator::File(PathBuf::from("spec.md")),
+            optional: false,
+            resolution: ResolutionProvenance::Strict,
+            requirement: None,
+            field: "dependencies",
+            reference: "spec.md".into(),
+        };
+        let mut adjacency: Vec<Vec<(usize, &ArtifactDependency)>> =
+            (1..500).map(|next| vec![(next, &dependency)]).collect();
+        adjacency.push(Vec::new());
+        assert!(strongly_connected_components(&adjacency).is_empty());
+
+        adjacency[499].push((0, &dependency));
+        let components = strongly_connected_components(&adjacency);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 500);
+    }
+
     #[test]
     fn dependency_tree_rejects_workspace_escape() {
         let temp = tempdir().unwrap();
@@ -2510,4 +3523,64 @@ work_type:
         assert!(downstream.contains("path-dependent"));
         assert!(tree.has_blocking_dependents());
     }
+
+    #[test]
+    fn refresh_inventory_paths_updates_cached_entries() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/provider")).unwrap();
+        fs::create_dir_all(root.join("spec/consumer")).unwrap();
+
+        fs::write(
+            root.join("spec/provider/spec.md"),
+            "---\nname: provider\nversion: \"1.0.0\"\n---\n# Provider\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("spec/consumer/spec.md"),
+            "---\nname: consumer\nversion: \"1.0.0\"\n---\n# Consumer\n",
+        )
+        .unwrap();
+
+        let services = DependencyGraphServices::new(FilesystemWorkspaceLocator::new(root.clone()));
+        let provider = normalize_workspace_path(&root.join("spec/provider/spec.md"));
+        let before = services.inventory_snapshot().unwrap();
+        assert_eq!(before.entries.len(), 2);
+        assert!(before.dependents_of(&provider).is_empty());
+
+        fs::write(
+            root.join("spec/consumer/spec.md"),
+            "---\nname: consumer\nversion: \"1.0.0\"\ndependencies:\n  - ../provider/spec.md\n---\n# Consumer\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("spec/extra")).unwrap();
+        fs::write(
+            root.join("spec/extra/spec.md"),
+            "---\nname: extra\nversion: \"1.0.0\"\n---\n# Extra\n",
+        )
+        .unwrap();
+        services
+            .refresh_inventory_paths(&[root.join("spec/consumer/spec.md"), root.join("spec/extra")])
+            .unwrap();
+
+        let after = services.inventory_snapshot().unwrap();
+        let names: Vec<_> = after
+            .entries
+            .iter()
+            .map(|entry| entry.summary.id.name.clone())
+            .collect();
+        assert_eq!(names, vec!["consumer", "extra", "provider"]);
+        let dependents = after.dependents_of(&provider);
+        assert_eq!(dependents.len(), 1);
+        assert_eq!(dependents[0].summary.id.name, "consumer");
+
+        fs::remove_dir_all(root.join("spec/consumer")).unwrap();
+        services
+            .refresh_inventory_paths(&[root.join("spec/consumer")])
+            .unwrap();
+        let removed = services.inventory_snapshot().unwrap();
+        assert_eq!(removed.entries.len(), 2);
+        assert!(removed.dependents_of(&provider).is_empty());
+    }
 }
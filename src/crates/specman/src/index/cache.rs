@@ -413,6 +413,7 @@ struct PersistedConstraintRecord {
     id: PersistedConstraintIdentifier,
     heading: PersistedHeadingIdentifier,
     line: usize,
+    content: String,
     referenced_headings: Vec<PersistedHeadingIdentifier>,
 }
 
@@ -502,6 +503,7 @@ impl PersistedWorkspaceIndex {
                 id: PersistedConstraintIdentifier::from(&record.id),
                 heading: PersistedHeadingIdentifier::from(&record.heading),
                 line: record.line,
+                content: record.content.clone(),
                 referenced_headings: record
                     .referenced_headings
                     .iter()
@@ -608,6 +610,7 @@ impl PersistedWorkspaceIndex {
                     id,
                     heading: HeadingIdentifier::from(record.heading),
                     line: record.line,
+                    content: record.content,
                     referenced_headings: record
                         .referenced_headings
                         .into_iter()
@@ -626,6 +629,7 @@ impl PersistedWorkspaceIndex {
                 to: edge.to,
             })
             .collect();
+        index.rebuild_backlinks();
 
         Ok(index)
     }
@@ -720,7 +724,7 @@ impl From<PersistedUnresolvedHeadingRef> for UnresolvedHeadingRef {
     }
 }
 
-fn write_atomic_json<T: Serialize>(path: &Path, value: &T) -> Result<(), SpecmanError> {
+pub(super) fn write_atomic_json<T: Serialize>(path: &Path, value: &T) -> Result<(), SpecmanError> {
     let tmp_path = path.with_extension("tmp");
     let payload = serde_json::to_string_pretty(value)?;
     fs::write(&tmp_path, payload).map_err(|err| {
@@ -878,6 +882,7 @@ pub(crate) fn resolve_unresolved_refs(
             }
         }
     }
+    index.rebuild_backlinks();
 }
 
 fn attach_heading_reference(
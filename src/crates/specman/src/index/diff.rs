@@ -0,0 +1,334 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
+use crate::workspace::{WorkspacePaths, normalize_workspace_path};
+
+use super::index::{ConstraintRecord, HeadingRecord, WorkspaceIndex};
+use super::indexer::build_document_index;
+
+/// One revision of a specification document, indexed in isolation so two revisions of the same
+/// file can be compared.
+#[derive(Clone, Debug)]
+pub struct SpecRevision {
+    /// Human-readable origin, e.g. `spec/core/spec.md` or `HEAD:spec/core/spec.md`.
+    pub label: String,
+    pub index: WorkspaceIndex,
+}
+
+impl SpecRevision {
+    /// Indexes `content` as the document at `path`.
+    pub fn from_content(
+        workspace: &WorkspacePaths,
+        path: &Path,
+        label: impl Into<String>,
+        content: &str,
+    ) -> Result<Self, SpecmanError> {
+        let index = build_document_index(workspace, ArtifactKind::Specification, path, content)?;
+        Ok(Self {
+            label: label.into(),
+            index,
+        })
+    }
+
+    /// Indexes the current contents of `path`.
+    pub fn from_file(workspace: &WorkspacePaths, path: &Path) -> Result<Self, SpecmanError> {
+        let path = normalize_workspace_path(path);
+        let content = fs::read_to_string(&path)?;
+        Self::from_content(workspace, &path, display_path(workspace, &path), &content)
+    }
+
+    /// Indexes `path` as recorded at `revision`, read with a local `git show`.
+    pub fn from_git(
+        workspace: &WorkspacePaths,
+        revision: &str,
+        path: &Path,
+    ) -> Result<Self, SpecmanError> {
+        let path = normalize_workspace_path(path);
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return Err(SpecmanError::Workspace(format!(
+                "cannot read {} from git: not a file path",
+                path.display()
+            )));
+        };
+
+        // `./` makes the blob path relative to the file's directory rather than the repo root.
+        let object = format!("{revision}:./{}", file_name.to_string_lossy());
+        let output = Command::new("git")
+            .arg("show")
+            .arg(&object)
+            .current_dir(dir)
+            .output()
+            .map_err(|err| SpecmanError::Workspace(format!("failed to run git show: {err}")))?;
+        let label = format!("{revision}:{}", display_path(workspace, &path));
+        if !output.status.success() {
+            return Err(SpecmanError::Workspace(format!(
+                "git show {label} failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let content = String::from_utf8(output.stdout)
+            .map_err(|err| SpecmanError::Workspace(format!("invalid UTF-8 in {label}: {err}")))?;
+        Self::from_content(workspace, &path, label, &content)
+    }
+
+    fn constraints(&self) -> BTreeMap<&str, &ConstraintRecord> {
+        self.index
+            .constraints
+            .values()
+            .map(|record| (record.id.group.as_str(), record))
+            .collect()
+    }
+
+    fn headings(&self) -> BTreeMap<&str, &HeadingRecord> {
+        self.index
+            .headings
+            .values()
+            .map(|record| (record.id.slug.as_str(), record))
+            .collect()
+    }
+}
+
+/// How an item differs between the old and new revision.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Change to a constraint group, keyed by its identifier.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ConstraintChange {
+    pub group: String,
+    pub change: ChangeKind,
+    /// Slug of the owning heading in the new revision (the old one for removals).
+    pub heading: String,
+    /// Owning heading slug in the old revision when the group moved under another heading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_heading: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_content: Option<String>,
+}
+
+/// Change to a heading, keyed by its slug. Modified headings changed title, level, or the
+/// content directly beneath them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct HeadingChange {
+    pub slug: String,
+    pub change: ChangeKind,
+    pub title: String,
+}
+
+/// Constraint- and heading-level differences between two revisions of a specification.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SpecDiff {
+    pub old: String,
+    pub new: String,
+    pub constraints: Vec<ConstraintChange>,
+    pub headings: Vec<HeadingChange>,
+}
+
+impl SpecDiff {
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty() && self.headings.is_empty()
+    }
+
+    /// Constraint groups implementers need to re-verify: added or modified groups.
+    pub fn reverify(&self) -> impl Iterator<Item = &ConstraintChange> {
+        self.constraints
+            .iter()
+            .filter(|change| change.change != ChangeKind::Removed)
+    }
+}
+
+/// Compares two revisions by constraint identifier and heading slug. Constraint text is compared
+/// after trimming trailing whitespace on each line.
+pub fn diff_specifications(old: &SpecRevision, new: &SpecRevision) -> SpecDiff {
+    let old_constraints = old.constraints();
+    let new_constraints = new.constraints();
+    let mut constraints = Vec::new();
+    for (group, record) in &old_constraints {
+        if !new_constraints.contains_key(group) {
+            constraints.push(ConstraintChange {
+                group: group.to_string(),
+                change: ChangeKind::Removed,
+                heading: record.heading.slug.clone(),
+                previous_heading: None,
+                old_content: Some(record.content.clone()),
+                new_content: None,
+            });
+        }
+    }
+    for (group, record) in &new_constraints {
+        let change = match old_constraints.get(group) {
+            None => ConstraintChange {
+                group: group.to_string(),
+                change: ChangeKind::Added,
+                heading: record.heading.slug.clone(),
+                previous_heading: None,
+                old_content: None,
+                new_content: Some(record.content.clone()),
+            },
+            Some(previous) => {
+                let moved = previous.heading.slug != record.heading.slug;
+                if !moved && same_text(&previous.content, &record.content) {
+                    continue;
+                }
+                ConstraintChange {
+                    group: group.to_string(),
+                    change: ChangeKind::Modified,
+                    heading: record.heading.slug.clone(),
+                    previous_heading: moved.then(|| previous.heading.slug.clone()),
+                    old_content: Some(previous.content.clone()),
+                    new_content: Some(record.content.clone()),
+                }
+            }
+        };
+        constraints.push(change);
+    }
+    constraints.sort_by(|a, b| a.group.cmp(&b.group));
+
+    let old_headings = old.headings();
+    let new_headings = new.headings();
+    let mut headings = Vec::new();
+    for (slug, record) in &old_headings {
+        if !new_headings.contains_key(slug) {
+            headings.push(HeadingChange {
+                slug: slug.to_string(),
+                change: ChangeKind::Removed,
+                title: record.title.clone(),
+            });
+        }
+    }
+    for (slug, record) in &new_headings {
+        let change = match old_headings.get(slug) {
+            None => ChangeKind::Added,
+            Some(previous)
+                if previous.title != record.title
+                    || previous.level != record.level
+                    || !same_text(&previous.content, &record.content) =>
+            {
+                ChangeKind::Modified
+            }
+            Some(_) => continue,
+        };
+        headings.push(HeadingChange {
+            slug: slug.to_string(),
+            change,
+            title: record.title.clone(),
+        });
+    }
+    headings.sort_by(|a, b| a.slug.cmp(&b.slug));
+
+    SpecDiff {
+        old: old.label.clone(),
+        new: new.label.clone(),
+        constraints,
+        headings,
+    }
+}
+
+fn same_text(a: &str, b: &str) -> bool {
+    a.lines()
+        .map(str::trim_end)
+        .eq(b.lines().map(str::trim_end))
+}
+
+fn display_path(workspace: &WorkspacePaths, path: &Path) -> String {
+    path.strip_prefix(workspace.root())
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const OLD: &str = "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n!concept-alpha.one:\n- MUST exist.\n\n!concept-alpha.two:\n- MUST be fast.\n\n## Concept: Beta\n\n!concept-beta.one:\n- MAY exist.\n";
+    const NEW: &str = "---\nname: core\nversion: \"2.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n!concept-alpha.one:\n- MUST exist.   \n\n!concept-alpha.two:\n- MUST be very fast.\n\n!concept-alpha.three:\n- SHOULD log.\n\n## Concept: Gamma\n\n!concept-beta.one:\n- MAY exist.\n";
+
+    fn revisions() -> (tempfile::TempDir, SpecRevision, SpecRevision) {
+        let temp = tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let path = root.join("spec/core/spec.md");
+        let old = SpecRevision::from_content(&workspace, &path, "old", OLD).unwrap();
+        let new = SpecRevision::from_content(&workspace, &path, "new", NEW).unwrap();
+        (temp, old, new)
+    }
+
+    #[test]
+    fn constraint_sections_stop_at_next_identifier() {
+        let (_temp, old, _) = revisions();
+        let contents: Vec<_> = old
+            .index
+            .constraints
+            .values()
+            .map(|record| (record.id.group.as_str(), record.content.as_str()))
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                ("concept-alpha.one", "- MUST exist.\n"),
+                ("concept-alpha.two", "- MUST be fast.\n"),
+                ("concept-beta.one", "- MAY exist.\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_changes_by_group_and_slug() {
+        let (_temp, old, new) = revisions();
+        let diff = diff_specifications(&old, &new);
+
+        let constraints: Vec<_> = diff
+            .constraints
+            .iter()
+            .map(|change| (change.group.as_str(), change.change))
+            .collect();
+        assert_eq!(
+            constraints,
+            vec![
+                ("concept-alpha.three", ChangeKind::Added),
+                ("concept-alpha.two", ChangeKind::Modified),
+                ("concept-beta.one", ChangeKind::Modified),
+            ]
+        );
+        let moved = &diff.constraints[2];
+        assert_eq!(moved.heading, "concept-gamma");
+        assert_eq!(moved.previous_heading.as_deref(), Some("concept-beta"));
+        assert_eq!(diff.reverify().count(), 3);
+
+        let headings: Vec<_> = diff
+            .headings
+            .iter()
+            .map(|change| (change.slug.as_str(), change.change))
+            .collect();
+        assert_eq!(
+            headings,
+            vec![
+                ("concept-alpha", ChangeKind::Modified),
+                ("concept-beta", ChangeKind::Removed),
+                ("concept-gamma", ChangeKind::Added),
+            ]
+        );
+
+        assert!(diff_specifications(&old, &old).is_empty());
+    }
+}
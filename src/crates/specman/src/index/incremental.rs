@@ -0,0 +1,350 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
+use crate::workspace::{WorkspaceFederation, WorkspacePaths, normalize_workspace_path};
+
+use super::index::{ArtifactKey, WORKSPACE_INDEX_SCHEMA_VERSION, WorkspaceIndex};
+use super::indexer::{
+    ArtifactLinks, ParsedArtifact, artifacts_by_path, assemble_relationships,
+    enumerate_canonical_artifact_files, parse_artifact,
+};
+
+/// Structure index that keeps every artifact's parse result in memory so individual file
+/// changes can be applied without re-reading the rest of the workspace.
+///
+/// Only the changed files are parsed again, and only they and the artifacts linking to them
+/// have their references re-resolved, so the resulting [`WorkspaceIndex`] matches a full
+/// rebuild.
+pub struct IncrementalStructureIndex {
+    workspace: WorkspacePaths,
+    parsed: BTreeMap<PathBuf, ParsedArtifact>,
+    links: BTreeMap<PathBuf, ArtifactLinks>,
+    index: Arc<WorkspaceIndex>,
+}
+
+/// Artifacts touched by a call to [`IncrementalStructureIndex::apply_changes`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StructureDelta {
+    /// Artifacts that were added or re-parsed.
+    pub updated: Vec<ArtifactKey>,
+    /// Artifacts that no longer exist (or failed to parse) and were dropped from the index.
+    pub removed: Vec<ArtifactKey>,
+    /// Per-file parse failures; the affected artifacts are listed under `removed`.
+    pub failures: Vec<(PathBuf, String)>,
+}
+
+impl StructureDelta {
+    pub fn is_empty(&self) -> bool {
+        self.updated.is_empty() && self.removed.is_empty() && self.failures.is_empty()
+    }
+}
+
+impl IncrementalStructureIndex {
+    /// Parses every canonical artifact in the workspace.
+    pub fn build(workspace: &WorkspacePaths) -> Result<Self, SpecmanError> {
+        let federation = WorkspaceFederation::load(workspace)?;
+        let mut parsed = BTreeMap::new();
+        for (kind, path) in enumerate_canonical_artifact_files(workspace)? {
+            let artifact = parse_artifact(kind, &path, workspace, &federation)?;
+            parsed.insert(path, artifact);
+        }
+        let changed = parsed.keys().map(|path| (path.clone(), None)).collect();
+
+        let mut incremental = Self {
+            workspace: workspace.clone(),
+            parsed,
+            links: BTreeMap::new(),
+            index: Arc::new(WorkspaceIndex {
+                schema_version: WORKSPACE_INDEX_SCHEMA_VERSION,
+                workspace_root: workspace.root().to_path_buf(),
+                ..Default::default()
+            }),
+        };
+        incremental.relink(changed);
+        Ok(incremental)
+    }
+
+    pub fn workspace(&self) -> &WorkspacePaths {
+        &self.workspace
+    }
+
+    /// Returns the current index; cloning the handle is cheap.
+    pub fn index(&self) -> Arc<WorkspaceIndex> {
+        self.index.clone()
+    }
+
+    /// Applies filesystem changes reported for `paths`.
+    ///
+    /// Paths may name artifact files or their containing directories; anything else is
+    /// ignored. Changes to the workspace layout directories themselves trigger a rescan.
+    pub fn apply_changes(&mut self, paths: &[PathBuf]) -> Result<StructureDelta, SpecmanError> {
+        let mut candidates: BTreeMap<PathBuf, ArtifactKind> = BTreeMap::new();
+        let mut rescan = false;
+
+        for path in paths {
+            let path = normalize_workspace_path(path);
+            if self.is_layout_root(&path) {
+                rescan = true;
+                continue;
+            }
+            if let Some((file, kind)) = canonical_candidate(&self.workspace, &path) {
+                candidates.insert(file, kind);
+            }
+        }
+
+        if rescan {
+            for (kind, path) in enumerate_canonical_artifact_files(&self.workspace)? {
+                candidates.insert(path, kind);
+            }
+            for (path, artifact) in &self.parsed {
+                candidates
+                    .entry(path.clone())
+                    .or_insert(artifact.key().kind);
+            }
+        }
+
+        let federation = WorkspaceFederation::load(&self.workspace)?;
+        let mut delta = StructureDelta::default();
+        // Changed files with the parse result they replace, if any.
+        let mut changed: BTreeMap<PathBuf, Option<ParsedArtifact>> = BTreeMap::new();
+        for (file, kind) in candidates {
+            if file.is_file() {
+                match parse_artifact(kind, &file, &self.workspace, &federation) {
+                    Ok(artifact) => {
+                        delta.updated.push(artifact.key().clone());
+                        let previous = self.parsed.insert(file.clone(), artifact);
+                        changed.insert(file, previous);
+                    }
+                    Err(err) => {
+                        if let Some(previous) = self.parsed.remove(&file) {
+                            delta.removed.push(previous.key().clone());
+                            changed.insert(file.clone(), Some(previous));
+                        }
+                        delta.failures.push((file, err.to_string()));
+                    }
+                }
+            } else if let Some(previous) = self.parsed.remove(&file) {
+                delta.removed.push(previous.key().clone());
+                changed.insert(file, Some(previous));
+            }
+        }
+
+        if !changed.is_empty() {
+            self.relink(changed);
+        }
+        Ok(delta)
+    }
+
+    /// Swaps the records of `changed` files into the index and re-resolves references for
+    /// them and for every artifact linking to one of their documents.
+    fn relink(&mut self, changed: BTreeMap<PathBuf, Option<ParsedArtifact>>) {
+        let index = Arc::make_mut(&mut self.index);
+        let mut documents = BTreeSet::new();
+        for (file, previous) in &changed {
+            if let Some(previous) = previous {
+                previous.remove_from(index);
+                documents.insert(previous.key().workspace_path.clone());
+            }
+            self.links.remove(file);
+            if let Some(current) = self.parsed.get(file) {
+                current.insert_into(index);
+                documents.insert(current.key().workspace_path.clone());
+            }
+        }
+
+        let artifact_by_path = artifacts_by_path(index);
+        for (file, parsed) in &self.parsed {
+            if changed.contains_key(file) || parsed.links_to(&documents) {
+                self.links
+                    .insert(file.clone(), parsed.relink(index, &artifact_by_path));
+            }
+        }
+
+        // BTreeMap order matches the sorted enumeration used by full builds.
+        index.relationships = assemble_relationships(
+            self.parsed
+                .iter()
+                .filter_map(|(file, parsed)| Some((parsed, self.links.get(file)?))),
+        );
+        index.rebuild_backlinks();
+    }
+
+    fn is_layout_root(&self, path: &Path) -> bool {
+        let workspace = &self.workspace;
+        [
+            workspace.root().to_path_buf(),
+            workspace.dot_specman().to_path_buf(),
+            workspace.spec_dir(),
+            workspace.impl_dir(),
+            workspace.scratchpad_dir(),
+        ]
+        .iter()
+        .any(|root| normalize_workspace_path(root) == path)
+    }
+}
+
+/// Maps a changed path to the canonical artifact file it can affect, if any.
+///
+/// Both `spec/<name>/spec.md` and `spec/<name>` resolve to the same artifact file so
+/// directory creation, removal, and renames are handled like edits to the file itself.
+fn canonical_candidate(workspace: &WorkspacePaths, path: &Path) -> Option<(PathBuf, ArtifactKind)> {
+    let layouts = [
+        (workspace.spec_dir(), "spec.md", ArtifactKind::Specification),
+        (
+            workspace.impl_dir(),
+            "impl.md",
+            ArtifactKind::Implementation,
+        ),
+        (
+            workspace.scratchpad_dir(),
+            "scratch.md",
+            ArtifactKind::ScratchPad,
+        ),
+    ];
+
+    for (dir, file_name, kind) in layouts {
+        let dir = normalize_workspace_path(&dir);
+        let Ok(rest) = path.strip_prefix(&dir) else {
+            continue;
+        };
+        let mut components = rest.components();
+        let artifact_dir = components.next()?;
+        let file = dir.join(artifact_dir).join(file_name);
+        return match components.next() {
+            None => Some((file, kind)),
+            Some(component) if component.as_os_str() == file_name => {
+                components.next().is_none().then_some((file, kind))
+            }
+            Some(_) => None,
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::index::{Backlink, HeadingIdentifier};
+    use crate::index::indexer::build_workspace_index;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn workspace_paths(root: &Path) -> WorkspacePaths {
+        WorkspacePaths::new(root.to_path_buf(), root.join(".specman"))
+    }
+
+    fn write(path: PathBuf, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn assert_matches_full_build(incremental: &IncrementalStructureIndex) {
+        let full = build_workspace_index(incremental.workspace()).unwrap();
+        let live = incremental.index();
+        assert_eq!(
+            live.artifacts.keys().collect::<Vec<_>>(),
+            full.artifacts.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            live.headings.keys().collect::<Vec<_>>(),
+            full.headings.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            live.constraints.keys().collect::<Vec<_>>(),
+            full.constraints.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(live.relationships, full.relationships);
+        assert_eq!(live.backlinks, full.backlinks);
+    }
+
+    #[test]
+    fn applying_file_changes_matches_full_rebuild() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n!core.alpha:\n- MUST exist.\n",
+        );
+        write(
+            root.join("spec/api/spec.md"),
+            "---\nname: api\nversion: \"1.0.0\"\n---\n# API\n\nSee [beta](../core/spec.md#concept-beta).\n",
+        );
+
+        let workspace = workspace_paths(root);
+        let mut incremental = IncrementalStructureIndex::build(&workspace).unwrap();
+        assert_matches_full_build(&incremental);
+
+        // Adding the heading in core resolves the link from api without re-reading api.
+        write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Beta\n",
+        );
+        let delta = incremental
+            .apply_changes(&[root.join("spec/core/spec.md")])
+            .unwrap();
+        assert_eq!(delta.updated.len(), 1);
+        assert!(delta.removed.is_empty());
+        assert_matches_full_build(&incremental);
+        let api_heading = incremental
+            .index()
+            .headings
+            .values()
+            .find(|record| record.id.artifact.workspace_path == "spec/api/spec.md")
+            .cloned()
+            .unwrap();
+        assert_eq!(api_heading.referenced_headings.len(), 1);
+        let beta = HeadingIdentifier {
+            artifact: ArtifactKey {
+                kind: ArtifactKind::Specification,
+                workspace_path: "spec/core/spec.md".to_string(),
+            },
+            slug: "concept-beta".to_string(),
+        };
+        assert_eq!(
+            incremental.index().backlinks_to(&beta),
+            [Backlink::Heading(api_heading.id.clone())]
+        );
+
+        fs::remove_dir_all(root.join("spec/api")).unwrap();
+        let delta = incremental.apply_changes(&[root.join("spec/api")]).unwrap();
+        assert_eq!(delta.removed.len(), 1);
+        assert_eq!(delta.removed[0].workspace_path, "spec/api/spec.md");
+        assert_matches_full_build(&incremental);
+        assert!(incremental.index().backlinks_to(&beta).is_empty());
+    }
+
+    #[test]
+    fn parse_failures_drop_artifact_and_ignore_unrelated_paths() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("impl/core-rs/impl.md"),
+            "---\nname: core-rs\nspec: spec://core\n---\n# Core\n",
+        );
+
+        let workspace = workspace_paths(root);
+        let mut incremental = IncrementalStructureIndex::build(&workspace).unwrap();
+
+        let delta = incremental
+            .apply_changes(&[root.join("impl/core-rs/notes.md"), root.join("README.md")])
+            .unwrap();
+        assert!(delta.is_empty());
+
+        write(
+            root.join("impl/core-rs/impl.md"),
+            "---\nname: [\n---\n# Core\n",
+        );
+        let delta = incremental
+            .apply_changes(&[root.join("impl/core-rs/impl.md")])
+            .unwrap();
+        assert_eq!(delta.removed.len(), 1);
+        assert_eq!(delta.failures.len(), 1);
+        assert!(incremental.index().artifacts.is_empty());
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::graph::tree::ArtifactKind;
 use crate::metadata::frontmatter::ArtifactFrontMatter;
 
-pub const WORKSPACE_INDEX_SCHEMA_VERSION: u32 = 2;
+pub const WORKSPACE_INDEX_SCHEMA_VERSION: u32 = 3;
 
 /// Stable identifier for an indexed artifact.
 ///
@@ -67,10 +67,39 @@ pub struct ConstraintRecord {
     pub heading: HeadingIdentifier,
     /// Line number within the artifact body (1-based) where the identifier appears.
     pub line: usize,
+    /// Markdown between the identifier line and the next identifier or heading, with
+    /// surrounding blank lines removed.
+    pub content: String,
     /// Resolved heading references found within the constraint group's text.
     pub referenced_headings: Vec<HeadingIdentifier>,
 }
 
+/// A heading or constraint group that links to a heading; the reverse of
+/// `referenced_headings`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Backlink {
+    Heading(HeadingIdentifier),
+    Constraint(ConstraintIdentifier),
+}
+
+impl Backlink {
+    /// The artifact containing the linking heading or constraint group.
+    pub fn artifact(&self) -> &ArtifactKey {
+        match self {
+            Backlink::Heading(id) => &id.artifact,
+            Backlink::Constraint(id) => &id.artifact,
+        }
+    }
+
+    /// `<path>#<slug>` for headings, `<path>!<group>` for constraint groups.
+    pub fn identifier(&self) -> String {
+        match self {
+            Backlink::Heading(id) => format!("{}#{}", id.artifact.workspace_path, id.slug),
+            Backlink::Constraint(id) => format!("{}!{}", id.artifact.workspace_path, id.group),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RelationshipKind {
     HeadingToArtifact,
@@ -97,4 +126,42 @@ pub struct WorkspaceIndex {
     pub headings: BTreeMap<HeadingIdentifier, HeadingRecord>,
     pub constraints: BTreeMap<ConstraintIdentifier, ConstraintRecord>,
     pub relationships: Vec<RelationshipEdge>,
+    /// Inbound heading references, rebuilt whenever references are resolved.
+    pub backlinks: BTreeMap<HeadingIdentifier, Vec<Backlink>>,
+}
+
+impl WorkspaceIndex {
+    /// Headings and constraint groups linking to `heading`, sorted and de-duplicated.
+    pub fn backlinks_to(&self, heading: &HeadingIdentifier) -> &[Backlink] {
+        self.backlinks
+            .get(heading)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Recomputes [`WorkspaceIndex::backlinks`] from every `referenced_headings` list.
+    pub(crate) fn rebuild_backlinks(&mut self) {
+        let mut backlinks: BTreeMap<HeadingIdentifier, Vec<Backlink>> = BTreeMap::new();
+        for record in self.headings.values() {
+            for target in &record.referenced_headings {
+                backlinks
+                    .entry(target.clone())
+                    .or_default()
+                    .push(Backlink::Heading(record.id.clone()));
+            }
+        }
+        for record in self.constraints.values() {
+            for target in &record.referenced_headings {
+                backlinks
+                    .entry(target.clone())
+                    .or_default()
+                    .push(Backlink::Constraint(record.id.clone()));
+            }
+        }
+        for sources in backlinks.values_mut() {
+            sources.sort();
+            sources.dedup();
+        }
+        self.backlinks = backlinks;
+    }
 }
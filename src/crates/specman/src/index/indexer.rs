@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -9,7 +9,8 @@ use crate::graph::tree::ArtifactKind;
 use crate::core::error::SpecmanError;
 use crate::metadata::frontmatter::{ArtifactFrontMatter, optional_front_matter};
 use crate::workspace::{
-    WorkspaceLocator, WorkspacePaths, normalize_workspace_path, workspace_relative_path,
+    WorkspaceFederation, WorkspaceLocator, WorkspacePaths, normalize_workspace_path,
+    workspace_relative_path,
 };
 
 use super::cache::{IndexCache, UnresolvedHeadingRef, UnresolvedTarget, resolve_unresolved_refs};
@@ -120,6 +121,42 @@ pub fn build_workspace_index(workspace: &WorkspacePaths) -> Result<WorkspaceInde
     Ok(build_workspace_index_with_unresolved(workspace)?.0)
 }
 
+/// Indexes a single document from in-memory `content` as if it were stored at `path`, e.g. an
+/// older revision read from version control. References to other documents stay unresolved.
+pub fn build_document_index(
+    workspace: &WorkspacePaths,
+    kind: ArtifactKind,
+    path: &Path,
+    content: &str,
+) -> Result<WorkspaceIndex, SpecmanError> {
+    let canonical_path = normalize_workspace_path(path);
+    let workspace_path =
+        workspace_relative_path(workspace.root(), &canonical_path).ok_or_else(|| {
+            SpecmanError::Workspace(format!(
+                "document {} is outside workspace {}",
+                canonical_path.display(),
+                workspace.root().display()
+            ))
+        })?;
+    let federation = WorkspaceFederation::load(workspace)?;
+    let parsed = parse_artifact_source(
+        kind,
+        &canonical_path,
+        &workspace_path,
+        workspace,
+        &federation,
+        content,
+    )?;
+
+    let mut index = WorkspaceIndex {
+        schema_version: WORKSPACE_INDEX_SCHEMA_VERSION,
+        workspace_root: workspace.root().to_path_buf(),
+        ..Default::default()
+    };
+    index_add_parsed(&mut index, vec![parsed]);
+    Ok(index)
+}
+
 /// Build a structure index for a specific set of artifacts, ignoring unrelated workspace files.
 pub fn build_workspace_index_for_artifacts(
     workspace: &WorkspacePaths,
@@ -174,11 +211,20 @@ fn index_add_artifacts(
     workspace: &WorkspacePaths,
     artifacts: &[(ArtifactKind, PathBuf)],
 ) -> Result<(Vec<UnresolvedHeadingRef>, Vec<RelationshipEdge>), SpecmanError> {
-    let mut artifact_by_path: HashMap<String, ArtifactKey> = HashMap::new();
-    for key in index.artifacts.keys() {
-        artifact_by_path.insert(key.workspace_path.clone(), key.clone());
-    }
+    let federation = WorkspaceFederation::load(workspace)?;
+    let parsed = artifacts
+        .iter()
+        .map(|(kind, path)| parse_artifact(*kind, path, workspace, &federation))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(index_add_parsed(index, parsed))
+}
 
+/// Links already-parsed artifacts into `index`, resolving heading and constraint references
+/// against everything the index holds after insertion.
+pub(super) fn index_add_parsed(
+    index: &mut WorkspaceIndex,
+    parsed_artifacts: Vec<ParsedArtifact>,
+) -> (Vec<UnresolvedHeadingRef>, Vec<RelationshipEdge>) {
     // [ENSURES: concept-specman-structure.indexing.headings:CHECK]
     let mut pending_heading_refs: Vec<PendingHeadingRef> = Vec::new();
     // [ENSURES: concept-specman-structure.indexing.constraints:CHECK]
@@ -186,18 +232,11 @@ fn index_add_artifacts(
     // [ENSURES: concept-specman-structure.indexing.relationships:CHECK]
     let mut relationships: Vec<RelationshipEdge> = index.relationships.clone();
 
-    for (kind, path) in artifacts {
-        let parsed = parse_artifact(*kind, path, workspace)?;
-
+    for parsed in parsed_artifacts {
         relationships.extend(parsed.relationships);
         pending_heading_refs.extend(parsed.pending_heading_refs);
         pending_constraint_refs.extend(parsed.pending_constraint_refs);
 
-        artifact_by_path.insert(
-            parsed.artifact.key.workspace_path.clone(),
-            parsed.artifact.key.clone(),
-        );
-
         index
             .artifacts
             .insert(parsed.artifact.key.clone(), parsed.artifact);
@@ -209,128 +248,154 @@ fn index_add_artifacts(
         }
     }
 
+    let artifact_by_path = artifacts_by_path(index);
+    let mut links = ArtifactLinks::default();
     let mut unresolved: Vec<UnresolvedHeadingRef> = Vec::new();
-    let mut resolved_relationships: Vec<RelationshipEdge> = Vec::new();
-    for pending in pending_heading_refs {
-        match pending.target {
-            PendingTarget::IntraDoc { slug } => {
-                let to = HeadingIdentifier {
-                    artifact: pending.from.artifact.clone(),
-                    slug: slug.clone(),
-                };
-                if index.headings.contains_key(&to) {
-                    attach_heading_reference(index, &pending.from, &to);
-                    resolved_relationships.push(RelationshipEdge {
-                        kind: RelationshipKind::HeadingToHeading,
-                        from: heading_ref_string(&pending.from),
-                        to: heading_ref_string(&to),
-                    });
-                } else if pending.from.artifact.kind != ArtifactKind::ScratchPad {
-                    // [ENSURES: concept-specman-structure.referencing.validation:CHECK]
-                    unresolved.push(UnresolvedHeadingRef {
-                        from: pending.from,
-                        target: UnresolvedTarget::IntraDoc { slug },
-                    });
-                }
-            }
-            PendingTarget::InterDoc {
-                workspace_path,
-                slug,
-            } => {
-                let Some(artifact_key) = artifact_by_path.get(&workspace_path).cloned() else {
-                    if pending.from.artifact.kind != ArtifactKind::ScratchPad {
-                        unresolved.push(UnresolvedHeadingRef {
-                            from: pending.from,
-                            target: UnresolvedTarget::InterDoc {
-                                workspace_path,
-                                slug,
-                            },
-                        });
-                    }
-                    continue;
-                };
-                let to = HeadingIdentifier {
-                    artifact: artifact_key,
+    for pending in &pending_heading_refs {
+        link_heading_ref(
+            index,
+            &artifact_by_path,
+            pending,
+            &mut links,
+            &mut unresolved,
+        );
+    }
+    for pending in &pending_constraint_refs {
+        link_constraint_ref(index, &artifact_by_path, pending, &mut links);
+    }
+
+    relationships.extend(links.heading_edges);
+    relationships.extend(links.constraint_edges);
+    index.relationships = relationships.clone();
+    index.rebuild_backlinks();
+
+    (unresolved, relationships)
+}
+
+/// Relationship edges resolved from one artifact's references, kept apart from the artifact's
+/// own edges so it can be relinked without touching the rest of the index.
+#[derive(Clone, Debug, Default)]
+pub(super) struct ArtifactLinks {
+    heading_edges: Vec<RelationshipEdge>,
+    constraint_edges: Vec<RelationshipEdge>,
+}
+
+/// Lays out relationship edges in full-build order: every artifact's own edges, then resolved
+/// heading references, then resolved constraint references.
+pub(super) fn assemble_relationships<'a>(
+    artifacts: impl Iterator<Item = (&'a ParsedArtifact, &'a ArtifactLinks)> + Clone,
+) -> Vec<RelationshipEdge> {
+    let own = artifacts
+        .clone()
+        .flat_map(|(parsed, _)| parsed.relationships.iter());
+    let headings = artifacts
+        .clone()
+        .flat_map(|(_, links)| links.heading_edges.iter());
+    let constraints = artifacts.flat_map(|(_, links)| links.constraint_edges.iter());
+    own.chain(headings).chain(constraints).cloned().collect()
+}
+
+pub(super) fn artifacts_by_path(index: &WorkspaceIndex) -> HashMap<String, ArtifactKey> {
+    index
+        .artifacts
+        .keys()
+        .map(|key| (key.workspace_path.clone(), key.clone()))
+        .collect()
+}
+
+fn link_heading_ref(
+    index: &mut WorkspaceIndex,
+    artifact_by_path: &HashMap<String, ArtifactKey>,
+    pending: &PendingHeadingRef,
+    links: &mut ArtifactLinks,
+    unresolved: &mut Vec<UnresolvedHeadingRef>,
+) {
+    let (artifact, slug) = match &pending.target {
+        PendingTarget::IntraDoc { slug } => (Some(pending.from.artifact.clone()), slug),
+        PendingTarget::InterDoc {
+            workspace_path,
+            slug,
+        } => (artifact_by_path.get(workspace_path).cloned(), slug),
+        PendingTarget::File { workspace_path } => {
+            links.heading_edges.push(RelationshipEdge {
+                kind: RelationshipKind::HeadingToFile,
+                from: heading_ref_string(&pending.from),
+                to: workspace_path.clone(),
+            });
+            return;
+        }
+    };
+
+    let to = artifact.map(|artifact| HeadingIdentifier {
+        artifact,
+        slug: slug.clone(),
+    });
+    match to {
+        Some(to) if index.headings.contains_key(&to) => {
+            attach_heading_reference(index, &pending.from, &to);
+            links.heading_edges.push(RelationshipEdge {
+                kind: RelationshipKind::HeadingToHeading,
+                from: heading_ref_string(&pending.from),
+                to: heading_ref_string(&to),
+            });
+        }
+        _ if pending.from.artifact.kind != ArtifactKind::ScratchPad => {
+            // [ENSURES: concept-specman-structure.referencing.validation:CHECK]
+            let target = match &pending.target {
+                PendingTarget::InterDoc {
+                    workspace_path,
+                    slug,
+                } => UnresolvedTarget::InterDoc {
+                    workspace_path: workspace_path.clone(),
                     slug: slug.clone(),
-                };
-                if index.headings.contains_key(&to) {
-                    attach_heading_reference(index, &pending.from, &to);
-                    resolved_relationships.push(RelationshipEdge {
-                        kind: RelationshipKind::HeadingToHeading,
-                        from: heading_ref_string(&pending.from),
-                        to: heading_ref_string(&to),
-                    });
-                } else if pending.from.artifact.kind != ArtifactKind::ScratchPad {
-                    // [ENSURES: concept-specman-structure.referencing.validation:CHECK]
-                    unresolved.push(UnresolvedHeadingRef {
-                        from: pending.from,
-                        target: UnresolvedTarget::InterDoc {
-                            workspace_path,
-                            slug,
-                        },
-                    });
-                }
-            }
-            PendingTarget::File { workspace_path } => {
-                resolved_relationships.push(RelationshipEdge {
-                    kind: RelationshipKind::HeadingToFile,
-                    from: heading_ref_string(&pending.from),
-                    to: workspace_path,
-                });
-            }
+                },
+                _ => UnresolvedTarget::IntraDoc { slug: slug.clone() },
+            };
+            unresolved.push(UnresolvedHeadingRef {
+                from: pending.from.clone(),
+                target,
+            });
         }
+        _ => {}
     }
+}
 
-    for pending in pending_constraint_refs {
-        match pending.target {
-            PendingTarget::IntraDoc { slug } => {
-                let to = HeadingIdentifier {
-                    artifact: pending.from.artifact.clone(),
-                    slug: slug.clone(),
-                };
-                if index.headings.contains_key(&to) {
-                    attach_constraint_reference(index, &pending.from, &to);
-                    resolved_relationships.push(RelationshipEdge {
-                        kind: RelationshipKind::ConstraintToHeading,
-                        from: format!(
-                            "{}!{}",
-                            pending.from.artifact.workspace_path, pending.from.group
-                        ),
-                        to: heading_ref_string(&to),
-                    });
-                }
-            }
-            PendingTarget::InterDoc {
-                workspace_path,
-                slug,
-            } => {
-                let Some(artifact_key) = artifact_by_path.get(&workspace_path).cloned() else {
-                    continue;
-                };
-                let to = HeadingIdentifier {
-                    artifact: artifact_key,
-                    slug: slug.clone(),
-                };
-                if index.headings.contains_key(&to) {
-                    attach_constraint_reference(index, &pending.from, &to);
-                    resolved_relationships.push(RelationshipEdge {
-                        kind: RelationshipKind::ConstraintToHeading,
-                        from: format!(
-                            "{}!{}",
-                            pending.from.artifact.workspace_path, pending.from.group
-                        ),
-                        to: heading_ref_string(&to),
-                    });
-                }
+fn link_constraint_ref(
+    index: &mut WorkspaceIndex,
+    artifact_by_path: &HashMap<String, ArtifactKey>,
+    pending: &PendingConstraintRef,
+    links: &mut ArtifactLinks,
+) {
+    let to = match &pending.target {
+        PendingTarget::IntraDoc { slug } => HeadingIdentifier {
+            artifact: pending.from.artifact.clone(),
+            slug: slug.clone(),
+        },
+        PendingTarget::InterDoc {
+            workspace_path,
+            slug,
+        } => {
+            let Some(artifact) = artifact_by_path.get(workspace_path).cloned() else {
+                return;
+            };
+            HeadingIdentifier {
+                artifact,
+                slug: slug.clone(),
             }
-            PendingTarget::File { .. } => {}
         }
+        PendingTarget::File { .. } => return,
+    };
+    if index.headings.contains_key(&to) {
+        attach_constraint_reference(index, &pending.from, &to);
+        links.constraint_edges.push(RelationshipEdge {
+            kind: RelationshipKind::ConstraintToHeading,
+            from: format!(
+                "{}!{}",
+                pending.from.artifact.workspace_path, pending.from.group
+            ),
+            to: heading_ref_string(&to),
+        });
     }
-
-    relationships.extend(resolved_relationships);
-    index.relationships = relationships.clone();
-
-    Ok((unresolved, relationships))
 }
 
 fn attach_heading_reference(
@@ -357,7 +422,7 @@ fn heading_ref_string(id: &HeadingIdentifier) -> String {
     format!("{}#{}", id.artifact.workspace_path, id.slug)
 }
 
-fn enumerate_canonical_artifact_files(
+pub(super) fn enumerate_canonical_artifact_files(
     workspace: &WorkspacePaths,
 ) -> Result<Vec<(ArtifactKind, PathBuf)>, SpecmanError> {
     let mut out: Vec<(ArtifactKind, PathBuf)> = Vec::new();
@@ -436,7 +501,8 @@ fn read_dir_sorted(dir: &Path) -> Result<Vec<fs::DirEntry>, SpecmanError> {
     Ok(entries)
 }
 
-struct ParsedArtifact {
+#[derive(Clone)]
+pub(super) struct ParsedArtifact {
     artifact: ArtifactRecord,
     headings: BTreeMap<HeadingIdentifier, HeadingRecord>,
     constraints: BTreeMap<ConstraintIdentifier, ConstraintRecord>,
@@ -445,6 +511,93 @@ struct ParsedArtifact {
     pending_constraint_refs: Vec<PendingConstraintRef>,
 }
 
+impl ParsedArtifact {
+    pub(super) fn key(&self) -> &ArtifactKey {
+        &self.artifact.key
+    }
+
+    /// Copies the artifact's records into `index` with no references resolved yet.
+    pub(super) fn insert_into(&self, index: &mut WorkspaceIndex) {
+        index
+            .artifacts
+            .insert(self.artifact.key.clone(), self.artifact.clone());
+        index.headings.extend(
+            self.headings
+                .iter()
+                .map(|(id, record)| (id.clone(), record.clone())),
+        );
+        index.constraints.extend(
+            self.constraints
+                .iter()
+                .map(|(id, record)| (id.clone(), record.clone())),
+        );
+    }
+
+    /// Drops the artifact's records from `index`.
+    pub(super) fn remove_from(&self, index: &mut WorkspaceIndex) {
+        index.artifacts.remove(&self.artifact.key);
+        for id in self.headings.keys() {
+            index.headings.remove(id);
+        }
+        for id in self.constraints.keys() {
+            index.constraints.remove(id);
+        }
+    }
+
+    /// Whether any heading or constraint reference names a document in `workspace_paths`.
+    pub(super) fn links_to(&self, workspace_paths: &BTreeSet<String>) -> bool {
+        let mut targets = self
+            .pending_heading_refs
+            .iter()
+            .map(|pending| &pending.target)
+            .chain(
+                self.pending_constraint_refs
+                    .iter()
+                    .map(|pending| &pending.target),
+            );
+        targets.any(|target| match target {
+            PendingTarget::InterDoc { workspace_path, .. } => {
+                workspace_paths.contains(workspace_path)
+            }
+            PendingTarget::IntraDoc { .. } | PendingTarget::File { .. } => false,
+        })
+    }
+
+    /// Re-resolves the artifact's references against `index`, replacing earlier results.
+    pub(super) fn relink(
+        &self,
+        index: &mut WorkspaceIndex,
+        artifact_by_path: &HashMap<String, ArtifactKey>,
+    ) -> ArtifactLinks {
+        for id in self.headings.keys() {
+            if let Some(record) = index.headings.get_mut(id) {
+                record.referenced_headings.clear();
+            }
+        }
+        for id in self.constraints.keys() {
+            if let Some(record) = index.constraints.get_mut(id) {
+                record.referenced_headings.clear();
+            }
+        }
+
+        let mut links = ArtifactLinks::default();
+        let mut unresolved = Vec::new();
+        for pending in &self.pending_heading_refs {
+            link_heading_ref(
+                index,
+                artifact_by_path,
+                pending,
+                &mut links,
+                &mut unresolved,
+            );
+        }
+        for pending in &self.pending_constraint_refs {
+            link_constraint_ref(index, artifact_by_path, pending, &mut links);
+        }
+        links
+    }
+}
+
 #[derive(Clone, Debug)]
 struct PendingHeadingRef {
     from: HeadingIdentifier,
@@ -471,10 +624,13 @@ enum PendingTarget {
     },
 }
 
-fn parse_artifact(
+/// Parses the artifact stored at `canonical_path`; links into the peers of `federation` are
+/// left to the peer's own index.
+pub(super) fn parse_artifact(
     kind: ArtifactKind,
     canonical_path: &Path,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<ParsedArtifact, SpecmanError> {
     if !canonical_path.starts_with(workspace.root()) {
         return Err(SpecmanError::Workspace(format!(
@@ -492,11 +648,6 @@ fn parse_artifact(
             ))
         })?;
 
-    let artifact_key = ArtifactKey {
-        kind,
-        workspace_path: workspace_path.clone(),
-    };
-
     let raw_bytes = fs::read(canonical_path)?;
     let raw = String::from_utf8(raw_bytes).map_err(|err| {
         SpecmanError::Workspace(format!(
@@ -505,7 +656,32 @@ fn parse_artifact(
         ))
     })?;
 
-    let normalized = normalize_newlines(&raw);
+    parse_artifact_source(
+        kind,
+        canonical_path,
+        &workspace_path,
+        workspace,
+        federation,
+        &raw,
+    )
+}
+
+/// Parses `raw` as the document stored at `canonical_path`, which need not match the file's
+/// current contents on disk.
+fn parse_artifact_source(
+    kind: ArtifactKind,
+    canonical_path: &Path,
+    workspace_path: &str,
+    workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
+    raw: &str,
+) -> Result<ParsedArtifact, SpecmanError> {
+    let artifact_key = ArtifactKey {
+        kind,
+        workspace_path: workspace_path.to_string(),
+    };
+
+    let normalized = normalize_newlines(raw);
 
     let (yaml_opt, _missing) = optional_front_matter(&normalized);
     let (front_matter, body) = if let Some(yaml) = yaml_opt {
@@ -532,7 +708,7 @@ fn parse_artifact(
 
     // Parse headings + content.
     let (mut headings, constraints, mut local_relationships, mut pending, mut pending_const) =
-        parse_markdown_structure(&artifact_key, canonical_path, workspace, body)?;
+        parse_markdown_structure(&artifact_key, canonical_path, workspace, federation, body)?;
 
     relationships.append(&mut local_relationships);
     pending_heading_refs.append(&mut pending);
@@ -606,6 +782,7 @@ fn parse_markdown_structure(
     artifact: &ArtifactKey,
     canonical_path: &Path,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
     body: &str,
 ) -> Result<
     (
@@ -722,9 +899,19 @@ fn parse_markdown_structure(
         record.content = join_lines(&content_lines);
 
         let (mut heading_constraints, mut refs, mut const_refs, mut rels) =
-            extract_constraints_and_links(&id, &content_lines, dir, workspace)?;
-
-        for (group, line_no, first_group_slug, nearest_heading) in heading_constraints.drain(..) {
+            extract_constraints_and_links(&id, &content_lines, dir, workspace, federation)?;
+
+        // A constraint section runs until the next identifier line or the end of the heading.
+        let section_ends: Vec<usize> = heading_constraints
+            .iter()
+            .skip(1)
+            .map(|(_, line_no, _, _)| line_no - 1)
+            .chain([content_lines.len()])
+            .collect();
+
+        for ((group, line_no, first_group_slug, nearest_heading), end) in
+            heading_constraints.drain(..).zip(section_ends)
+        {
             if !constraint_seen.insert(group.clone()) {
                 return Err(SpecmanError::Workspace(format!(
                     "duplicate constraint group '{group}' in {}",
@@ -753,6 +940,7 @@ fn parse_markdown_structure(
                 id: cid.clone(),
                 heading: owning_heading,
                 line: line_no,
+                content: constraint_section(&content_lines[line_no..end]),
                 referenced_headings: Vec::new(),
             };
             constraints.insert(cid, cref);
@@ -783,6 +971,18 @@ fn join_lines(lines: &[String]) -> String {
     out
 }
 
+fn constraint_section(lines: &[String]) -> String {
+    let start = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(start, |idx| idx + 1);
+    join_lines(&lines[start..end])
+}
+
 #[derive(Clone, Debug)]
 struct FenceState {
     ch: char,
@@ -836,6 +1036,7 @@ fn extract_constraints_and_links(
     content_lines: &[String],
     base_dir: &Path,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<
     (
         Vec<(String, usize, String, HeadingIdentifier)>,
@@ -880,7 +1081,7 @@ fn extract_constraints_and_links(
                     })
                 } else if let Some((path_part, frag)) = dest.split_once('#') {
                     if let Some(workspace_path) =
-                        resolve_workspace_link_path(path_part, base_dir, workspace)?
+                        resolve_workspace_link_path(path_part, base_dir, workspace, federation)?
                     {
                         Some(PendingTarget::InterDoc {
                             workspace_path,
@@ -892,7 +1093,7 @@ fn extract_constraints_and_links(
                 } else {
                     // Bare file link.
                     if let Some(workspace_path) =
-                        resolve_workspace_link_path(dest.as_str(), base_dir, workspace)?
+                        resolve_workspace_link_path(dest.as_str(), base_dir, workspace, federation)?
                     {
                         Some(PendingTarget::File { workspace_path })
                     } else {
@@ -997,6 +1198,7 @@ fn resolve_workspace_link_path(
     reference: &str,
     base_dir: &Path,
     workspace: &WorkspacePaths,
+    federation: &WorkspaceFederation,
 ) -> Result<Option<String>, SpecmanError> {
     let trimmed = reference.trim();
     if trimmed.is_empty() {
@@ -1023,6 +1225,10 @@ fn resolve_workspace_link_path(
 
     let normalized = lexical_normalize(&absolute);
     if !normalized.starts_with(workspace.root()) {
+        // Links into federated peers are indexed by the peer itself.
+        if federation.owner_of(&normalized).is_some() {
+            return Ok(None);
+        }
         return Err(SpecmanError::Workspace(format!(
             "link {} escapes workspace {}",
             normalized.display(),
@@ -0,0 +1,859 @@
+//! A small query language over the headings, constraint groups and relationships of a
+//! [`WorkspaceIndex`].
+//!
+//! ```text
+//! query     := source [ "where" condition ] [ "select" field { "," field } ] [ "limit" N ]
+//! source    := "headings" | "constraints" | "relationships"
+//! condition := term { "or" term }
+//! term      := factor { "and" factor }
+//! factor    := "not" factor | "(" condition ")" | field op value
+//! op        := "=" | "!=" | "~" | "^=" | "<" | "<=" | ">" | ">="
+//! ```
+//!
+//! `~` is a case-insensitive substring match and `^=` a prefix match. List fields match
+//! when any element matches. Values are bare words or quoted strings; keywords are
+//! case-insensitive.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
+use crate::workspace::{FilesystemWorkspaceLocator, WorkspacePaths};
+
+use super::index::{HeadingIdentifier, RelationshipKind, WorkspaceIndex};
+use super::indexer::FilesystemStructureIndexer;
+
+/// The index table a query reads rows from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QuerySource {
+    Headings,
+    Constraints,
+    Relationships,
+}
+
+impl QuerySource {
+    fn parse(word: &str) -> Option<Self> {
+        match word.to_ascii_lowercase().as_str() {
+            "headings" => Some(Self::Headings),
+            "constraints" => Some(Self::Constraints),
+            "relationships" => Some(Self::Relationships),
+            _ => None,
+        }
+    }
+
+    fn fields(self) -> &'static [Field] {
+        match self {
+            Self::Headings => HEADING_FIELDS,
+            Self::Constraints => CONSTRAINT_FIELDS,
+            Self::Relationships => RELATIONSHIP_FIELDS,
+        }
+    }
+
+    fn default_columns(self) -> &'static [&'static str] {
+        match self {
+            Self::Headings => &["identifier", "title", "level", "constraints"],
+            Self::Constraints => &["identifier", "heading", "line", "external_references"],
+            Self::Relationships => &["kind", "from", "to"],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FieldType {
+    Text,
+    Number,
+    Bool,
+    List,
+}
+
+struct Field {
+    name: &'static str,
+    ty: FieldType,
+}
+
+const fn field(name: &'static str, ty: FieldType) -> Field {
+    Field { name, ty }
+}
+
+/// Row layouts; `*_row` builders emit values in exactly this order.
+const HEADING_FIELDS: &[Field] = &[
+    field("identifier", FieldType::Text),
+    field("artifact", FieldType::Text),
+    field("kind", FieldType::Text),
+    field("slug", FieldType::Text),
+    field("title", FieldType::Text),
+    field("level", FieldType::Number),
+    field("parent", FieldType::Text),
+    field("under", FieldType::List),
+    field("content", FieldType::Text),
+    field("children", FieldType::Number),
+    field("constraints", FieldType::Number),
+    field("references", FieldType::List),
+    field("external_references", FieldType::Number),
+];
+
+const CONSTRAINT_FIELDS: &[Field] = &[
+    field("identifier", FieldType::Text),
+    field("artifact", FieldType::Text),
+    field("kind", FieldType::Text),
+    field("group", FieldType::Text),
+    field("heading", FieldType::Text),
+    field("under", FieldType::List),
+    field("line", FieldType::Number),
+    field("content", FieldType::Text),
+    field("references", FieldType::List),
+    field("external_references", FieldType::Number),
+];
+
+const RELATIONSHIP_FIELDS: &[Field] = &[
+    field("kind", FieldType::Text),
+    field("from", FieldType::Text),
+    field("to", FieldType::Text),
+    field("from_artifact", FieldType::Text),
+    field("to_artifact", FieldType::Text),
+    field("from_kind", FieldType::Text),
+    field("to_kind", FieldType::Text),
+    field("external", FieldType::Bool),
+];
+
+/// A single cell of a query result.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum QueryValue {
+    Text(String),
+    Number(u64),
+    Bool(bool),
+    List(Vec<String>),
+}
+
+impl fmt::Display for QueryValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::List(items) => f.write_str(&items.join(", ")),
+        }
+    }
+}
+
+/// Tabular result of running an [`IndexQuery`].
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct QueryTable {
+    pub source: QuerySource,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<QueryValue>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    Ne,
+    Contains,
+    Prefix,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Operator {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Contains => "~",
+            Self::Prefix => "^=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Operand {
+    Text(String),
+    Number(u64),
+    Bool(bool),
+}
+
+#[derive(Clone, Debug)]
+enum Condition {
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    Compare {
+        field: usize,
+        op: Operator,
+        value: Operand,
+    },
+}
+
+/// A parsed query, validated against the fields of its source.
+#[derive(Clone, Debug)]
+pub struct IndexQuery {
+    source: QuerySource,
+    filter: Option<Condition>,
+    /// Explicit projection; selected rows are de-duplicated.
+    select: Option<Vec<usize>>,
+    limit: Option<usize>,
+}
+
+impl IndexQuery {
+    pub fn parse(expression: &str) -> Result<Self, SpecmanError> {
+        let tokens = tokenize(expression)?;
+        Parser {
+            tokens,
+            position: 0,
+        }
+        .query()
+    }
+
+    pub fn source(&self) -> QuerySource {
+        self.source
+    }
+
+    pub fn run(&self, index: &WorkspaceIndex) -> QueryTable {
+        let fields = self.source.fields();
+        let columns: Vec<usize> = match &self.select {
+            Some(columns) => columns.clone(),
+            None => self
+                .source
+                .default_columns()
+                .iter()
+                .filter_map(|name| fields.iter().position(|field| field.name == *name))
+                .collect(),
+        };
+
+        let mut seen: HashSet<Vec<QueryValue>> = HashSet::new();
+        let mut rows = Vec::new();
+        for row in source_rows(self.source, index) {
+            if self.limit.is_some_and(|limit| rows.len() >= limit) {
+                break;
+            }
+            if self
+                .filter
+                .as_ref()
+                .is_some_and(|condition| !condition.matches(&row))
+            {
+                continue;
+            }
+            let projected: Vec<QueryValue> =
+                columns.iter().map(|column| row[*column].clone()).collect();
+            if self.select.is_some() && !seen.insert(projected.clone()) {
+                continue;
+            }
+            rows.push(projected);
+        }
+
+        QueryTable {
+            source: self.source,
+            columns: columns
+                .iter()
+                .map(|column| fields[*column].name.to_string())
+                .collect(),
+            rows,
+        }
+    }
+}
+
+impl Condition {
+    fn matches(&self, row: &[QueryValue]) -> bool {
+        match self {
+            Self::And(left, right) => left.matches(row) && right.matches(row),
+            Self::Or(left, right) => left.matches(row) || right.matches(row),
+            Self::Not(inner) => !inner.matches(row),
+            Self::Compare { field, op, value } => compare(&row[*field], *op, value),
+        }
+    }
+}
+
+fn compare(actual: &QueryValue, op: Operator, expected: &Operand) -> bool {
+    match (actual, expected) {
+        (QueryValue::Text(text), Operand::Text(expected)) => text_matches(text, op, expected),
+        (QueryValue::List(items), Operand::Text(expected)) => match op {
+            Operator::Ne => !items.iter().any(|item| item == expected),
+            _ => items.iter().any(|item| text_matches(item, op, expected)),
+        },
+        (QueryValue::Number(number), Operand::Number(expected)) => match op {
+            Operator::Eq => number == expected,
+            Operator::Ne => number != expected,
+            Operator::Lt => number < expected,
+            Operator::Le => number <= expected,
+            Operator::Gt => number > expected,
+            Operator::Ge => number >= expected,
+            Operator::Contains | Operator::Prefix => false,
+        },
+        (QueryValue::Bool(value), Operand::Bool(expected)) => match op {
+            Operator::Eq => value == expected,
+            Operator::Ne => value != expected,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn text_matches(text: &str, op: Operator, expected: &str) -> bool {
+    match op {
+        Operator::Eq => text == expected,
+        Operator::Ne => text != expected,
+        Operator::Contains => text
+            .to_ascii_lowercase()
+            .contains(&expected.to_ascii_lowercase()),
+        Operator::Prefix => text.starts_with(expected),
+        Operator::Lt | Operator::Le | Operator::Gt | Operator::Ge => false,
+    }
+}
+
+/// Parses and runs `expression` against the cached structure index of `workspace`.
+pub fn query_workspace(
+    workspace: &WorkspacePaths,
+    expression: &str,
+) -> Result<QueryTable, SpecmanError> {
+    let query = IndexQuery::parse(expression)?;
+    let index = FilesystemStructureIndexer::new(FilesystemWorkspaceLocator::new(
+        workspace.root().to_path_buf(),
+    ))
+    .build_cached_with_workspace(workspace)?;
+    Ok(query.run(&index))
+}
+
+fn query_error(message: impl fmt::Display) -> SpecmanError {
+    SpecmanError::Workspace(format!("invalid query: {message}"))
+}
+
+fn kind_name(kind: ArtifactKind) -> &'static str {
+    match kind {
+        ArtifactKind::Specification => "spec",
+        ArtifactKind::Implementation => "impl",
+        ArtifactKind::ScratchPad => "scratch",
+    }
+}
+
+fn relationship_kind_name(kind: RelationshipKind) -> &'static str {
+    match kind {
+        RelationshipKind::HeadingToArtifact => "heading_to_artifact",
+        RelationshipKind::HeadingToHeading => "heading_to_heading",
+        RelationshipKind::HeadingToFile => "heading_to_file",
+        RelationshipKind::ConstraintToHeading => "constraint_to_heading",
+        RelationshipKind::ParentToChild => "parent_to_child",
+    }
+}
+
+fn heading_ref(id: &HeadingIdentifier) -> String {
+    format!("{}#{}", id.artifact.workspace_path, id.slug)
+}
+
+/// Slugs of `heading` and each of its ancestors, innermost first.
+fn section_slugs(index: &WorkspaceIndex, heading: &HeadingIdentifier) -> Vec<String> {
+    let mut slugs = Vec::new();
+    let mut current = Some(heading);
+    while let Some(id) = current {
+        slugs.push(id.slug.clone());
+        current = index
+            .headings
+            .get(id)
+            .and_then(|record| record.parent.as_ref());
+    }
+    slugs
+}
+
+fn source_rows(source: QuerySource, index: &WorkspaceIndex) -> Vec<Vec<QueryValue>> {
+    match source {
+        QuerySource::Headings => heading_rows(index),
+        QuerySource::Constraints => constraint_rows(index),
+        QuerySource::Relationships => relationship_rows(index),
+    }
+}
+
+fn heading_rows(index: &WorkspaceIndex) -> Vec<Vec<QueryValue>> {
+    let mut constraint_counts: BTreeMap<&HeadingIdentifier, u64> = BTreeMap::new();
+    for record in index.constraints.values() {
+        *constraint_counts.entry(&record.heading).or_default() += 1;
+    }
+
+    let mut records: Vec<_> = index.headings.values().collect();
+    records.sort_by(|a, b| (&a.id.artifact, a.order).cmp(&(&b.id.artifact, b.order)));
+    records
+        .into_iter()
+        .map(|record| {
+            let id = &record.id;
+            let under = record
+                .parent
+                .as_ref()
+                .map(|parent| section_slugs(index, parent))
+                .unwrap_or_default();
+            vec![
+                QueryValue::Text(heading_ref(id)),
+                QueryValue::Text(id.artifact.workspace_path.clone()),
+                QueryValue::Text(kind_name(id.artifact.kind).to_string()),
+                QueryValue::Text(id.slug.clone()),
+                QueryValue::Text(record.title.clone()),
+                QueryValue::Number(u64::from(record.level)),
+                QueryValue::Text(record.parent.as_ref().map(heading_ref).unwrap_or_default()),
+                QueryValue::List(under),
+                QueryValue::Text(record.content.clone()),
+                QueryValue::Number(record.children.len() as u64),
+                QueryValue::Number(constraint_counts.get(id).copied().unwrap_or(0)),
+                QueryValue::List(record.referenced_headings.iter().map(heading_ref).collect()),
+                QueryValue::Number(external_count(&record.referenced_headings, id)),
+            ]
+        })
+        .collect()
+}
+
+fn constraint_rows(index: &WorkspaceIndex) -> Vec<Vec<QueryValue>> {
+    index
+        .constraints
+        .values()
+        .map(|record| {
+            let id = &record.id;
+            vec![
+                QueryValue::Text(format!("{}!{}", id.artifact.workspace_path, id.group)),
+                QueryValue::Text(id.artifact.workspace_path.clone()),
+                QueryValue::Text(kind_name(id.artifact.kind).to_string()),
+                QueryValue::Text(id.group.clone()),
+                QueryValue::Text(heading_ref(&record.heading)),
+                QueryValue::List(section_slugs(index, &record.heading)),
+                QueryValue::Number(record.line as u64),
+                QueryValue::Text(record.content.clone()),
+                QueryValue::List(record.referenced_headings.iter().map(heading_ref).collect()),
+                QueryValue::Number(external_count(&record.referenced_headings, &record.heading)),
+            ]
+        })
+        .collect()
+}
+
+fn external_count(references: &[HeadingIdentifier], own: &HeadingIdentifier) -> u64 {
+    references
+        .iter()
+        .filter(|target| target.artifact.workspace_path != own.artifact.workspace_path)
+        .count() as u64
+}
+
+fn relationship_rows(index: &WorkspaceIndex) -> Vec<Vec<QueryValue>> {
+    let kinds: BTreeMap<&str, ArtifactKind> = index
+        .artifacts
+        .keys()
+        .map(|key| (key.workspace_path.as_str(), key.kind))
+        .collect();
+    let artifact_of =
+        |node: &str| -> String { node.split(['#', '!']).next().unwrap_or(node).to_string() };
+    let kind_of = |artifact: &str| -> String {
+        kinds
+            .get(artifact)
+            .map(|kind| kind_name(*kind).to_string())
+            .unwrap_or_default()
+    };
+
+    let mut edges: Vec<_> = index.relationships.iter().collect();
+    edges.sort();
+    edges.dedup();
+    edges
+        .into_iter()
+        .map(|edge| {
+            let from_artifact = artifact_of(&edge.from);
+            let to_artifact = artifact_of(&edge.to);
+            vec![
+                QueryValue::Text(relationship_kind_name(edge.kind).to_string()),
+                QueryValue::Text(edge.from.clone()),
+                QueryValue::Text(edge.to.clone()),
+                QueryValue::Text(from_artifact.clone()),
+                QueryValue::Text(to_artifact.clone()),
+                QueryValue::Text(kind_of(&from_artifact)),
+                QueryValue::Text(kind_of(&to_artifact)),
+                QueryValue::Bool(from_artifact != to_artifact),
+            ]
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(Operator),
+    Open,
+    Close,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "`{word}`"),
+            Self::Quoted(text) => write!(f, "\"{text}\""),
+            Self::Op(op) => write!(f, "`{}`", op.symbol()),
+            Self::Open => f.write_str("`(`"),
+            Self::Close => f.write_str("`)`"),
+            Self::Comma => f.write_str("`,`"),
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, SpecmanError> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, width) = match (c, next) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('(', _) => (Token::Open, 1),
+            (')', _) => (Token::Close, 1),
+            (',', _) => (Token::Comma, 1),
+            ('~', _) => (Token::Op(Operator::Contains), 1),
+            ('=', _) => (Token::Op(Operator::Eq), 1),
+            ('!', Some('=')) => (Token::Op(Operator::Ne), 2),
+            ('^', Some('=')) => (Token::Op(Operator::Prefix), 2),
+            ('<', Some('=')) => (Token::Op(Operator::Le), 2),
+            ('>', Some('=')) => (Token::Op(Operator::Ge), 2),
+            ('<', _) => (Token::Op(Operator::Lt), 1),
+            ('>', _) => (Token::Op(Operator::Gt), 1),
+            ('"' | '\'', _) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|candidate| *candidate == c)
+                    .ok_or_else(|| query_error(format!("unterminated string starting at {i}")))?;
+                let text: String = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Quoted(text), end + 2)
+            }
+            _ => {
+                let mut end = i;
+                while end < chars.len() && !ends_word(chars[end], chars.get(end + 1).copied()) {
+                    end += 1;
+                }
+                (Token::Word(chars[i..end].iter().collect()), end - i)
+            }
+        };
+        tokens.push(token);
+        i += width;
+    }
+    Ok(tokens)
+}
+
+/// `!` and `^` only delimit when they start an operator, so constraint identifiers such
+/// as `spec/a/spec.md!group` stay a single word.
+fn ends_word(c: char, next: Option<char>) -> bool {
+    c.is_whitespace()
+        || matches!(c, '(' | ')' | ',' | '~' | '=' | '<' | '>' | '"' | '\'')
+        || (matches!(c, '!' | '^') && next == Some('='))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn query(mut self) -> Result<IndexQuery, SpecmanError> {
+        let source = match self.next() {
+            Some(Token::Word(word)) => QuerySource::parse(&word).ok_or_else(|| {
+                query_error(format!(
+                    "unknown source `{word}` (expected headings, constraints, or relationships)"
+                ))
+            })?,
+            Some(other) => {
+                return Err(query_error(format!("expected a source but found {other}")));
+            }
+            None => return Err(query_error("empty query")),
+        };
+
+        let mut query = IndexQuery {
+            source,
+            filter: None,
+            select: None,
+            limit: None,
+        };
+        if self.keyword("where") {
+            query.filter = Some(self.condition(source)?);
+        }
+        if self.keyword("select") {
+            let mut columns = vec![self.field(source)?.0];
+            while self.peek() == Some(&Token::Comma) {
+                self.position += 1;
+                columns.push(self.field(source)?.0);
+            }
+            query.select = Some(columns);
+        }
+        if self.keyword("limit") {
+            query.limit = Some(match self.next() {
+                Some(Token::Word(word)) => word
+                    .parse()
+                    .map_err(|_| query_error(format!("limit must be a number, found `{word}`")))?,
+                other => {
+                    return Err(query_error(format!(
+                        "expected a limit after {}",
+                        describe(other)
+                    )));
+                }
+            });
+        }
+        if let Some(token) = self.next() {
+            return Err(query_error(format!("unexpected {token}")));
+        }
+        Ok(query)
+    }
+
+    fn condition(&mut self, source: QuerySource) -> Result<Condition, SpecmanError> {
+        let mut left = self.term(source)?;
+        while self.keyword("or") {
+            left = Condition::Or(Box::new(left), Box::new(self.term(source)?));
+        }
+        Ok(left)
+    }
+
+    fn term(&mut self, source: QuerySource) -> Result<Condition, SpecmanError> {
+        let mut left = self.factor(source)?;
+        while self.keyword("and") {
+            left = Condition::And(Box::new(left), Box::new(self.factor(source)?));
+        }
+        Ok(left)
+    }
+
+    fn factor(&mut self, source: QuerySource) -> Result<Condition, SpecmanError> {
+        if self.keyword("not") {
+            return Ok(Condition::Not(Box::new(self.factor(source)?)));
+        }
+        if self.peek() == Some(&Token::Open) {
+            self.position += 1;
+            let inner = self.condition(source)?;
+            return match self.next() {
+                Some(Token::Close) => Ok(inner),
+                other => Err(query_error(format!(
+                    "expected `)` but found {}",
+                    describe(other)
+                ))),
+            };
+        }
+
+        let (field, ty) = self.field(source)?;
+        let name = source.fields()[field].name;
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            other => {
+                return Err(query_error(format!(
+                    "expected an operator after `{name}` but found {}",
+                    describe(other)
+                )));
+            }
+        };
+        let raw = match self.next() {
+            Some(Token::Word(word) | Token::Quoted(word)) => word,
+            other => {
+                return Err(query_error(format!(
+                    "expected a value after `{name} {}` but found {}",
+                    op.symbol(),
+                    describe(other)
+                )));
+            }
+        };
+
+        let allowed: &[Operator] = match ty {
+            FieldType::Text | FieldType::List => &[
+                Operator::Eq,
+                Operator::Ne,
+                Operator::Contains,
+                Operator::Prefix,
+            ],
+            FieldType::Number => &[
+                Operator::Eq,
+                Operator::Ne,
+                Operator::Lt,
+                Operator::Le,
+                Operator::Gt,
+                Operator::Ge,
+            ],
+            FieldType::Bool => &[Operator::Eq, Operator::Ne],
+        };
+        if !allowed.contains(&op) {
+            return Err(query_error(format!(
+                "operator `{}` does not apply to `{name}`",
+                op.symbol()
+            )));
+        }
+        let value = match ty {
+            FieldType::Text | FieldType::List => Operand::Text(raw),
+            FieldType::Number => Operand::Number(
+                raw.parse()
+                    .map_err(|_| query_error(format!("`{name}` is numeric, found `{raw}`")))?,
+            ),
+            FieldType::Bool => match raw.to_ascii_lowercase().as_str() {
+                "true" => Operand::Bool(true),
+                "false" => Operand::Bool(false),
+                _ => {
+                    return Err(query_error(format!(
+                        "`{name}` is true or false, found `{raw}`"
+                    )));
+                }
+            },
+        };
+        Ok(Condition::Compare { field, op, value })
+    }
+
+    fn field(&mut self, source: QuerySource) -> Result<(usize, FieldType), SpecmanError> {
+        let fields = source.fields();
+        match self.next() {
+            Some(Token::Word(word)) => fields
+                .iter()
+                .position(|field| field.name.eq_ignore_ascii_case(&word))
+                .map(|position| (position, fields[position].ty))
+                .ok_or_else(|| {
+                    let names: Vec<&str> = fields.iter().map(|field| field.name).collect();
+                    query_error(format!(
+                        "unknown field `{word}` for {} (expected one of: {})",
+                        source_name(source),
+                        names.join(", ")
+                    ))
+                }),
+            other => Err(query_error(format!(
+                "expected a field but found {}",
+                describe(other)
+            ))),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+}
+
+fn source_name(source: QuerySource) -> &'static str {
+    match source {
+        QuerySource::Headings => "headings",
+        QuerySource::Constraints => "constraints",
+        QuerySource::Relationships => "relationships",
+    }
+}
+
+fn describe(token: Option<Token>) -> String {
+    token.map_or_else(|| "end of query".to_string(), |token| token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::build_workspace_index_for_artifacts;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn index() -> (TempDir, WorkspaceIndex) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        fs::create_dir_all(root.join("spec/flow")).unwrap();
+        fs::write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\n---\n# Core\n\n## Concept: Entities\n\nEntities are records.\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("spec/flow/spec.md"),
+            "---\nname: flow\n---\n# Flow\n\n## Concept: Automation\n\n### Triggers\n\n!concept-automation.triggers:\n- MUST act on [entities](../core/spec.md#concept-entities).\n\n!concept-automation.local:\n- MUST log each run.\n\n## Notes\n\nNothing enforced.\n",
+        )
+        .unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let index = build_workspace_index_for_artifacts(
+            &workspace,
+            &[
+                (ArtifactKind::Specification, root.join("spec/core/spec.md")),
+                (ArtifactKind::Specification, root.join("spec/flow/spec.md")),
+            ],
+        )
+        .unwrap();
+        (temp, index)
+    }
+
+    fn first_column(table: &QueryTable) -> Vec<String> {
+        table.rows.iter().map(|row| row[0].to_string()).collect()
+    }
+
+    #[test]
+    fn filters_constraints_under_a_section_with_external_references() {
+        let (_temp, index) = index();
+        let table = IndexQuery::parse(
+            "constraints where under = concept-automation and external_references > 0",
+        )
+        .unwrap()
+        .run(&index);
+        assert_eq!(
+            first_column(&table),
+            vec!["spec/flow/spec.md!concept-automation.triggers"]
+        );
+        assert_eq!(
+            table.columns,
+            vec!["identifier", "heading", "line", "external_references"]
+        );
+    }
+
+    #[test]
+    fn finds_headings_without_constraints_and_projects_distinct_rows() {
+        let (_temp, index) = index();
+        let table = IndexQuery::parse("headings where constraints = 0 and not (level < 2)")
+            .unwrap()
+            .run(&index);
+        assert_eq!(
+            first_column(&table),
+            vec![
+                "spec/core/spec.md#concept-entities",
+                "spec/flow/spec.md#triggers",
+                "spec/flow/spec.md#notes",
+            ]
+        );
+
+        let table = IndexQuery::parse(
+            "relationships where to_artifact = 'spec/core/spec.md' and external = true select from_artifact",
+        )
+        .unwrap()
+        .run(&index);
+        assert_eq!(table.columns, vec!["from_artifact"]);
+        assert_eq!(first_column(&table), vec!["spec/flow/spec.md"]);
+    }
+
+    #[test]
+    fn rejects_unknown_fields_and_mismatched_operators() {
+        for (expression, expected) in [
+            ("", "empty query"),
+            ("sections", "unknown source `sections`"),
+            ("headings where colour = red", "unknown field `colour`"),
+            (
+                "headings where level ~ 2",
+                "operator `~` does not apply to `level`",
+            ),
+            ("headings where level = two", "`level` is numeric"),
+            ("headings where title = \"open", "unterminated string"),
+            ("headings limit 1 extra", "unexpected `extra`"),
+        ] {
+            let err = IndexQuery::parse(expression).unwrap_err().to_string();
+            assert!(err.contains(expected), "{expression}: {err}");
+        }
+    }
+}
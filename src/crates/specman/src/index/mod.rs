@@ -1,13 +1,28 @@
 mod cache;
+mod diff;
+mod incremental;
 mod index;
 mod indexer;
+mod language;
 mod query;
+mod search;
+mod target;
 
+pub use diff::{
+    ChangeKind, ConstraintChange, HeadingChange, SpecDiff, SpecRevision, diff_specifications,
+};
+pub use incremental::{IncrementalStructureIndex, StructureDelta};
 pub use index::{
-    ArtifactKey, ArtifactRecord, ConstraintIdentifier, ConstraintRecord, HeadingIdentifier,
-    HeadingRecord, RelationshipEdge, RelationshipKind, WorkspaceIndex,
+    ArtifactKey, ArtifactRecord, Backlink, ConstraintIdentifier, ConstraintRecord,
+    HeadingIdentifier, HeadingRecord, RelationshipEdge, RelationshipKind, WorkspaceIndex,
 };
 pub use indexer::{
-    FilesystemStructureIndexer, StructureIndexing, build_workspace_index_for_artifacts,
+    FilesystemStructureIndexer, StructureIndexing, build_document_index,
+    build_workspace_index_for_artifacts,
 };
+pub use language::{IndexQuery, QuerySource, QueryTable, QueryValue, query_workspace};
 pub use query::StructureQuery;
+pub use search::{
+    SearchHit, SearchIndex, SearchIndexCache, SearchOptions, SearchTarget, search_workspace,
+};
+pub use target::{IndexTarget, TargetBacklink};
@@ -126,7 +126,7 @@ impl StructureQuery for WorkspaceIndex {
 }
 
 impl WorkspaceIndex {
-    fn render_heading_internal(
+    pub(super) fn render_heading_internal(
         &self,
         heading: &HeadingIdentifier,
         include_references: bool,
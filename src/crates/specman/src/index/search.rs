@@ -0,0 +1,465 @@
+//! Full-text search over indexed headings and constraint groups, ranked with BM25.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
+use crate::workspace::{FilesystemWorkspaceLocator, WorkspacePaths};
+
+use super::cache::write_atomic_json;
+use super::index::WorkspaceIndex;
+use super::indexer::FilesystemStructureIndexer;
+
+const SEARCH_INDEX_SCHEMA_VERSION: u32 = 1;
+/// BM25 term-frequency saturation.
+const K1: f64 = 1.2;
+/// BM25 document-length normalization.
+const B: f64 = 0.75;
+const SNIPPET_CHARS: usize = 160;
+
+fn search_file_name() -> String {
+    format!("search.v{SEARCH_INDEX_SCHEMA_VERSION}.json")
+}
+
+/// What a search hit points at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchTarget {
+    Heading,
+    Constraint,
+}
+
+/// A ranked heading or constraint group.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SearchHit {
+    pub target: SearchTarget,
+    pub artifact_kind: ArtifactKind,
+    /// Workspace-relative artifact path.
+    pub artifact: String,
+    /// `<artifact>#<slug>` for headings, `<artifact>!<group>` for constraint groups.
+    pub identifier: String,
+    /// Heading title, or the title of the heading a constraint group belongs to.
+    pub title: String,
+    /// 1-based line of a constraint group's identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub score: f64,
+    /// The first line mentioning a query term, trimmed to a short window.
+    pub snippet: String,
+}
+
+/// Filters applied to a search.
+#[derive(Clone, Debug)]
+pub struct SearchOptions {
+    /// Only return hits from artifacts of this kind.
+    pub kind: Option<ArtifactKind>,
+    /// Skip headings and return constraint groups only.
+    pub constraints_only: bool,
+    pub limit: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            kind: None,
+            constraints_only: false,
+            limit: 20,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SearchDocument {
+    target: SearchTarget,
+    artifact_kind: ArtifactKind,
+    artifact: String,
+    identifier: String,
+    title: String,
+    line: Option<usize>,
+    text: String,
+    length: u32,
+}
+
+/// Inverted index over the headings and constraint groups of a [`WorkspaceIndex`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchIndex {
+    schema_version: u32,
+    /// Digest of every indexed document, used to detect a stale persisted index.
+    fingerprint: String,
+    documents: Vec<SearchDocument>,
+    /// Token -> `(document, term frequency)` postings.
+    postings: BTreeMap<String, Vec<(u32, u32)>>,
+}
+
+impl SearchIndex {
+    pub fn build(index: &WorkspaceIndex) -> Self {
+        Self::from_documents(collect_documents(index))
+    }
+
+    fn from_documents(mut documents: Vec<SearchDocument>) -> Self {
+        let fingerprint = fingerprint(&documents);
+        let mut postings: BTreeMap<String, Vec<(u32, u32)>> = BTreeMap::new();
+        for (position, document) in documents.iter_mut().enumerate() {
+            let mut frequencies: BTreeMap<String, u32> = BTreeMap::new();
+            for token in tokenize(&document.text) {
+                *frequencies.entry(token).or_default() += 1;
+                document.length += 1;
+            }
+            for (token, frequency) in frequencies {
+                postings
+                    .entry(token)
+                    .or_default()
+                    .push((position as u32, frequency));
+            }
+        }
+        Self {
+            schema_version: SEARCH_INDEX_SCHEMA_VERSION,
+            fingerprint,
+            documents,
+            postings,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Ranks documents against every distinct term in `query` with BM25; ties are broken by
+    /// identifier so results are deterministic.
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Vec<SearchHit> {
+        let terms: BTreeSet<String> = tokenize(query).collect();
+        if terms.is_empty() || self.documents.is_empty() {
+            return Vec::new();
+        }
+
+        let count = self.documents.len() as f64;
+        let average_length = self
+            .documents
+            .iter()
+            .map(|document| f64::from(document.length))
+            .sum::<f64>()
+            / count;
+
+        let mut scores: HashMap<u32, f64> = HashMap::new();
+        for term in &terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let frequency = postings.len() as f64;
+            let idf = ((count - frequency + 0.5) / (frequency + 0.5) + 1.0).ln();
+            for &(position, tf) in postings {
+                let document = &self.documents[position as usize];
+                if !options.accepts(document) {
+                    continue;
+                }
+                let tf = f64::from(tf);
+                let norm = 1.0 - B + B * f64::from(document.length) / average_length.max(1.0);
+                *scores.entry(position).or_default() += idf * tf * (K1 + 1.0) / (tf + K1 * norm);
+            }
+        }
+
+        let mut ranked: Vec<(u32, f64)> = scores.into_iter().collect();
+        ranked.sort_by(|(a, a_score), (b, b_score)| {
+            b_score.total_cmp(a_score).then_with(|| {
+                self.documents[*a as usize]
+                    .identifier
+                    .cmp(&self.documents[*b as usize].identifier)
+            })
+        });
+        ranked.truncate(options.limit);
+
+        ranked
+            .into_iter()
+            .map(|(position, score)| {
+                let document = &self.documents[position as usize];
+                SearchHit {
+                    target: document.target,
+                    artifact_kind: document.artifact_kind,
+                    artifact: document.artifact.clone(),
+                    identifier: document.identifier.clone(),
+                    title: document.title.clone(),
+                    line: document.line,
+                    score,
+                    snippet: snippet(&document.text, &terms),
+                }
+            })
+            .collect()
+    }
+}
+
+impl SearchOptions {
+    fn accepts(&self, document: &SearchDocument) -> bool {
+        self.kind.is_none_or(|kind| kind == document.artifact_kind)
+            && (!self.constraints_only || document.target == SearchTarget::Constraint)
+    }
+}
+
+/// Persisted search index, stored next to the structure index cache at
+/// `.specman/cache/index/search.v<N>.json` and rebuilt whenever indexed content changes.
+#[derive(Clone, Debug)]
+pub struct SearchIndexCache {
+    path: PathBuf,
+}
+
+impl SearchIndexCache {
+    pub fn new(workspace: &WorkspacePaths) -> Self {
+        Self {
+            path: workspace
+                .dot_specman()
+                .join("cache")
+                .join("index")
+                .join(search_file_name()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the persisted index when it matches `index`, otherwise rebuilds and persists it.
+    pub fn load_or_build(&self, index: &WorkspaceIndex) -> Result<SearchIndex, SpecmanError> {
+        let documents = collect_documents(index);
+        if let Some(persisted) = self.load()
+            && persisted.fingerprint == fingerprint(&documents)
+        {
+            return Ok(persisted);
+        }
+
+        let built = SearchIndex::from_documents(documents);
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_atomic_json(&self.path, &built)?;
+        Ok(built)
+    }
+
+    /// A corrupt or outdated file is treated as missing.
+    fn load(&self) -> Option<SearchIndex> {
+        let content = fs::read_to_string(&self.path).ok()?;
+        let persisted: SearchIndex = serde_json::from_str(&content).ok()?;
+        (persisted.schema_version == SEARCH_INDEX_SCHEMA_VERSION).then_some(persisted)
+    }
+}
+
+/// Searches the headings and constraint groups of every indexed artifact in `workspace`.
+pub fn search_workspace(
+    workspace: &WorkspacePaths,
+    query: &str,
+    options: &SearchOptions,
+) -> Result<Vec<SearchHit>, SpecmanError> {
+    let index = FilesystemStructureIndexer::new(FilesystemWorkspaceLocator::new(
+        workspace.root().to_path_buf(),
+    ))
+    .build_cached_with_workspace(workspace)?;
+    let search = SearchIndexCache::new(workspace).load_or_build(&index)?;
+    Ok(search.search(query, options))
+}
+
+/// Document text leads with prose so snippets prefer it over titles and group identifiers.
+fn collect_documents(index: &WorkspaceIndex) -> Vec<SearchDocument> {
+    let mut documents = Vec::with_capacity(index.headings.len() + index.constraints.len());
+    for (id, heading) in &index.headings {
+        documents.push(SearchDocument {
+            target: SearchTarget::Heading,
+            artifact_kind: id.artifact.kind,
+            artifact: id.artifact.workspace_path.clone(),
+            identifier: format!("{}#{}", id.artifact.workspace_path, id.slug),
+            title: heading.title.clone(),
+            line: None,
+            text: format!("{}\n{}", heading.content, heading.title),
+            length: 0,
+        });
+    }
+    for (id, constraint) in &index.constraints {
+        documents.push(SearchDocument {
+            target: SearchTarget::Constraint,
+            artifact_kind: id.artifact.kind,
+            artifact: id.artifact.workspace_path.clone(),
+            identifier: format!("{}!{}", id.artifact.workspace_path, id.group),
+            title: index
+                .headings
+                .get(&constraint.heading)
+                .map(|heading| heading.title.clone())
+                .unwrap_or_default(),
+            line: Some(constraint.line),
+            text: format!("{}\n{}", constraint.content, id.group),
+            length: 0,
+        });
+    }
+    documents
+}
+
+fn fingerprint(documents: &[SearchDocument]) -> String {
+    let mut hasher = Sha256::new();
+    for document in documents {
+        hasher.update(document.identifier.as_bytes());
+        hasher.update([0]);
+        hasher.update(document.title.as_bytes());
+        hasher.update([0]);
+        hasher.update(document.text.as_bytes());
+        hasher.update([0]);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Lowercased alphanumeric runs; identifiers like `concept-retry.backoff` split into words.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn snippet(text: &str, terms: &BTreeSet<String>) -> String {
+    let line = text
+        .lines()
+        .find(|line| tokenize(line).any(|token| terms.contains(&token)))
+        .or_else(|| text.lines().find(|line| !line.trim().is_empty()))
+        .unwrap_or_default();
+    let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() <= SNIPPET_CHARS {
+        return line;
+    }
+
+    let lowered = line.to_lowercase();
+    let first_match = terms
+        .iter()
+        .filter_map(|term| lowered.find(term.as_str()))
+        .min()
+        .map(|byte| lowered[..byte].chars().count())
+        .unwrap_or(0);
+    let start = first_match
+        .saturating_sub(SNIPPET_CHARS / 4)
+        .min(chars.len() - SNIPPET_CHARS);
+    let end = start + SNIPPET_CHARS;
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.extend(&chars[start..end]);
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::build_workspace_index_for_artifacts;
+    use tempfile::TempDir;
+
+    fn workspace() -> (TempDir, WorkspacePaths, WorkspaceIndex) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/net")).unwrap();
+        fs::create_dir_all(root.join("impl/client")).unwrap();
+        fs::write(
+            root.join("spec/net/spec.md"),
+            "---\nname: net\n---\n# Net\n\n## Retries\n\nRequests are retried with backoff.\n\n!concept-net.retry:\n- Clients MUST retry idempotent requests at most three times.\n\n## Timeouts\n\n!concept-net.timeout:\n- Clients MUST give up after thirty seconds.\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("impl/client/impl.md"),
+            "---\nname: client\nspec: ../../spec/net/spec.md\n---\n# Client\n\n## Retry loop\n\nThe retry loop doubles its delay.\n",
+        )
+        .unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let index = build_workspace_index_for_artifacts(
+            &workspace,
+            &[
+                (ArtifactKind::Specification, root.join("spec/net/spec.md")),
+                (
+                    ArtifactKind::Implementation,
+                    root.join("impl/client/impl.md"),
+                ),
+            ],
+        )
+        .unwrap();
+        (temp, workspace, index)
+    }
+
+    #[test]
+    fn ranks_constraints_and_headings_with_filters() {
+        let (_temp, _workspace, index) = workspace();
+        let search = SearchIndex::build(&index);
+
+        let hits = search.search("retry", &SearchOptions::default());
+        let identifiers: Vec<&str> = hits.iter().map(|hit| hit.identifier.as_str()).collect();
+        assert!(identifiers.contains(&"spec/net/spec.md!concept-net.retry"));
+        assert!(identifiers.contains(&"impl/client/impl.md#retry-loop"));
+        assert!(!identifiers.contains(&"spec/net/spec.md!concept-net.timeout"));
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        let constraints = search.search(
+            "retry",
+            &SearchOptions {
+                constraints_only: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(constraints.len(), 1);
+        assert!(constraints[0].line.is_some());
+        assert_eq!(constraints[0].title, "Retries");
+        assert_eq!(
+            constraints[0].snippet,
+            "- Clients MUST retry idempotent requests at most three times."
+        );
+
+        let implementations = search.search(
+            "retry",
+            &SearchOptions {
+                kind: Some(ArtifactKind::Implementation),
+                ..Default::default()
+            },
+        );
+        assert!(
+            implementations
+                .iter()
+                .all(|hit| hit.artifact_kind == ArtifactKind::Implementation)
+        );
+        assert!(search.search("   ", &SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn persisted_index_is_reused_until_content_changes() {
+        let (_temp, workspace, index) = workspace();
+        let cache = SearchIndexCache::new(&workspace);
+
+        let built = cache.load_or_build(&index).unwrap();
+        assert!(cache.path().is_file());
+        assert_eq!(cache.load().unwrap().fingerprint, built.fingerprint);
+
+        let mut changed = index.clone();
+        let heading = changed.headings.values_mut().next().unwrap();
+        heading.content.push_str("\nNew retry guidance.\n");
+        let rebuilt = cache.load_or_build(&changed).unwrap();
+        assert_ne!(rebuilt.fingerprint, built.fingerprint);
+        assert_eq!(cache.load().unwrap().fingerprint, rebuilt.fingerprint);
+    }
+
+    #[test]
+    fn long_lines_are_windowed_around_the_first_match() {
+        let text = format!("{} retry {}", "a ".repeat(150), "b ".repeat(150));
+        let terms = BTreeSet::from(["retry".to_string()]);
+        let snippet = snippet(&text, &terms);
+        assert!(
+            snippet.starts_with('…') && snippet.ends_with('…'),
+            "{snippet}"
+        );
+        assert!(snippet.contains("retry"));
+    }
+}
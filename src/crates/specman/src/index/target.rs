@@ -0,0 +1,328 @@
+use std::collections::BTreeSet;
+
+use crate::core::error::SpecmanError;
+use crate::workspace::{WorkspaceContext, WorkspacePaths, workspace_relative_path};
+
+use super::index::{
+    ArtifactKey, Backlink, ConstraintIdentifier, HeadingIdentifier, WorkspaceIndex,
+};
+use super::query::StructureQuery;
+
+/// An artifact, heading, or constraint group addressed as `<locator>[#<heading-slug>]` or
+/// `<locator>/constraints/<group>`, where the locator is a `spec://`, `impl://`, or
+/// `scratch://` handle or a workspace-relative path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexTarget {
+    Artifact(ArtifactKey),
+    Heading(HeadingIdentifier),
+    Constraint(ConstraintIdentifier),
+}
+
+/// A link into an [`IndexTarget`] from outside of it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TargetBacklink {
+    /// The heading inside the target that is linked to.
+    pub target: HeadingIdentifier,
+    pub source: Backlink,
+}
+
+impl IndexTarget {
+    pub fn resolve(
+        index: &WorkspaceIndex,
+        workspace: &WorkspacePaths,
+        target: &str,
+    ) -> Result<Self, SpecmanError> {
+        if let Some((locator, group)) = target.split_once("/constraints/") {
+            let constraint = ConstraintIdentifier {
+                artifact: resolve_artifact(index, workspace, locator)?,
+                group: group.to_string(),
+            };
+            if !index.constraints.contains_key(&constraint) {
+                return Err(SpecmanError::Workspace(format!(
+                    "constraint group '{group}' not found in {}",
+                    constraint.artifact.workspace_path
+                )));
+            }
+            return Ok(Self::Constraint(constraint));
+        }
+
+        let (locator, slug) = match target.split_once('#') {
+            Some((locator, slug)) => (locator, Some(slug)),
+            None => (target, None),
+        };
+        let artifact = resolve_artifact(index, workspace, locator)?;
+
+        let Some(slug) = slug else {
+            return Ok(Self::Artifact(artifact));
+        };
+        let heading = HeadingIdentifier {
+            artifact,
+            slug: slug.to_string(),
+        };
+        if !index.headings.contains_key(&heading) {
+            return Err(SpecmanError::Workspace(format!(
+                "heading '{slug}' not found in {}",
+                heading.artifact.workspace_path
+            )));
+        }
+        Ok(Self::Heading(heading))
+    }
+
+    pub fn artifact(&self) -> &ArtifactKey {
+        match self {
+            Self::Artifact(key) => key,
+            Self::Heading(id) => &id.artifact,
+            Self::Constraint(id) => &id.artifact,
+        }
+    }
+
+    /// `<path>`, `<path>#<slug>`, or `<path>!<group>`.
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::Artifact(key) => key.workspace_path.clone(),
+            Self::Heading(id) => format!("{}#{}", id.artifact.workspace_path, id.slug),
+            Self::Constraint(id) => format!("{}!{}", id.artifact.workspace_path, id.group),
+        }
+    }
+
+    /// Renders the target as markdown. With `expand_references`, headings are followed by
+    /// the sections they link to and constraint groups by their transitive reference closure
+    /// (see [`StructureQuery`]); otherwise only the target's own text is rendered.
+    pub fn render(
+        &self,
+        index: &WorkspaceIndex,
+        expand_references: bool,
+    ) -> Result<String, SpecmanError> {
+        match self {
+            Self::Heading(id) => index.render_heading_internal(id, expand_references),
+            Self::Constraint(id) if expand_references => index.render_constraint_group(id),
+            Self::Constraint(id) => {
+                let record = index.constraints.get(id).ok_or_else(|| {
+                    SpecmanError::Workspace(format!(
+                        "constraint group '{}' not found in {}",
+                        id.group, id.artifact.workspace_path
+                    ))
+                })?;
+                let mut rendered = format!("!{}:\n{}", id.group, record.content);
+                if !rendered.ends_with('\n') {
+                    rendered.push('\n');
+                }
+                Ok(rendered)
+            }
+            Self::Artifact(_) => {
+                let mut sections = Vec::new();
+                for id in self.headings(index) {
+                    if index.headings.get(&id).is_some_and(|h| h.parent.is_none()) {
+                        sections.push(index.render_heading_internal(&id, expand_references)?);
+                    }
+                }
+                Ok(sections.join("\n"))
+            }
+        }
+    }
+
+    /// Every heading the target covers in document order: the whole artifact, or a heading
+    /// and its nested subsections. Constraint groups cover no headings.
+    pub fn headings(&self, index: &WorkspaceIndex) -> Vec<HeadingIdentifier> {
+        let mut headings: Vec<&HeadingIdentifier> = match self {
+            Self::Artifact(key) => index
+                .headings
+                .keys()
+                .filter(|id| &id.artifact == key)
+                .collect(),
+            Self::Heading(root) => {
+                let mut covered = vec![root];
+                let mut i = 0;
+                while i < covered.len() {
+                    if let Some(record) = index.headings.get(covered[i]) {
+                        covered.extend(record.children.iter());
+                    }
+                    i += 1;
+                }
+                covered
+            }
+            Self::Constraint(_) => Vec::new(),
+        };
+        headings.sort_by_key(|id| index.headings.get(*id).map_or(usize::MAX, |h| h.order));
+        headings.into_iter().cloned().collect()
+    }
+
+    /// Headings and constraint groups elsewhere in the workspace that link into the target.
+    /// Links from inside the target itself are omitted, and a heading is not listed next to
+    /// one of its own constraint groups linking to the same place, since heading content
+    /// includes the text of its constraint groups.
+    pub fn backlinks(&self, index: &WorkspaceIndex) -> Vec<TargetBacklink> {
+        let covered = self.headings(index);
+        let inside: BTreeSet<&HeadingIdentifier> = covered.iter().collect();
+        let is_inside = |source: &Backlink| match source {
+            Backlink::Heading(id) => inside.contains(id),
+            Backlink::Constraint(id) => index
+                .constraints
+                .get(id)
+                .is_some_and(|record| inside.contains(&record.heading)),
+        };
+
+        let mut links = Vec::new();
+        for heading in &covered {
+            let sources = index.backlinks_to(heading);
+            let via_constraint: BTreeSet<&HeadingIdentifier> = sources
+                .iter()
+                .filter_map(|source| match source {
+                    Backlink::Constraint(id) => index.constraints.get(id).map(|r| &r.heading),
+                    Backlink::Heading(_) => None,
+                })
+                .collect();
+            for source in sources {
+                let shadowed =
+                    matches!(source, Backlink::Heading(id) if via_constraint.contains(id));
+                if !shadowed && !is_inside(source) {
+                    links.push(TargetBacklink {
+                        target: heading.clone(),
+                        source: source.clone(),
+                    });
+                }
+            }
+        }
+        links
+    }
+}
+
+fn resolve_artifact(
+    index: &WorkspaceIndex,
+    workspace: &WorkspacePaths,
+    locator: &str,
+) -> Result<ArtifactKey, SpecmanError> {
+    let path = WorkspaceContext::new(workspace.clone()).resolve_locator(locator)?;
+    let workspace_path = workspace_relative_path(workspace.root(), &path).ok_or_else(|| {
+        SpecmanError::Workspace(format!(
+            "failed to resolve workspace-relative path for '{}'",
+            path.display()
+        ))
+    })?;
+    index
+        .artifacts
+        .keys()
+        .find(|key| key.workspace_path == workspace_path)
+        .cloned()
+        .ok_or_else(|| {
+            SpecmanError::Workspace(format!(
+                "artifact '{locator}' is not in the structure index ({workspace_path})"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::tree::ArtifactKind;
+    use crate::index::build_workspace_index_for_artifacts;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn backlinks_cover_nested_sections_and_skip_internal_links() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        fs::create_dir_all(root.join("impl/runner")).unwrap();
+        fs::write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\n---\n# Core\n\n## Concept: Discovery\n\nSee [roots](#roots).\n\n### Roots\n\nRoots are directories.\n\n## Concept: Other\n\n!concept-other.links:\n- MUST follow [discovery](#concept-discovery).\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("impl/runner/impl.md"),
+            "---\nname: runner\nspec: ../../spec/core/spec.md\n---\n# Runner\n\n## Startup\n\nWalks [roots](../../spec/core/spec.md#roots).\n",
+        )
+        .unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let index = build_workspace_index_for_artifacts(
+            &workspace,
+            &[
+                (ArtifactKind::Specification, root.join("spec/core/spec.md")),
+                (
+                    ArtifactKind::Implementation,
+                    root.join("impl/runner/impl.md"),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let target =
+            IndexTarget::resolve(&index, &workspace, "spec://core#concept-discovery").unwrap();
+        let sources: Vec<(String, String)> = target
+            .backlinks(&index)
+            .into_iter()
+            .map(|link| (link.target.slug, link.source.identifier()))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                (
+                    "concept-discovery".to_string(),
+                    "spec/core/spec.md!concept-other.links".to_string()
+                ),
+                (
+                    "roots".to_string(),
+                    "impl/runner/impl.md#startup".to_string()
+                ),
+            ]
+        );
+
+        let artifact = IndexTarget::resolve(&index, &workspace, "spec/core/spec.md").unwrap();
+        assert_eq!(artifact.backlinks(&index).len(), 1);
+
+        let err = IndexTarget::resolve(&index, &workspace, "spec://core#missing").unwrap_err();
+        assert!(err.to_string().contains("heading 'missing' not found"));
+    }
+
+    #[test]
+    fn renders_constraint_groups_with_and_without_expansion() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        fs::write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\n---\n# Core\n\n## Concept: Roots\n\nRoots are directories.\n\n## Concept: Walk\n\n!concept-walk.order:\n- MUST visit [roots](#concept-roots) first.\n",
+        )
+        .unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        let index = build_workspace_index_for_artifacts(
+            &workspace,
+            &[(ArtifactKind::Specification, root.join("spec/core/spec.md"))],
+        )
+        .unwrap();
+
+        let target = IndexTarget::resolve(
+            &index,
+            &workspace,
+            "spec://core/constraints/concept-walk.order",
+        )
+        .unwrap();
+        assert_eq!(target.identifier(), "spec/core/spec.md!concept-walk.order");
+        assert_eq!(
+            target.render(&index, false).unwrap(),
+            "!concept-walk.order:\n- MUST visit [roots](#concept-roots) first.\n"
+        );
+        let expanded = target.render(&index, true).unwrap();
+        assert!(expanded.contains("## Concept: Walk"), "{expanded}");
+        assert!(expanded.contains("Roots are directories."), "{expanded}");
+
+        let heading = IndexTarget::resolve(&index, &workspace, "spec://core#concept-walk").unwrap();
+        assert!(
+            !heading
+                .render(&index, false)
+                .unwrap()
+                .contains("Roots are directories.")
+        );
+
+        let err =
+            IndexTarget::resolve(&index, &workspace, "spec://core/constraints/nope").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("constraint group 'nope' not found")
+        );
+    }
+}
@@ -7,54 +7,109 @@ pub(crate) mod scratchpad;
 pub(crate) mod storage;
 pub(crate) mod templates;
 pub(crate) mod validation;
+pub(crate) mod watch;
 pub(crate) mod workspace;
 
 pub use core::env::SpecmanEnv;
 pub use core::error::{LifecycleError, SpecmanError};
+pub use core::network::{
+    NETWORK_POLICY_ENV, NETWORK_POLICY_FILE, NetworkPolicy, VENDOR_DIR, VendorDirectory,
+    VendorReport, vendor_workspace, vendor_workspace_with_fetcher,
+};
 pub use core::shared::{EntityKind, SchemaRef, SemVer};
+pub use graph::export::{DependencyGraphExport, GraphFormat};
+pub use graph::lock::{
+    DEPS_LOCK_FILE, DependencyLock, DependencyLockReport, DependencyLockVerification,
+    DependencyLocker, LockIssue, LockIssueKind, LockedDependency, LockedFetcher,
+};
 pub use graph::tree::{
-    ArtifactId, ArtifactKind, ArtifactSummary, DependencyEdge, DependencyGraphServices,
-    DependencyMapping, DependencyRelation, DependencyTree, FilesystemDependencyMapper,
-    InventoryDependent, WorkspaceInventorySnapshot,
+    ArtifactId, ArtifactKind, ArtifactSummary, ContentFetcher, CycleEdge, DependencyCycle, DependencyEdge,
+    DependencyGraphServices, DependencyMapping, DependencyRelation, DependencyTree,
+    FilesystemDependencyMapper, InventoryDependent, VersionMismatch, WorkspaceInventorySnapshot,
 };
 pub use index::{
-    ArtifactKey, ArtifactRecord, ConstraintIdentifier, ConstraintRecord,
-    FilesystemStructureIndexer, HeadingIdentifier, HeadingRecord, RelationshipEdge,
-    RelationshipKind, StructureIndexing, StructureQuery, WorkspaceIndex,
+    ArtifactKey, ArtifactRecord, Backlink, ChangeKind, ConstraintChange, ConstraintIdentifier,
+    ConstraintRecord, FilesystemStructureIndexer, HeadingChange, HeadingIdentifier, HeadingRecord,
+    IncrementalStructureIndex, IndexQuery, IndexTarget, QuerySource, QueryTable, QueryValue,
+    RelationshipEdge, RelationshipKind, SearchHit, SearchIndex, SearchIndexCache, SearchOptions,
+    SearchTarget, SpecDiff, SpecRevision, StructureDelta, StructureIndexing, StructureQuery,
+    TargetBacklink, WorkspaceIndex, build_document_index, diff_specifications, query_workspace,
+    search_workspace,
 };
 pub use metadata::frontmatter::{
-    ArtifactIdentityFields, ArtifactFrontMatter, DependencyEntry, ImplementationFrontMatter,
+    ArtifactIdentityFields, ArtifactFrontMatter, CompliancePolicy, CustomWorkType, DependencyEntry,
+    DependencyObject,
+    ImplementationFrontMatter,
     ReferenceEntry, ScratchFrontMatter, ScratchRefactorMetadata, ScratchRevisionMetadata,
     ScratchFixMetadata,
     ScratchWorkType, ScratchWorkloadExtras, SpecificationFrontMatter, split_front_matter,
 };
+pub use metadata::waiver::{Waiver, WaiverDate};
 pub use metadata::{
     FrontMatterUpdate, FrontMatterUpdateResult, IdentityUpdate, ImplementationUpdate,
     ScratchUpdate, SpecificationUpdate,
 };
+pub use ops::bump::{BumpOptions, BumpResult, VersionBump, bump_version};
 pub use ops::create::{
     CreateImplOptions, CreateResult, CreateScratchOptions, CreateSpecOptions,
     create_implementation, create_scratch_pad, create_specification,
 };
 pub use ops::delete::{DeleteOptions, DeleteResult, delete_artifact};
+pub use ops::rename::{
+    ReferenceRewrite, RenameOptions, RenamePlan, RenameResult, rename_artifact,
+};
 pub use ops::update::apply_front_matter_update;
-pub use scratchpad::ScratchPadProfile;
+pub use scratchpad::{
+    BUILTIN_WORK_TYPES, ScratchPadProfile, WORK_TYPES_FILE, WorkTypeDefinition, WorkTypeRegistry,
+    load_work_types,
+};
 pub use storage::adapter::{DataModelAdapter, InMemoryAdapter};
 pub use storage::persistence::{
     ArtifactRemovalStore, PersistedArtifact, RemovedArtifact, WorkspacePersistence,
 };
 pub use templates::catalog::{ResolvedTemplate, TemplateCatalog};
 pub use templates::engine::{
-    ImplContext, MarkdownTemplateEngine, RenderedTemplate, ScratchPadContext, SpecContext,
-    TemplateDescriptor, TemplateEngine, TemplateLocator, TemplateProvenance, TemplateScenario,
-    TemplateTier, TokenMap,
+    CreationContext, DEFAULT_UNFILLED_MARKERS, ImplContext, MarkdownTemplateEngine,
+    RenderedTemplate, ScratchPadContext, SpecContext, TemplateDescriptor, TemplateEngine,
+    TemplateLocator, TemplateProvenance, TemplateScenario, TemplateTier, TokenMap,
+};
+pub use templates::inspect::{
+    TemplateToken, TemplateValidation, sample_tokens, template_source, template_tokens,
+    validate_template,
 };
 pub use validation::status::{
     ArtifactStatus, StatusResult, WorkspaceStatusConfig, WorkspaceStatusReport,
     validate_workspace_status,
 };
-pub use validation::{ValidationTag, validate_compliance};
-pub use validation::analysis::{DeletionImpact, check_deletion_impact};
+pub use validation::autofix::{
+    ReferenceFix, ReferenceFixOptions, ReferenceFixReport, ReferenceSuggestion, SuggestionReason,
+    SuggestionSources, fix_references,
+};
+pub use validation::lint::{
+    LINT_FILE, LintConfig, LintIssue, LintLevel, LintReport, LintRule, lint_document, lint_index,
+    lint_workspace, load_lint_config,
+};
+pub use validation::watch::{StatusCheck, StatusDiff, StatusIssue, StatusWatcher};
+pub use validation::plan::{
+    ImplementationPlan, PlanStep, PlannedImplementation, plan_workspace,
+};
+pub use validation::junit::{JUnitCase, JUnitOutcome, JUnitReport, JUnitSuite};
+pub use validation::sarif::{
+    SARIF_SCHEMA, SARIF_VERSION, SarifArtifactLocation, SarifDriver, SarifLevel, SarifLocation,
+    SarifLog, SarifMessage, SarifPhysicalLocation, SarifRegion, SarifResult, SarifRule,
+    SarifRuleConfiguration, SarifRun, SarifTool,
+};
+pub use validation::unfilled::{
+    UnfilledKind, UnfilledMarker, check_unfilled_template, find_unfilled,
+};
+pub use validation::waivers::{WAIVERS_FILE, load_workspace_waivers};
+pub use validation::{
+    ComplianceMetrics, ComplianceReport, ConceptCoverage, SourceLocation, ValidationTag,
+    ValidationType, validate_compliance,
+};
+pub use validation::analysis::{
+    DeletionImpact, ImplementationImpact, SpecImpact, analyze_spec_impact, check_deletion_impact,
+};
 pub use validation::references::{
     DestinationKind, DiscoveredReference, HttpsMethod, HttpsValidationMode, HttpsValidationOptions,
     IssueSeverity, ReachabilityPolicy, ReferenceIssueKind, ReferenceKind, ReferenceRecord,
@@ -62,7 +117,12 @@ pub use validation::references::{
     ReferenceValidationReport, ReferenceValidationStatus, ReferenceValidator, SourcePoint,
     SourceRange, TransitiveOptions, ValidationMode, validate_references,
 };
+pub use watch::{
+    FileChange, FileChangeKind, FileWatcher, ManualFileWatcher, NotifyFileWatcher, WatchDelta,
+    WorkspaceWatcher,
+};
 pub use workspace::{
-    FilesystemWorkspaceLocator, WorkspaceContext, WorkspaceDiscovery, WorkspaceError,
-    WorkspaceLocator, WorkspacePaths, discover as discover_workspace, workspace_relative_path,
+    FilesystemWorkspaceLocator, PeerWorkspace, WORKSPACES_FILE, WorkspaceContext,
+    WorkspaceDiscovery, WorkspaceError, WorkspaceFederation, WorkspaceLocator, WorkspacePaths,
+    discover as discover_workspace, workspace_relative_path,
 };
@@ -10,6 +10,7 @@ use serde_yaml::{Mapping, Value as YamlValue};
 
 use crate::core::error::SpecmanError;
 use crate::graph::tree::ArtifactId;
+use crate::metadata::waiver::Waiver;
 use crate::storage::persistence::PersistedArtifact;
 
 /// Shared identity fields repeated across specification, implementation, and scratch metadata
@@ -48,6 +49,22 @@ pub struct ImplementationFrontMatter {
     pub references: Vec<ReferenceEntry>,
     #[serde(default)]
     pub dependencies: Vec<DependencyEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<CompliancePolicy>,
+    /// Constraint groups intentionally left uncovered by this implementation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waivers: Vec<Waiver>,
+}
+
+/// Compliance thresholds an implementation declares under `compliance:` in its front matter.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct CompliancePolicy {
+    /// Minimum fraction (0.0–1.0) of constraint groups that must be covered. Defaults to 1.0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_coverage: Option<f64>,
+    /// Only count constraint groups covered by at least one `TEST` tag.
+    #[serde(default)]
+    pub require_test: bool,
 }
 
 /// Scratch pad YAML fields defined in the Scratch Pad Metadata section of the
@@ -156,6 +173,14 @@ impl ArtifactFrontMatter {
         }
     }
 
+    pub(crate) fn identity_mut(&mut self) -> &mut ArtifactIdentityFields {
+        match self {
+            ArtifactFrontMatter::Specification(front) => &mut front.identity,
+            ArtifactFrontMatter::Implementation(front) => &mut front.identity,
+            ArtifactFrontMatter::Scratch(front) => &mut front.identity,
+        }
+    }
+
     /// Parses from a borrowed YAML value, cloning only when necessary.
     pub fn from_yaml_value(value: &YamlValue) -> Result<Self, SpecmanError> {
         Self::from_value(value.clone())
@@ -177,6 +202,9 @@ pub struct DependencyObject {
     pub reference: String,
     #[serde(default, rename = "optional")]
     pub optional: Option<bool>,
+    /// Semver requirement (e.g. `^3`) the target's `version` must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 /// Implementation reference entry defined alongside the Implementation Metadata rules.
@@ -190,7 +218,8 @@ pub struct ReferenceEntry {
     pub optional: Option<bool>,
 }
 
-/// Discriminated enum capturing scratch pad work types (draft, revision, feat, ref, fix).
+/// Discriminated enum capturing scratch pad work types (draft, revision, feat, ref, fix, or a
+/// type declared in `.specman/work-types.yaml`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScratchWorkType {
     Draft(ScratchWorkloadExtras),
@@ -198,6 +227,7 @@ pub enum ScratchWorkType {
     Feat(ScratchWorkloadExtras),
     Refactor(ScratchRefactorMetadata),
     Fix(ScratchFixMetadata),
+    Custom(CustomWorkType),
 }
 
 /// Identifies the concrete scratch work type.
@@ -208,10 +238,11 @@ pub enum ScratchWorkTypeKind {
     Feat,
     Refactor,
     Fix,
+    Custom,
 }
 
 impl ScratchWorkType {
-    /// Returns the normalized work type label (draft, revision, feat, ref, or fix).
+    /// Returns the normalized work type label (draft, revision, feat, ref, fix, or custom).
     pub fn kind(&self) -> ScratchWorkTypeKind {
         match self {
             ScratchWorkType::Draft(_) => ScratchWorkTypeKind::Draft,
@@ -219,6 +250,15 @@ impl ScratchWorkType {
             ScratchWorkType::Feat(_) => ScratchWorkTypeKind::Feat,
             ScratchWorkType::Refactor(_) => ScratchWorkTypeKind::Refactor,
             ScratchWorkType::Fix(_) => ScratchWorkTypeKind::Fix,
+            ScratchWorkType::Custom(_) => ScratchWorkTypeKind::Custom,
+        }
+    }
+
+    /// Returns the `work_type` key, which is the declared name for custom work types.
+    pub fn name(&self) -> &str {
+        match self {
+            ScratchWorkType::Custom(custom) => &custom.name,
+            other => other.kind().as_str(),
         }
     }
 }
@@ -232,10 +272,18 @@ impl ScratchWorkTypeKind {
             ScratchWorkTypeKind::Feat => "feat",
             ScratchWorkTypeKind::Refactor => "ref",
             ScratchWorkTypeKind::Fix => "fix",
+            ScratchWorkTypeKind::Custom => "custom",
         }
     }
 }
 
+/// A work type declared in `.specman/work-types.yaml`, with its metadata fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomWorkType {
+    pub name: String,
+    pub metadata: BTreeMap<String, JsonValue>,
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
 pub struct ScratchWorkloadExtras {
     #[serde(flatten)]
@@ -278,6 +326,9 @@ impl Serialize for ScratchWorkType {
             ScratchWorkType::Feat(data) => map.serialize_entry("feat", data)?,
             ScratchWorkType::Refactor(data) => map.serialize_entry("ref", data)?,
             ScratchWorkType::Fix(data) => map.serialize_entry("fix", data)?,
+            ScratchWorkType::Custom(custom) => {
+                map.serialize_entry(&custom.name, &custom.metadata)?
+            }
         }
         map.end()
     }
@@ -291,7 +342,7 @@ impl<'de> Deserialize<'de> for ScratchWorkType {
         let raw: serde_json::Map<String, JsonValue> = serde_json::Map::deserialize(deserializer)?;
         if raw.len() != 1 {
             return Err(de::Error::custom(
-                "work_type must contain exactly one draft|revision|feat|ref|fix entry",
+                "work_type must contain exactly one draft|revision|feat|ref|fix|<custom> entry",
             ));
         }
         let (key, value) = raw.into_iter().next().unwrap();
@@ -321,9 +372,17 @@ impl<'de> Deserialize<'de> for ScratchWorkType {
                     serde_json::from_value(value).map_err(de::Error::custom)?;
                 Ok(ScratchWorkType::Fix(data))
             }
-            other => Err(de::Error::custom(format!(
-                "unsupported work_type '{other}'"
-            ))),
+            // Whether the name is declared in `.specman/work-types.yaml` is checked by status.
+            _ => {
+                let metadata = match value {
+                    JsonValue::Null => BTreeMap::new(),
+                    value => serde_json::from_value(value).map_err(de::Error::custom)?,
+                };
+                Ok(ScratchWorkType::Custom(CustomWorkType {
+                    name: key,
+                    metadata,
+                }))
+            }
         }
     }
 }
@@ -343,6 +402,14 @@ impl JsonSchema for ScratchWorkType {
             variant_schema("feat", generator.subschema_for::<ScratchWorkloadExtras>()),
             variant_schema("ref", generator.subschema_for::<ScratchRefactorMetadata>()),
             variant_schema("fix", generator.subschema_for::<ScratchFixMetadata>()),
+            serde_json::from_value(serde_json::json!({
+                "type": "object",
+                "description": "Work type declared in .specman/work-types.yaml",
+                "additionalProperties": { "type": "object" },
+                "minProperties": 1,
+                "maxProperties": 1
+            }))
+            .expect("valid custom work_type schema"),
         ];
 
         serde_json::from_value(serde_json::json!({ "anyOf": variants }))
@@ -1,6 +1,7 @@
 pub mod frontmatter;
 pub mod mutation;
 pub mod update_model;
+pub mod waiver;
 
 pub use frontmatter::*;
 pub use update_model::*;
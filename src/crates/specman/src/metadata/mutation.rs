@@ -11,7 +11,20 @@ pub fn write_artifact_front_matter(
     let content = fs::read_to_string(path)
         .map_err(|e| SpecmanError::Workspace(format!("Failed to read artifact: {}", e)))?;
 
-    let split = split_front_matter(&content)?;
+    let to_write = render_artifact_front_matter(&content, front_matter)?;
+
+    fs::write(path, to_write)
+        .map_err(|e| SpecmanError::Workspace(format!("Failed to write artifact: {}", e)))?;
+
+    Ok(())
+}
+
+/// Returns `content` with its front matter replaced by `front_matter`, preserving the body.
+pub fn render_artifact_front_matter(
+    content: &str,
+    front_matter: &ArtifactFrontMatter,
+) -> Result<String, SpecmanError> {
+    let split = split_front_matter(content)?;
 
     let yaml_str = serde_yaml::to_string(front_matter)
         .map_err(|e| SpecmanError::Serialization(e.to_string()))?;
@@ -20,10 +33,9 @@ pub fn write_artifact_front_matter(
     let yaml_clean = yaml_str.trim_start_matches("---").trim_start();
 
     // Construct the new file content
-    let to_write = format!("---\n{}\n---\n{}", yaml_clean.trim_end(), split.body);
-
-    fs::write(path, to_write)
-        .map_err(|e| SpecmanError::Workspace(format!("Failed to write artifact: {}", e)))?;
-
-    Ok(())
+    Ok(format!(
+        "---\n{}\n---\n{}",
+        yaml_clean.trim_end(),
+        split.body
+    ))
 }
@@ -0,0 +1,111 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::core::calendar::{civil_from_days, unix_seconds};
+
+/// Declares a constraint group as intentionally unimplemented.
+///
+/// Waivers are listed under `waivers:` in implementation front matter or in
+/// `.specman/waivers.yaml`. Entries in the workspace file apply to every implementation
+/// unless `implementation` names one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Waiver {
+    /// Constraint group identifier, e.g. `concept-alpha.requirements`.
+    pub constraint: String,
+    pub reason: String,
+    /// Last day (inclusive) on which the waiver applies, as `YYYY-MM-DD`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<String>")]
+    pub expires: Option<WaiverDate>,
+    /// Implementation the waiver is scoped to; only meaningful in `.specman/waivers.yaml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub implementation: Option<String>,
+}
+
+impl Waiver {
+    pub fn applies_to(&self, implementation: &str) -> bool {
+        self.implementation
+            .as_deref()
+            .is_none_or(|name| name == implementation)
+    }
+
+    pub fn is_expired(&self, today: WaiverDate) -> bool {
+        self.expires.is_some_and(|expires| expires < today)
+    }
+}
+
+/// Calendar date used for waiver expiry, serialized as `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WaiverDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl WaiverDate {
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        let date = Self { year, month, day };
+        ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+            .then_some(date)
+    }
+
+    /// Current date in UTC.
+    pub fn today() -> Self {
+        let (year, month, day) = civil_from_days((unix_seconds(SystemTime::now()) / 86_400) as i64);
+        Self { year, month, day }
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for WaiverDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Serialize for WaiverDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WaiverDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        WaiverDate::parse(&raw).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid waiver expiry '{raw}', expected YYYY-MM-DD"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_orders_dates() {
+        let date = WaiverDate::parse("2024-02-29").unwrap();
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert!(WaiverDate::parse("2023-02-29").is_none());
+        assert!(WaiverDate::parse("2024-13-01").is_none());
+        assert!(WaiverDate::parse("soon").is_none());
+        assert!(date < WaiverDate::parse("2024-03-01").unwrap());
+    }
+}
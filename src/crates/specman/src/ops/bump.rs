@@ -0,0 +1,222 @@
+use std::fs;
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::env::SpecmanEnv;
+use crate::core::error::SpecmanError;
+use crate::core::shared::SemVer;
+use crate::graph::tree::{ArtifactId, VersionMismatch};
+use crate::metadata::frontmatter::{ArtifactFrontMatter, split_front_matter};
+use crate::metadata::mutation::write_artifact_front_matter;
+use crate::workspace::normalize_workspace_path;
+
+/// Which semver component a bump increments.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl VersionBump {
+    /// Returns `version` with the selected component incremented, lower components reset, and
+    /// pre-release/build metadata dropped.
+    pub fn apply(self, version: &SemVer) -> SemVer {
+        match self {
+            VersionBump::Major => SemVer::new(version.major + 1, 0, 0),
+            VersionBump::Minor => SemVer::new(version.major, version.minor + 1, 0),
+            VersionBump::Patch => SemVer::new(version.major, version.minor, version.patch + 1),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BumpOptions {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BumpResult {
+    pub artifact: ArtifactId,
+    pub path: PathBuf,
+    pub previous: SemVer,
+    pub version: SemVer,
+    /// Dependents whose version requirement on the artifact no longer holds.
+    pub broken: Vec<VersionMismatch>,
+}
+
+/// Increments the `version` front-matter field of `target` and reports the downstream
+/// dependency requirements that the new version fails. With `dry_run` nothing is written.
+pub fn bump_version(
+    env: &SpecmanEnv,
+    target: &ArtifactId,
+    bump: VersionBump,
+    opts: BumpOptions,
+) -> Result<BumpResult, SpecmanError> {
+    let path = env.persistence.artifact_path(target)?;
+    if !path.is_file() {
+        return Err(SpecmanError::MissingTarget(path));
+    }
+
+    let raw = fs::read_to_string(&path)?;
+    let split = split_front_matter(&raw)?;
+    let mut front = ArtifactFrontMatter::from_yaml_str(split.yaml)?;
+    let declared = front.version().ok_or_else(|| {
+        SpecmanError::Workspace(format!("{target} does not declare a version to bump"))
+    })?;
+    let previous = SemVer::parse(declared).map_err(|err| {
+        SpecmanError::Workspace(format!("{target} has invalid version '{declared}': {err}"))
+    })?;
+    let version = bump.apply(&previous);
+
+    let broken = env.graph.inventory_snapshot()?.mismatches_at_version(
+        &normalize_workspace_path(&path),
+        &previous,
+        &version,
+    );
+
+    if !opts.dry_run {
+        front.identity_mut().version = Some(version.to_string());
+        write_artifact_front_matter(&path, &front)?;
+        env.graph.invalidate_inventory();
+    }
+
+    Ok(BumpResult {
+        artifact: target.clone(),
+        path,
+        previous,
+        version,
+        broken,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use tempfile::TempDir;
+
+    use crate::graph::tree::ArtifactKind;
+    use crate::workspace::FilesystemWorkspaceLocator;
+
+    fn workspace_fixture() -> (TempDir, SpecmanEnv) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        fs::create_dir_all(root.join("spec/api")).unwrap();
+        fs::create_dir_all(root.join("spec/cli")).unwrap();
+
+        fs::write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.4.2\"\n---\n# Core\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("spec/api/spec.md"),
+            "---\nname: api\nversion: \"1.0.0\"\ndependencies:\n  - ref: ../core/spec.md\n    version: \"^1.4\"\n---\n# Api\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("spec/cli/spec.md"),
+            "---\nname: cli\nversion: \"1.0.0\"\ndependencies:\n  - ref: spec://core\n    version: \">=1\"\n---\n# Cli\n",
+        )
+        .unwrap();
+
+        let locator = Arc::new(FilesystemWorkspaceLocator::new(root.to_path_buf()));
+        let env = SpecmanEnv::new(locator, None).unwrap();
+        (temp, env)
+    }
+
+    fn spec(name: &str) -> ArtifactId {
+        ArtifactId {
+            kind: ArtifactKind::Specification,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn bump_levels_reset_lower_components() {
+        let version = SemVer::parse("1.4.2-rc.1").unwrap();
+        assert_eq!(VersionBump::Major.apply(&version).to_string(), "2.0.0");
+        assert_eq!(VersionBump::Minor.apply(&version).to_string(), "1.5.0");
+        assert_eq!(VersionBump::Patch.apply(&version).to_string(), "1.4.3");
+    }
+
+    #[test]
+    fn bump_rewrites_version_and_lists_broken_dependents() {
+        let (temp, env) = workspace_fixture();
+
+        let result = bump_version(
+            &env,
+            &spec("core"),
+            VersionBump::Minor,
+            BumpOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(result.version.to_string(), "1.5.0");
+        assert!(result.broken.is_empty());
+
+        let result = bump_version(
+            &env,
+            &spec("core"),
+            VersionBump::Major,
+            BumpOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(result.previous.to_string(), "1.5.0");
+        assert_eq!(result.version.to_string(), "2.0.0");
+        assert_eq!(result.broken.len(), 1);
+        assert_eq!(result.broken[0].dependent, spec("api"));
+        assert_eq!(result.broken[0].requirement, "^1.4");
+
+        let content = fs::read_to_string(temp.path().join("spec/core/spec.md")).unwrap();
+        assert!(content.contains("version: 2.0.0"));
+        assert!(content.ends_with("# Core\n"));
+    }
+
+    #[test]
+    fn bump_skips_dependents_that_already_fail() {
+        let (temp, env) = workspace_fixture();
+        fs::create_dir_all(temp.path().join("spec/legacy")).unwrap();
+        fs::write(
+            temp.path().join("spec/legacy/spec.md"),
+            "---\nname: legacy\nversion: \"1.0.0\"\ndependencies:\n  - ref: ../core/spec.md\n    version: \"^0.9\"\n---\n# Legacy\n",
+        )
+        .unwrap();
+
+        let result = bump_version(
+            &env,
+            &spec("core"),
+            VersionBump::Major,
+            BumpOptions { dry_run: true },
+        )
+        .unwrap();
+        let broken: Vec<_> = result.broken.iter().map(|m| &m.dependent).collect();
+        assert_eq!(broken, vec![&spec("api")]);
+    }
+
+    #[test]
+    fn dry_run_reports_broken_dependents_without_writing() {
+        let (temp, env) = workspace_fixture();
+        let path = temp.path().join("spec/core/spec.md");
+        let before = fs::read_to_string(&path).unwrap();
+
+        let result = bump_version(
+            &env,
+            &spec("core"),
+            VersionBump::Major,
+            BumpOptions { dry_run: true },
+        )
+        .unwrap();
+        assert_eq!(result.version.to_string(), "2.0.0");
+        assert_eq!(result.broken.len(), 1);
+        assert_eq!(result.broken[0].dependent, spec("api"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), before);
+    }
+}
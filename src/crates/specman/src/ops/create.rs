@@ -5,11 +5,12 @@ use crate::graph::tree::{
     normalize_persisted_reference_for_create,
 };
 use crate::metadata::frontmatter::{
-    ArtifactFrontMatter, ImplementationFrontMatter, ScratchFrontMatter, ScratchWorkType,
-    SpecificationFrontMatter, split_front_matter,
+    ArtifactFrontMatter, CustomWorkType, ImplementationFrontMatter, ScratchFrontMatter,
+    ScratchWorkType, SpecificationFrontMatter, split_front_matter,
 };
+use crate::scratchpad::load_work_types;
 use crate::storage::persistence::PersistedArtifact;
-use crate::templates::engine::{RenderedTemplate, TemplateScenario, TokenMap};
+use crate::templates::engine::{CreationContext, RenderedTemplate, TemplateScenario};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -62,15 +63,7 @@ pub fn create_specification(
         name: opts.name.clone(),
     };
 
-    let mut tokens = TokenMap::new();
-    tokens.insert(
-        "output_name".to_string(),
-        serde_json::Value::String(opts.name.clone()),
-    );
-    tokens.insert(
-        "title".to_string(),
-        serde_json::Value::String(opts.title.clone()),
-    );
+    let tokens = CreationContext::Specification { title: &opts.title }.tokens(&opts.name)?;
 
     let workspace = env.persistence.workspace()?;
     let artifact_path = artifact_path_for(&artifact, &workspace);
@@ -131,15 +124,10 @@ pub fn create_implementation(
         name: opts.name.clone(),
     };
 
-    let mut tokens = TokenMap::new();
-    tokens.insert(
-        "output_name".to_string(),
-        serde_json::Value::String(opts.name.clone()),
-    );
-    tokens.insert(
-        "target_path".to_string(),
-        serde_json::Value::String(opts.target.clone()),
-    );
+    let tokens = CreationContext::Implementation {
+        target: &opts.target,
+    }
+    .tokens(&opts.name)?;
 
     let workspace = env.persistence.workspace()?;
     let artifact_path = artifact_path_for(&artifact, &workspace);
@@ -191,34 +179,21 @@ pub fn create_scratch_pad(
 ) -> Result<CreateResult, SpecmanError> {
     // [ENSURES: concept-lifecycle-automation.requirements:CHECK]
     // [ENSURES: entity-lifecyclecontroller.requirements:CHECK]
-    let scenario = TemplateScenario::WorkType(opts.work_type.kind().as_str().to_string());
+    let scenario = TemplateScenario::WorkType(opts.work_type.name().to_string());
     let resolved = env.catalog.resolve(scenario)?;
+    if let ScratchWorkType::Custom(custom) = &opts.work_type {
+        check_custom_work_type(env, custom, &opts.target)?;
+    }
     let artifact = ArtifactId {
         kind: ArtifactKind::ScratchPad,
         name: opts.name.clone(),
     };
 
-    let mut tokens = TokenMap::new();
-    tokens.insert(
-        "output_name".to_string(),
-        serde_json::Value::String(opts.name.clone()),
-    );
-    tokens.insert(
-        "target_path".to_string(),
-        serde_json::Value::String(opts.target.clone()),
-    );
-    tokens.insert(
-        "work_type".to_string(),
-        serde_json::Value::String(opts.work_type.kind().as_str().to_string()),
-    );
-    tokens.insert(
-        "work_type_kind".to_string(),
-        serde_json::Value::String(opts.work_type.kind().as_str().to_string()),
-    );
-    tokens.insert(
-        "work_type_object".to_string(),
-        serde_json::to_value(&opts.work_type)?,
-    );
+    let tokens = CreationContext::ScratchPad {
+        target: &opts.target,
+        work_type: &opts.work_type,
+    }
+    .tokens(&opts.name)?;
 
     let workspace = env.persistence.workspace()?;
     let artifact_path = artifact_path_for(&artifact, &workspace);
@@ -265,6 +240,38 @@ pub fn create_scratch_pad(
     Ok(CreateResult::Persisted(persisted))
 }
 
+/// Enforces the required metadata and target kinds declared in `.specman/work-types.yaml`.
+fn check_custom_work_type(
+    env: &SpecmanEnv,
+    custom: &CustomWorkType,
+    target: &str,
+) -> Result<(), SpecmanError> {
+    let registry = load_work_types(&env.persistence.workspace()?)?;
+    let definition = registry
+        .get(&custom.name)
+        .ok_or_else(|| SpecmanError::UnknownWorkType(custom.name.clone()))?;
+
+    let missing = definition.missing_metadata(&custom.metadata);
+    if !missing.is_empty() {
+        return Err(SpecmanError::Workspace(format!(
+            "work type {} requires metadata: {}",
+            custom.name,
+            missing.join(", ")
+        )));
+    }
+
+    if !definition.targets.is_empty() {
+        let kind = env.graph.dependency_tree_from_locator(target)?.root.id.kind;
+        if !definition.allows_target(kind) {
+            return Err(SpecmanError::Workspace(format!(
+                "work type {} cannot target {target} ({kind:?})",
+                custom.name
+            )));
+        }
+    }
+    Ok(())
+}
+
 // Helpers copied/adapted from service.rs
 
 fn artifact_path_for(
@@ -1,4 +1,6 @@
+pub mod bump;
 pub mod create;
 pub mod delete;
+pub mod rename;
 
 pub mod update;
@@ -0,0 +1,721 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::env::SpecmanEnv;
+use crate::core::error::SpecmanError;
+use crate::graph::tree::{
+    ArtifactId, ArtifactKind, ReferenceField, diff_paths, normalize_persisted_reference_for_create,
+    resolve_reference_path,
+};
+use crate::metadata::frontmatter::{ArtifactFrontMatter, DependencyEntry, split_front_matter};
+use crate::validation::autofix::locate_destination;
+use crate::validation::references::link_destinations;
+use crate::workspace::{WorkspaceFederation, WorkspacePaths, normalize_workspace_path};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RenameOptions {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// A single reference that will be (or was) rewritten by a rename.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ReferenceRewrite {
+    pub artifact: ArtifactId,
+    pub path: PathBuf,
+    /// Front-matter field holding the reference (`dependencies`, `spec`, `references`, `target`),
+    /// or `body` for a markdown link.
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RenamePlan {
+    pub from: ArtifactId,
+    pub to: ArtifactId,
+    pub source: PathBuf,
+    pub destination: PathBuf,
+    pub rewrites: Vec<ReferenceRewrite>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "status", content = "plan", rename_all = "snake_case")]
+pub enum RenameResult {
+    Renamed(RenamePlan),
+    DryRun(RenamePlan),
+}
+
+impl RenameResult {
+    pub fn plan(&self) -> &RenamePlan {
+        match self {
+            RenameResult::Renamed(plan) | RenameResult::DryRun(plan) => plan,
+        }
+    }
+}
+
+/// Renames an artifact directory and rewrites every inbound front-matter reference and markdown
+/// link so the dependency graph and body links stay intact.
+pub fn rename_artifact(
+    env: &SpecmanEnv,
+    target: &ArtifactId,
+    new_name: &str,
+    opts: RenameOptions,
+) -> Result<RenameResult, SpecmanError> {
+    // [ENSURES: entity-lifecyclecontroller.requirements:CHECK]
+    let renamed = ArtifactId {
+        kind: target.kind,
+        name: validate_new_name(target, new_name)?,
+    };
+
+    let workspace = env.persistence.workspace()?;
+    let source = env.persistence.artifact_path(target)?;
+    let destination = env.persistence.artifact_path(&renamed)?;
+    if !source.is_file() {
+        return Err(SpecmanError::MissingTarget(source));
+    }
+    if destination.parent().is_some_and(Path::exists) {
+        return Err(SpecmanError::Workspace(format!(
+            "cannot rename {target} to {renamed}: {} already exists",
+            destination.display()
+        )));
+    }
+
+    let canonical_source = normalize_workspace_path(&source);
+    let context = RewriteContext {
+        workspace: &workspace,
+        federation: &WorkspaceFederation::load(&workspace)?,
+        source: &canonical_source,
+        renamed: &renamed,
+    };
+
+    let inventory = env.graph.inventory_snapshot()?;
+    let dependents: HashSet<PathBuf> = inventory
+        .dependents_of(&canonical_source)
+        .into_iter()
+        .filter_map(|dependent| dependent.summary.resolved_path)
+        .map(|path| normalize_workspace_path(Path::new(&path)))
+        .collect();
+    let links = LinkRewrite {
+        from: canonical_source.parent().unwrap_or(&canonical_source),
+        to: &normalize_workspace_path(destination.parent().unwrap_or(&destination)),
+    };
+
+    // Everything is rendered and checked before the directory moves, so a failure part way
+    // through can restore the workspace as it was.
+    let mut rewrites = Vec::new();
+    let mut writes = Vec::new();
+    for entry in inventory.entries.iter() {
+        let Some(path) = entry.summary.resolved_path.as_deref().map(PathBuf::from) else {
+            continue;
+        };
+        let canonical = normalize_workspace_path(&path);
+        let moved = canonical == canonical_source;
+        let (artifact, written_to) = if moved {
+            (&renamed, &destination)
+        } else {
+            (&entry.summary.id, &path)
+        };
+
+        let raw = fs::read_to_string(&path)?;
+        let mut changes = Vec::new();
+        let mut edits = Vec::new();
+        if moved {
+            edits.extend(identity_edit(&raw, &target.name, &renamed.name)?);
+        } else if dependents.contains(&canonical) {
+            let split = split_front_matter(&raw)?;
+            let mut front = ArtifactFrontMatter::from_yaml_str(split.yaml)?;
+            changes = rewrite_front_matter(&mut front, &path, &context)?;
+            edits.extend(front_matter_edits(&raw, &changes)?);
+        }
+        for (start, before, after) in links.rewrite(&raw, &path, written_to) {
+            edits.push((start..start + before.len(), after.clone()));
+            changes.push(("body".to_string(), before, after));
+        }
+        if edits.is_empty() {
+            continue;
+        }
+
+        if fs::metadata(&path)?.permissions().readonly() {
+            return Err(SpecmanError::Workspace(format!(
+                "cannot rename {target} to {renamed}: {} is read-only",
+                path.display()
+            )));
+        }
+        rewrites.extend(
+            changes
+                .into_iter()
+                .map(|(field, before, after)| ReferenceRewrite {
+                    artifact: artifact.clone(),
+                    path: written_to.clone(),
+                    field,
+                    before,
+                    after,
+                }),
+        );
+        writes.push(StagedWrite {
+            path: written_to.clone(),
+            updated: apply_edits(&raw, edits),
+            original: raw,
+        });
+    }
+
+    let plan = RenamePlan {
+        from: target.clone(),
+        to: renamed.clone(),
+        source,
+        destination,
+        rewrites,
+    };
+
+    if opts.dry_run {
+        return Ok(RenameResult::DryRun(plan));
+    }
+
+    env.persistence.relocate(target, &renamed)?;
+    if let Err(err) = commit_writes(&writes) {
+        let undo = env.persistence.relocate(&renamed, target);
+        env.graph.invalidate_inventory();
+        return Err(match undo {
+            Ok(_) => err,
+            Err(undo) => SpecmanError::Workspace(format!(
+                "{err}; moving {renamed} back to {target} also failed: {undo}"
+            )),
+        });
+    }
+    env.graph.invalidate_inventory();
+
+    Ok(RenameResult::Renamed(plan))
+}
+
+/// A file rewrite computed before the rename touches disk.
+struct StagedWrite {
+    path: PathBuf,
+    original: String,
+    updated: String,
+}
+
+/// Applies `writes` in order, restoring the files already written when one fails.
+fn commit_writes(writes: &[StagedWrite]) -> Result<(), SpecmanError> {
+    for (idx, write) in writes.iter().enumerate() {
+        if let Err(err) = fs::write(&write.path, &write.updated) {
+            for done in &writes[..idx] {
+                let _ = fs::write(&done.path, &done.original);
+            }
+            return Err(SpecmanError::Workspace(format!(
+                "failed to write {}: {err}",
+                write.path.display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+struct RewriteContext<'a> {
+    workspace: &'a WorkspacePaths,
+    federation: &'a WorkspaceFederation,
+    source: &'a Path,
+    renamed: &'a ArtifactId,
+}
+
+impl RewriteContext<'_> {
+    /// Returns the replacement for `reference` when it resolves to the renamed artifact,
+    /// preserving the original locator style (handle, scratch slug, or relative path).
+    fn rewrite(
+        &self,
+        reference: &str,
+        field: ReferenceField,
+        document: &Path,
+    ) -> Result<Option<String>, SpecmanError> {
+        let resolved =
+            resolve_reference_path(reference, field, document, self.workspace, self.federation);
+        if resolved.as_deref() != Some(self.source) {
+            return Ok(None);
+        }
+
+        let trimmed = reference.trim();
+        let handle = format!(
+            "{}://{}",
+            handle_scheme(self.renamed.kind),
+            self.renamed.name
+        );
+        if trimmed.contains("://") {
+            return Ok(Some(handle));
+        }
+        if field == ReferenceField::ScratchDependency
+            && !trimmed.contains('/')
+            && !trimmed.contains('\\')
+        {
+            return Ok(Some(self.renamed.name.clone()));
+        }
+
+        // The destination does not exist until the directory moves, so lower the handle with the
+        // create-time normalizer rather than the existence-checking variant.
+        let base = match field {
+            ReferenceField::Dependency => document.parent().unwrap_or(self.workspace.root()),
+            ReferenceField::ScratchTarget | ReferenceField::ScratchDependency => {
+                self.workspace.root()
+            }
+        };
+        normalize_persisted_reference_for_create(&handle, base, self.workspace).map(Some)
+    }
+
+    fn rewrite_dependencies(
+        &self,
+        entries: &mut [DependencyEntry],
+        field: ReferenceField,
+        document: &Path,
+        changes: &mut Vec<(String, String, String)>,
+    ) -> Result<(), SpecmanError> {
+        for entry in entries {
+            let reference = match entry {
+                DependencyEntry::Simple(value) => value,
+                DependencyEntry::Detailed(obj) => &mut obj.reference,
+            };
+            if let Some(after) = self.rewrite(reference, field, document)? {
+                let before = std::mem::replace(reference, after.clone());
+                changes.push(("dependencies".to_string(), before, after));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn rewrite_front_matter(
+    front: &mut ArtifactFrontMatter,
+    document: &Path,
+    context: &RewriteContext<'_>,
+) -> Result<Vec<(String, String, String)>, SpecmanError> {
+    let mut changes = Vec::new();
+    match front {
+        ArtifactFrontMatter::Specification(spec) => {
+            context.rewrite_dependencies(
+                &mut spec.dependencies,
+                ReferenceField::Dependency,
+                document,
+                &mut changes,
+            )?;
+        }
+        ArtifactFrontMatter::Implementation(implementation) => {
+            if let Some(spec) = implementation.spec.as_mut()
+                && let Some(after) = context.rewrite(spec, ReferenceField::Dependency, document)?
+            {
+                let before = std::mem::replace(spec, after.clone());
+                changes.push(("spec".to_string(), before, after));
+            }
+            for entry in &mut implementation.references {
+                if let Some(after) =
+                    context.rewrite(&entry.reference, ReferenceField::Dependency, document)?
+                {
+                    let before = std::mem::replace(&mut entry.reference, after.clone());
+                    changes.push(("references".to_string(), before, after));
+                }
+            }
+            context.rewrite_dependencies(
+                &mut implementation.dependencies,
+                ReferenceField::Dependency,
+                document,
+                &mut changes,
+            )?;
+        }
+        ArtifactFrontMatter::Scratch(scratch) => {
+            if let Some(target) = scratch.target.as_mut()
+                && let Some(after) =
+                    context.rewrite(target, ReferenceField::ScratchTarget, document)?
+            {
+                let before = std::mem::replace(target, after.clone());
+                changes.push(("target".to_string(), before, after));
+            }
+            context.rewrite_dependencies(
+                &mut scratch.dependencies,
+                ReferenceField::ScratchDependency,
+                document,
+                &mut changes,
+            )?;
+        }
+    }
+    Ok(changes)
+}
+
+/// Front-matter edit that updates `name` on the moved artifact when it still carries the old name.
+fn identity_edit(raw: &str, old_name: &str, new_name: &str) -> Result<Option<Edit>, SpecmanError> {
+    let split = split_front_matter(raw)?;
+    let front = ArtifactFrontMatter::from_yaml_str(split.yaml)?;
+    if front.name() != Some(old_name) {
+        return Ok(None);
+    }
+    let change = (
+        "name".to_string(),
+        old_name.to_string(),
+        new_name.to_string(),
+    );
+    Ok(front_matter_edits(raw, &[change])?.pop())
+}
+
+/// A byte range of a document and its replacement text.
+type Edit = (Range<usize>, String);
+
+fn apply_edits(raw: &str, mut edits: Vec<Edit>) -> String {
+    edits.sort_by_key(|(range, _)| range.start);
+    let mut updated = raw.to_string();
+    for (range, after) in edits.into_iter().rev() {
+        updated.replace_range(range, &after);
+    }
+    updated
+}
+
+/// Edits replacing each `(field, before, after)` scalar in the front matter of `raw`, so every
+/// other byte of the document stays as it was. Changes for the same field match in entry order.
+fn front_matter_edits(
+    raw: &str,
+    changes: &[(String, String, String)],
+) -> Result<Vec<Edit>, SpecmanError> {
+    let yaml = split_front_matter(raw)?.yaml;
+    let offset = yaml.as_ptr() as usize - raw.as_ptr() as usize;
+    let mut cursors: HashMap<&str, usize> = HashMap::new();
+    let mut edits = Vec::new();
+    for (field, before, after) in changes {
+        let located = field_block(yaml, field).and_then(|block| {
+            let cursor = cursors.entry(field).or_insert(block.start);
+            let found = find_scalar(yaml, *cursor..block.end, before)?;
+            *cursor = found.end;
+            Some(found)
+        });
+        let Some(found) = located else {
+            return Err(SpecmanError::Workspace(format!(
+                "could not locate '{before}' under '{field}' in the front matter"
+            )));
+        };
+        edits.push((offset + found.start..offset + found.end, after.clone()));
+    }
+    Ok(edits)
+}
+
+/// Byte range of the value belonging to the top-level `field` key: the rest of the key line
+/// plus every indented or list line that follows it.
+fn field_block(yaml: &str, field: &str) -> Option<Range<usize>> {
+    let mut start = None;
+    let mut pos = 0;
+    for line in yaml.split_inclusive('\n') {
+        let line_start = pos;
+        pos += line.len();
+        let top_level = !line.trim().is_empty() && !line.starts_with([' ', '\t', '-', '#']);
+        match start {
+            None => {
+                if top_level
+                    && let Some(rest) = line.strip_prefix(field)
+                    && rest.trim_start().starts_with(':')
+                {
+                    start = Some(line_start + field.len());
+                }
+            }
+            Some(start) if top_level => return Some(start..line_start),
+            Some(_) => {}
+        }
+    }
+    start.map(|start| start..yaml.len())
+}
+
+/// Finds `scalar` as a whole YAML scalar (plain or quoted) within `within`.
+fn find_scalar(yaml: &str, within: Range<usize>, scalar: &str) -> Option<Range<usize>> {
+    let haystack = &yaml[within.clone()];
+    haystack.match_indices(scalar).find_map(|(idx, _)| {
+        let start = within.start + idx;
+        let end = start + scalar.len();
+        let before = &yaml[..start];
+        let after = &yaml[end..];
+        let quote = before
+            .chars()
+            .next_back()
+            .filter(|c| matches!(c, '"' | '\''));
+        let before = quote.map_or(before, |q| &before[..before.len() - q.len_utf8()]);
+        let after = match quote {
+            Some(q) => after.strip_prefix(q)?,
+            None => after,
+        };
+        let opens = before
+            .trim_end_matches([' ', '\t'])
+            .ends_with([':', '-', '[', '{', ',']);
+        let after = after.trim_start_matches([' ', '\t']);
+        let closes = after.is_empty() || after.starts_with(['\n', '\r', ',', ']', '}', '#']);
+        (opens && closes).then_some(start..end)
+    })
+}
+
+/// Markdown links into the renamed artifact's directory, which moves from `from` to `to`.
+struct LinkRewrite<'a> {
+    from: &'a Path,
+    to: &'a Path,
+}
+
+impl LinkRewrite<'_> {
+    /// `(offset, before, after)` for each link in `raw` that stops resolving once the rename
+    /// moves `document` to `relocated` and the renamed directory to its new name. Fragments and
+    /// query strings are kept.
+    fn rewrite(
+        &self,
+        raw: &str,
+        document: &Path,
+        relocated: &Path,
+    ) -> Vec<(usize, String, String)> {
+        let (Some(base), Some(new_base)) = (document.parent(), relocated.parent()) else {
+            return Vec::new();
+        };
+        let mut edits = BTreeMap::new();
+        for (destination, line) in link_destinations(raw) {
+            if destination.contains("://") || destination.starts_with(['#', '/']) {
+                continue;
+            }
+            let (path, suffix) =
+                destination.split_at(destination.find(['#', '?']).unwrap_or(destination.len()));
+            if path.is_empty() {
+                continue;
+            }
+            let Ok(rest) = normalize_workspace_path(&base.join(path))
+                .strip_prefix(self.from)
+                .map(Path::to_path_buf)
+            else {
+                continue;
+            };
+            let moved = self.to.join(rest);
+            if normalize_workspace_path(&new_base.join(path)) == moved {
+                continue;
+            }
+            let Some(relative) = diff_paths(&moved, new_base) else {
+                continue;
+            };
+            let after = format!("{}{suffix}", relative.to_string_lossy().replace('\\', "/"));
+            if let Some(start) = locate_destination(raw, line, &destination) {
+                edits.entry(start).or_insert((destination, after));
+            }
+        }
+        edits
+            .into_iter()
+            .map(|(start, (before, after))| (start, before, after))
+            .collect()
+    }
+}
+
+fn validate_new_name(target: &ArtifactId, new_name: &str) -> Result<String, SpecmanError> {
+    let trimmed = new_name.trim();
+    if trimmed.is_empty() {
+        return Err(SpecmanError::Workspace(
+            "artifact name must not be empty".into(),
+        ));
+    }
+    if !trimmed
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_'))
+    {
+        return Err(SpecmanError::Workspace(format!(
+            "artifact name '{trimmed}' may only contain lowercase letters, numbers, '-' or '_'"
+        )));
+    }
+    if trimmed == target.name {
+        return Err(SpecmanError::Workspace(format!(
+            "{target} already uses the name '{trimmed}'"
+        )));
+    }
+    Ok(trimmed.to_string())
+}
+
+fn handle_scheme(kind: ArtifactKind) -> &'static str {
+    match kind {
+        ArtifactKind::Specification => "spec",
+        ArtifactKind::Implementation => "impl",
+        ArtifactKind::ScratchPad => "scratch",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use tempfile::TempDir;
+
+    use crate::workspace::FilesystemWorkspaceLocator;
+
+    fn workspace_fixture() -> (TempDir, SpecmanEnv) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(".specman/scratchpad/auth-notes")).unwrap();
+        fs::create_dir_all(root.join("spec/auth")).unwrap();
+        fs::create_dir_all(root.join("spec/gateway")).unwrap();
+        fs::create_dir_all(root.join("impl/auth-rust")).unwrap();
+
+        fs::write(
+            root.join("spec/auth/spec.md"),
+            "---\nname: auth\nversion: \"1.0.0\"\ndependencies: []\n---\n# Auth\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("spec/gateway/spec.md"),
+            "---\nname: gateway\nversion: \"1.0.0\"\ndependencies:\n  - ../auth/spec.md\n  - ref: spec://auth\n    optional: true\n---\n# Gateway\n\nSee [tokens](../auth/spec.md#auth).\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("impl/auth-rust/impl.md"),
+            "---\nname: auth-rust\nspec: ../../spec/auth/spec.md\nreferences:\n  - ref: spec://auth\n    type: specification\n---\n# Auth Rust\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(".specman/scratchpad/auth-notes/scratch.md"),
+            "---\nname: auth-notes\ntarget: spec/auth/spec.md\nwork_type:\n  revision: {}\n---\n# Notes\n",
+        )
+        .unwrap();
+
+        let locator = Arc::new(FilesystemWorkspaceLocator::new(root.to_path_buf()));
+        let env = SpecmanEnv::new(locator, None).unwrap();
+        (temp, env)
+    }
+
+    fn spec(name: &str) -> ArtifactId {
+        ArtifactId {
+            kind: ArtifactKind::Specification,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn dry_run_reports_rewrites_without_touching_disk() {
+        let (temp, env) = workspace_fixture();
+        let root = temp.path();
+        let before = fs::read_to_string(root.join("spec/gateway/spec.md")).unwrap();
+
+        let result = rename_artifact(
+            &env,
+            &spec("auth"),
+            "identity",
+            RenameOptions { dry_run: true },
+        )
+        .expect("dry run");
+
+        let RenameResult::DryRun(plan) = result else {
+            panic!("expected dry run result");
+        };
+        let mut observed: Vec<_> = plan
+            .rewrites
+            .iter()
+            .map(|r| (r.artifact.name.as_str(), r.field.as_str(), r.after.as_str()))
+            .collect();
+        observed.sort();
+        assert_eq!(
+            observed,
+            vec![
+                ("auth-notes", "target", "spec/identity/spec.md"),
+                ("auth-rust", "references", "spec://identity"),
+                ("auth-rust", "spec", "../../spec/identity/spec.md"),
+                ("gateway", "body", "../identity/spec.md#auth"),
+                ("gateway", "dependencies", "../identity/spec.md"),
+                ("gateway", "dependencies", "spec://identity"),
+            ]
+        );
+        assert!(root.join("spec/auth/spec.md").is_file());
+        assert!(!root.join("spec/identity").exists());
+        assert_eq!(
+            before,
+            fs::read_to_string(root.join("spec/gateway/spec.md")).unwrap()
+        );
+    }
+
+    #[test]
+    fn rename_moves_directory_and_keeps_graph_intact() {
+        let (temp, env) = workspace_fixture();
+        let root = temp.path();
+
+        rename_artifact(&env, &spec("auth"), "identity", RenameOptions::default()).expect("rename");
+
+        assert!(!root.join("spec/auth").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("spec/identity/spec.md")).unwrap(),
+            "---\nname: identity\nversion: \"1.0.0\"\ndependencies: []\n---\n# Auth\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("spec/gateway/spec.md")).unwrap(),
+            "---\nname: gateway\nversion: \"1.0.0\"\ndependencies:\n  - ../identity/spec.md\n  - ref: spec://identity\n    optional: true\n---\n# Gateway\n\nSee [tokens](../identity/spec.md#auth).\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("impl/auth-rust/impl.md")).unwrap(),
+            "---\nname: auth-rust\nspec: ../../spec/identity/spec.md\nreferences:\n  - ref: spec://identity\n    type: specification\n---\n# Auth Rust\n"
+        );
+
+        let tree = env.mapping.dependency_tree(&spec("identity")).unwrap();
+        let mut dependents: Vec<_> = tree
+            .downstream
+            .iter()
+            .map(|edge| edge.from.id.name.clone())
+            .collect();
+        dependents.sort();
+        dependents.dedup();
+        assert_eq!(dependents, vec!["auth-notes", "auth-rust", "gateway"]);
+    }
+
+    #[test]
+    fn workspace_status_passes_after_rename() {
+        let (temp, env) = workspace_fixture();
+        let root = temp.path();
+
+        rename_artifact(&env, &spec("auth"), "identity", RenameOptions::default()).expect("rename");
+
+        // The fixture implementation has no `location`, so compliance has nothing to scan.
+        let config = crate::validation::status::WorkspaceStatusConfig {
+            compliance: false,
+            reference_options: Some(crate::validation::references::ReferenceValidationOptions {
+                https: crate::validation::references::HttpsValidationOptions {
+                    mode: crate::validation::references::HttpsValidationMode::SyntaxOnly,
+                },
+                transitive: Default::default(),
+            }),
+            ..Default::default()
+        };
+        let report =
+            crate::validation::status::validate_workspace_status(root.to_path_buf(), config)
+                .unwrap();
+        assert_eq!(
+            report.global_status,
+            crate::validation::status::StatusResult::Pass,
+            "{report:?}"
+        );
+    }
+
+    #[test]
+    fn rename_leaves_workspace_untouched_when_a_dependent_is_read_only() {
+        let (temp, env) = workspace_fixture();
+        let root = temp.path();
+        let gateway = root.join("spec/gateway/spec.md");
+        let impl_before = fs::read_to_string(root.join("impl/auth-rust/impl.md")).unwrap();
+        let original = fs::metadata(&gateway).unwrap().permissions();
+        let mut read_only = original.clone();
+        read_only.set_readonly(true);
+        fs::set_permissions(&gateway, read_only).unwrap();
+
+        let err = rename_artifact(&env, &spec("auth"), "identity", RenameOptions::default())
+            .expect_err("read-only dependent");
+        assert!(err.to_string().contains("is read-only"), "{err}");
+
+        fs::set_permissions(&gateway, original).unwrap();
+
+        assert!(root.join("spec/auth/spec.md").is_file());
+        assert!(!root.join("spec/identity").exists());
+        let auth = fs::read_to_string(root.join("spec/auth/spec.md")).unwrap();
+        assert!(auth.contains("name: auth\n"), "{auth}");
+        assert_eq!(
+            impl_before,
+            fs::read_to_string(root.join("impl/auth-rust/impl.md")).unwrap()
+        );
+    }
+
+    #[test]
+    fn rename_rejects_existing_destination() {
+        let (_temp, env) = workspace_fixture();
+        let err = rename_artifact(&env, &spec("auth"), "gateway", RenameOptions::default())
+            .expect_err("destination exists");
+        assert!(err.to_string().contains("already exists"), "{err}");
+    }
+}
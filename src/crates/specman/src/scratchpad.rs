@@ -1,9 +1,19 @@
 use std::collections::BTreeMap;
+use std::fs;
 
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
 use crate::templates::engine::{TemplateDescriptor, TemplateProvenance, TokenMap};
+use crate::workspace::WorkspacePaths;
+
+/// Custom work type declarations, relative to `.specman/`.
+pub const WORK_TYPES_FILE: &str = "work-types.yaml";
+
+/// Work type names built into SpecMan; `.specman/work-types.yaml` cannot redeclare them.
+pub const BUILTIN_WORK_TYPES: [&str; 5] = ["draft", "revision", "feat", "ref", "fix"];
 
 /// Standard scratch pad profiles aligned with SpecMan work types.
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -12,17 +22,20 @@ pub enum ScratchPadProfileKind {
     Feat,
     Fix,
     Revision,
+    /// A work type declared in `.specman/work-types.yaml`.
+    Custom(String),
 }
 
 impl ScratchPadProfileKind {
     /// Returns the canonical slug used for workspace directories and provenance.
-    pub fn slug(&self) -> &'static str {
+    pub fn slug(&self) -> &str {
         // [ENSURES: concept-scratch-pads.work-type:CHECK]
         match self {
             Self::Ref => "ref",
             Self::Feat => "feat",
             Self::Fix => "fix",
             Self::Revision => "revision",
+            Self::Custom(name) => name,
         }
     }
 }
@@ -66,3 +79,132 @@ impl ScratchPadProfile {
             .collect()
     }
 }
+
+/// A scratch pad work type declared in `.specman/work-types.yaml`:
+///
+/// ```yaml
+/// spike:
+///   description: Time-boxed investigation of an open question
+///   template: docs/templates/spike.md
+///   targets: [spec, impl]
+///   required_metadata: [question, timebox]
+///   unfilled_markers: ["SPIKE TODO:"]
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WorkTypeDefinition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Workspace-relative path or HTTPS URL of the template. Without one, the type resolves
+    /// only from `.specman/templates/scratch/<name>.md`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Artifact kinds (`spec`, `impl`, `scratch`) a scratch pad of this type may target; any
+    /// kind when empty.
+    #[serde(
+        default,
+        serialize_with = "serialize_target_kinds",
+        deserialize_with = "deserialize_target_kinds"
+    )]
+    #[schemars(with = "Vec<String>")]
+    pub targets: Vec<ArtifactKind>,
+    /// Keys that must be set under `work_type.<name>` in the scratch pad front matter.
+    #[serde(default)]
+    pub required_metadata: Vec<String>,
+    /// Guard comment prefixes that mark unfilled scaffolding in scratch pads of this type;
+    /// replaces the default markers of the resolved template when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfilled_markers: Option<Vec<String>>,
+}
+
+impl WorkTypeDefinition {
+    /// Whether a scratch pad of this type may target an artifact of `kind`.
+    pub fn allows_target(&self, kind: ArtifactKind) -> bool {
+        self.targets.is_empty() || self.targets.contains(&kind)
+    }
+
+    /// Required metadata keys that are absent or null in `metadata`.
+    pub fn missing_metadata<'a>(
+        &'a self,
+        metadata: &BTreeMap<String, serde_json::Value>,
+    ) -> Vec<&'a str> {
+        self.required_metadata
+            .iter()
+            .filter(|key| metadata.get(*key).is_none_or(|value| value.is_null()))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Contents of `.specman/work-types.yaml`, keyed by work type name.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct WorkTypeRegistry {
+    pub work_types: BTreeMap<String, WorkTypeDefinition>,
+}
+
+impl WorkTypeRegistry {
+    pub fn get(&self, name: &str) -> Option<&WorkTypeDefinition> {
+        self.work_types.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &WorkTypeDefinition)> {
+        self.work_types
+            .iter()
+            .map(|(name, definition)| (name.as_str(), definition))
+    }
+}
+
+/// Reads `.specman/work-types.yaml`; a missing file yields an empty registry.
+pub fn load_work_types(workspace: &WorkspacePaths) -> Result<WorkTypeRegistry, SpecmanError> {
+    let path = workspace.dot_specman().join(WORK_TYPES_FILE);
+    if !path.is_file() {
+        return Ok(WorkTypeRegistry::default());
+    }
+    let content = fs::read_to_string(&path)?;
+    let invalid = |message: String| {
+        SpecmanError::Serialization(format!("invalid {}: {message}", path.display()))
+    };
+    let registry: WorkTypeRegistry =
+        serde_yaml::from_str(&content).map_err(|err| invalid(err.to_string()))?;
+    for name in registry.work_types.keys() {
+        if BUILTIN_WORK_TYPES.contains(&name.as_str()) {
+            return Err(invalid(format!("work type `{name}` is built in")));
+        }
+        let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(invalid(format!(
+                "work type `{name}` must be lowercase letters, digits, and hyphens"
+            )));
+        }
+    }
+    Ok(registry)
+}
+
+fn serialize_target_kinds<S>(kinds: &[ArtifactKind], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    kinds
+        .iter()
+        .map(|kind| match kind {
+            ArtifactKind::Specification => "spec",
+            ArtifactKind::Implementation => "impl",
+            ArtifactKind::ScratchPad => "scratch",
+        })
+        .collect::<Vec<_>>()
+        .serialize(serializer)
+}
+
+fn deserialize_target_kinds<'de, D>(deserializer: D) -> Result<Vec<ArtifactKind>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|kind| kind.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
@@ -181,6 +181,59 @@ impl<L: WorkspaceLocator> WorkspacePersistence<L> {
         })
     }
 
+    /// Moves the canonical artifact directory for `from` to the location owned by `to`.
+    ///
+    /// Both artifacts must share a kind and the destination directory must not exist yet.
+    pub fn relocate(
+        &self,
+        from: &ArtifactId,
+        to: &ArtifactId,
+    ) -> Result<PersistedArtifact, SpecmanError> {
+        ensure_safe_name(&from.name)?;
+        ensure_safe_name(&to.name)?;
+        if from.kind != to.kind {
+            return Err(SpecmanError::Workspace(format!(
+                "cannot relocate {from} to {to}: artifact kinds differ"
+            )));
+        }
+
+        let workspace = self.locator.workspace()?;
+        let source_file = resolve_target_path(from, &workspace)?;
+        let target_file = resolve_target_path(to, &workspace)?;
+        let (Some(source_dir), Some(target_dir)) = (source_file.parent(), target_file.parent())
+        else {
+            return Err(SpecmanError::Workspace(format!(
+                "unable to compute artifact directories for {from} and {to}"
+            )));
+        };
+
+        if !source_dir.is_dir() {
+            return Err(SpecmanError::Workspace(format!(
+                "artifact directory does not exist: {}",
+                source_dir.display()
+            )));
+        }
+        if target_dir.exists() {
+            return Err(SpecmanError::Workspace(format!(
+                "artifact directory already exists: {}",
+                target_dir.display()
+            )));
+        }
+
+        fs::rename(source_dir, target_dir)?;
+        if let Some(inventory) = &self.dependency_inventory {
+            inventory.invalidate();
+        }
+        self.invalidate_tree_in_adapter(from)?;
+        self.invalidate_tree_in_adapter(to)?;
+
+        Ok(PersistedArtifact {
+            artifact: to.clone(),
+            path: target_file,
+            workspace,
+        })
+    }
+
     /// Saves the provided dependency tree via the configured data-model adapter.
     pub fn save_dependency_tree(
         &self,
@@ -9,7 +9,10 @@ use sha2::{Digest, Sha256};
 use url::Url;
 
 use crate::core::error::SpecmanError;
-use crate::scratchpad::{ScratchPadProfile, ScratchPadProfileKind};
+use crate::core::network::{NetworkPolicy, VendorDirectory};
+use crate::scratchpad::{
+    ScratchPadProfile, ScratchPadProfileKind, WORK_TYPES_FILE, WorkTypeDefinition, load_work_types,
+};
 use crate::workspace::{WorkspacePaths, workspace_relative_path};
 
 use crate::templates::engine::{
@@ -27,6 +30,7 @@ const EMBEDDED_SCRATCH_REVISION: &str = include_str!("scratch/revision.md");
 /// pointer files, remote caches, and embedded defaults.
 pub struct TemplateCatalog {
     workspace: WorkspacePaths,
+    network: NetworkPolicy,
 }
 
 /// Result of resolving a template with provenance metadata for persistence.
@@ -46,22 +50,38 @@ struct ResolvedFromPathOverrides {
 
 impl TemplateCatalog {
     pub fn new(workspace: WorkspacePaths) -> Self {
-        Self { workspace }
+        let network = NetworkPolicy::for_workspace_or_deny(&workspace);
+        Self::with_network_policy(workspace, network)
+    }
+
+    /// Remote pointers are downloaded only under [`NetworkPolicy::Allow`]; otherwise they
+    /// resolve from `.specman/vendor` (and, for `cache-only`, the template cache).
+    pub fn with_network_policy(workspace: WorkspacePaths, network: NetworkPolicy) -> Self {
+        Self { workspace, network }
     }
 
     /// Resolves a template descriptor for the given scenario following the
     /// override → pointer → embedded order mandated by SpecMan Core.
+    /// Work types declared in `.specman/work-types.yaml` resolve from their declared template
+    /// instead of the `SCRATCH` pointer, have no embedded default, and may declare their own
+    /// unfilled markers.
     pub fn resolve(&self, scenario: TemplateScenario) -> Result<ResolvedTemplate, SpecmanError> {
-        validate_scenario(&scenario)?;
-        if let Some(resolved) = self.try_workspace_override(&scenario)? {
-            return Ok(resolved);
-        }
+        let custom = self.check_scenario(&scenario)?;
+        let mut resolved = match self.try_workspace_override(&scenario)? {
+            Some(resolved) => resolved,
+            None => match &custom {
+                Some((name, definition)) => self.declared_template(&scenario, name, definition)?,
+                None => match self.try_pointer(&scenario)? {
+                    Some(resolved) => resolved,
+                    None => self.embedded_default(&scenario)?,
+                },
+            },
+        };
 
-        if let Some(resolved) = self.try_pointer(&scenario)? {
-            return Ok(resolved);
+        if let Some(markers) = custom.and_then(|(_, definition)| definition.unfilled_markers) {
+            resolved.descriptor.unfilled_markers = markers;
         }
-
-        self.embedded_default(&scenario)
+        Ok(resolved)
     }
 
     /// Sets or updates the pointer file for the provided scenario and returns the
@@ -71,13 +91,13 @@ impl TemplateCatalog {
         scenario: TemplateScenario,
         locator: impl AsRef<str>,
     ) -> Result<ResolvedTemplate, SpecmanError> {
-        validate_scenario(&scenario)?;
+        self.validate_pointer_scenario(&scenario)?;
         let pointer_name = pointer_name(&scenario);
         let templates_dir = self.templates_dir();
         let lock = PointerLock::acquire(&templates_dir, pointer_name)?;
         let destination = self.normalize_pointer_locator(locator.as_ref())?;
         if let PointerDestination::Remote(url) = &destination {
-            let cache = TemplateCache::new(&self.workspace);
+            let cache = TemplateCache::new(&self.workspace, self.network);
             cache.fetch_url(url)?;
         }
 
@@ -93,7 +113,7 @@ impl TemplateCatalog {
         &self,
         scenario: TemplateScenario,
     ) -> Result<ResolvedTemplate, SpecmanError> {
-        validate_scenario(&scenario)?;
+        self.validate_pointer_scenario(&scenario)?;
         let pointer_name = pointer_name(&scenario);
         let templates_dir = self.templates_dir();
         let lock = PointerLock::acquire(&templates_dir, pointer_name)?;
@@ -128,7 +148,7 @@ impl TemplateCatalog {
                     "pointer {pointer_name} referenced invalid URL {trimmed}: {err}"
                 ))
             })?;
-            let cache = TemplateCache::new(&self.workspace);
+            let cache = TemplateCache::new(&self.workspace, self.network);
             cache.invalidate_url(&url)?;
         }
 
@@ -137,6 +157,26 @@ impl TemplateCatalog {
         self.resolve(scenario)
     }
 
+    /// Every scenario `resolve` accepts in this workspace: specifications, implementations, the
+    /// built-in work types, then work types declared in `.specman/work-types.yaml`.
+    pub fn scenarios(&self) -> Result<Vec<TemplateScenario>, SpecmanError> {
+        let mut scenarios = vec![
+            TemplateScenario::Specification,
+            TemplateScenario::Implementation,
+        ];
+        scenarios.extend(
+            ["ref", "feat", "fix", "revision"]
+                .into_iter()
+                .map(|kind| TemplateScenario::WorkType(kind.to_string())),
+        );
+        scenarios.extend(
+            load_work_types(&self.workspace)?
+                .iter()
+                .map(|(name, _)| TemplateScenario::WorkType(name.to_string())),
+        );
+        Ok(scenarios)
+    }
+
     /// Convenience helper for describing scratch pad profiles with catalog
     /// managed templates and provenance metadata.
     pub fn scratch_profile(
@@ -154,6 +194,119 @@ impl TemplateCatalog {
         })
     }
 
+    /// URLs that template pointer files currently point at.
+    pub(crate) fn remote_pointers(&self) -> Result<Vec<Url>, SpecmanError> {
+        let mut urls = Vec::new();
+        for pointer_name in ["SPEC", "IMPL", "SCRATCH"] {
+            let path = self.templates_dir().join(pointer_name);
+            if !path.is_file() {
+                continue;
+            }
+            let contents = fs::read_to_string(&path)?;
+            let trimmed = contents.trim();
+            if (trimmed.starts_with("http://") || trimmed.starts_with("https://"))
+                && let Ok(url) = Url::parse(trimmed)
+            {
+                urls.push(url);
+            }
+        }
+        for (_, definition) in load_work_types(&self.workspace)?.iter() {
+            if let Some(template) = &definition.template
+                && template.starts_with("https://")
+                && let Ok(url) = Url::parse(template)
+            {
+                urls.push(url);
+            }
+        }
+        Ok(urls)
+    }
+
+    /// Validates the scenario and returns the name and declaration of a custom work type.
+    fn check_scenario(
+        &self,
+        scenario: &TemplateScenario,
+    ) -> Result<Option<(String, WorkTypeDefinition)>, SpecmanError> {
+        match validate_scenario(scenario) {
+            Err(SpecmanError::UnknownWorkType(kind)) => {
+                let slug = sanitize_key(&kind);
+                match load_work_types(&self.workspace)?.get(&slug) {
+                    Some(definition) => Ok(Some((slug, definition.clone()))),
+                    None => Err(SpecmanError::UnknownWorkType(kind)),
+                }
+            }
+            other => other.map(|_| None),
+        }
+    }
+
+    /// Pointer files only apply to built-in scenarios.
+    fn validate_pointer_scenario(&self, scenario: &TemplateScenario) -> Result<(), SpecmanError> {
+        if let Some((name, _)) = self.check_scenario(scenario)? {
+            return Err(SpecmanError::Template(format!(
+                "work type {name} takes its template from {WORK_TYPES_FILE}, not a pointer file"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Resolves the `template` locator a custom work type declares in `work-types.yaml`.
+    fn declared_template(
+        &self,
+        scenario: &TemplateScenario,
+        name: &str,
+        definition: &WorkTypeDefinition,
+    ) -> Result<ResolvedTemplate, SpecmanError> {
+        let Some(locator) = definition.template.as_deref().map(str::trim) else {
+            let candidates = self
+                .override_candidates(scenario)
+                .iter()
+                .map(|path| workspace_relative(self.workspace.root(), path))
+                .collect::<Vec<_>>()
+                .join(" or ");
+            return Err(SpecmanError::Template(format!(
+                "work type {name} declares no template in {WORK_TYPES_FILE} and {candidates} does not exist"
+            )));
+        };
+
+        if locator.starts_with("http://") {
+            return Err(SpecmanError::Template(format!(
+                "work type {name} template {locator} must use https"
+            )));
+        }
+
+        if locator.starts_with("https://") {
+            let url = Url::parse(locator).map_err(|err| {
+                SpecmanError::Template(format!(
+                    "work type {name} has an invalid template URL {locator}: {err}"
+                ))
+            })?;
+            let cache = TemplateCache::new(&self.workspace, self.network);
+            let hit = cache.fetch_url(&url)?;
+            let cache_path = workspace_relative(self.workspace.root(), &hit.path);
+            return Ok(self.resolved_from_path(
+                scenario,
+                hit.path,
+                TemplateTier::PointerUrl,
+                ResolvedFromPathOverrides {
+                    pointer: Some(WORK_TYPES_FILE.to_string()),
+                    locator_override: Some(url.to_string()),
+                    cache_override: Some(cache_path),
+                    last_modified: hit.last_modified,
+                },
+            ));
+        }
+
+        let path = self.resolve_pointer_path(locator, WORK_TYPES_FILE)?;
+        Ok(self.resolved_from_path(
+            scenario,
+            path,
+            TemplateTier::PointerFile,
+            ResolvedFromPathOverrides {
+                pointer: Some(WORK_TYPES_FILE.to_string()),
+                ..ResolvedFromPathOverrides::default()
+            },
+        ))
+    }
+
     /// Returns the `.specman/templates` directory inside the active workspace.
     fn templates_dir(&self) -> PathBuf {
         self.workspace.dot_specman().join("templates")
@@ -253,7 +406,7 @@ impl TemplateCatalog {
     /// Rewrites the embedded fallback cache copy immediately after pointer mutations.
     fn refresh_embedded_cache(&self, scenario: &TemplateScenario) -> Result<(), SpecmanError> {
         let (key, body) = embedded_assets(scenario)?;
-        let cache = TemplateCache::new(&self.workspace);
+        let cache = TemplateCache::new(&self.workspace, self.network);
         // [ENSURES: concept-template-orchestration.ai-instruction-directives:CHECK]
         // Embedded template bodies are copied verbatim so AI instruction directives remain intact.
         cache.write_embedded(key, body).map(|_| ())
@@ -304,7 +457,7 @@ impl TemplateCatalog {
             let url = Url::parse(trimmed).map_err(|err| {
                 SpecmanError::Template(format!("invalid template pointer URL {trimmed}: {err}"))
             })?;
-            let cache = TemplateCache::new(&self.workspace);
+            let cache = TemplateCache::new(&self.workspace, self.network);
             match cache.fetch_url(&url) {
                 Ok(hit) => {
                     let cache_path = workspace_relative(self.workspace.root(), &hit.path);
@@ -346,7 +499,7 @@ impl TemplateCatalog {
     ) -> Result<ResolvedTemplate, SpecmanError> {
         let (key, body) = embedded_assets(scenario)?;
 
-        let cache = TemplateCache::new(&self.workspace);
+        let cache = TemplateCache::new(&self.workspace, self.network);
         let path = cache.write_embedded(key, body)?;
         let cache_path = workspace_relative(self.workspace.root(), &path);
         Ok(self.resolved_from_path(
@@ -432,6 +585,7 @@ impl TemplateCatalog {
                 locator,
                 scenario: scenario.clone(),
                 required_tokens: Vec::new(),
+                ..TemplateDescriptor::default()
             },
             provenance,
         }
@@ -500,12 +654,16 @@ fn workspace_relative(root: &Path, path: &Path) -> String {
 struct TemplateCache {
     // [ENSURES: entity-templatecache.requirements:CHECK]
     root: PathBuf,
+    vendor: VendorDirectory,
+    network: NetworkPolicy,
 }
 
 impl TemplateCache {
-    fn new(workspace: &WorkspacePaths) -> Self {
+    fn new(workspace: &WorkspacePaths, network: NetworkPolicy) -> Self {
         Self {
             root: workspace.dot_specman().join("cache").join("templates"),
+            vendor: VendorDirectory::new(workspace),
+            network,
         }
     }
 
@@ -526,6 +684,26 @@ impl TemplateCache {
         let path = self.root.join(format!("url-{key}.md"));
         let meta_path = self.root.join(format!("url-{key}.json"));
 
+        if !self.network.allows_network() {
+            if let Some(path) = self.vendor.existing(url) {
+                return Ok(CacheHit {
+                    path,
+                    last_modified: None,
+                });
+            }
+            if self.network.allows_caches() && path.is_file() {
+                let metadata = read_metadata(&meta_path)?;
+                return Ok(CacheHit {
+                    path,
+                    last_modified: metadata.and_then(|m| m.last_modified),
+                });
+            }
+            return Err(SpecmanError::Template(format!(
+                "template {url} is not vendored and the `{}` network policy forbids downloading it",
+                self.network
+            )));
+        }
+
         match ureq::get(url.as_str()).call() {
             Ok(response) => {
                 if response.status() >= 400 {
@@ -7,6 +7,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::core::error::SpecmanError;
+use crate::metadata::frontmatter::ScratchWorkType;
 
 pub type TokenMap = BTreeMap<String, serde_json::Value>;
 
@@ -34,7 +35,7 @@ pub struct TemplateProvenance {
 }
 
 /// Supported template scenarios described by the specification.
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
 pub enum TemplateScenario {
     Specification,
     Implementation,
@@ -48,6 +49,28 @@ impl Default for TemplateScenario {
     }
 }
 
+impl TemplateScenario {
+    /// Short label used on the command line: `spec`, `impl`, `scratch`, or the work type name.
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Specification => "spec",
+            Self::Implementation => "impl",
+            Self::ScratchPad => "scratch",
+            Self::WorkType(kind) => kind,
+        }
+    }
+
+    /// Inverse of [`TemplateScenario::label`]; any other label names a work type.
+    pub fn from_label(label: &str) -> Self {
+        match label {
+            "spec" | "specification" => Self::Specification,
+            "impl" | "implementation" => Self::Implementation,
+            "scratch" => Self::ScratchPad,
+            other => Self::WorkType(other.to_string()),
+        }
+    }
+}
+
 /// Template locators include both filesystem paths and remote URLs.
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub enum TemplateLocator {
@@ -61,13 +84,39 @@ impl Default for TemplateLocator {
     }
 }
 
+/// Guard comment prefixes that the embedded templates use for authoring directives.
+pub const DEFAULT_UNFILLED_MARKERS: &[&str] = &["AI TODO:", "AI INSTRUCTIONS:", "AI STOP:"];
+
 /// Rich descriptor for a template plus its required tokens.
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TemplateDescriptor {
     // [ENSURES: entity-templatedescriptor.requirements:CHECK]
     pub locator: TemplateLocator,
     pub scenario: TemplateScenario,
     pub required_tokens: Vec<String>,
+    /// HTML comment prefixes (the text after `<!--`) that mark directives an author must
+    /// resolve; artifacts still carrying them fail the status structure check. Work types
+    /// declared in `.specman/work-types.yaml` may replace them with `unfilled_markers`.
+    #[serde(default = "default_unfilled_markers")]
+    pub unfilled_markers: Vec<String>,
+}
+
+impl Default for TemplateDescriptor {
+    fn default() -> Self {
+        Self {
+            locator: TemplateLocator::default(),
+            scenario: TemplateScenario::default(),
+            required_tokens: Vec::new(),
+            unfilled_markers: default_unfilled_markers(),
+        }
+    }
+}
+
+fn default_unfilled_markers() -> Vec<String> {
+    DEFAULT_UNFILLED_MARKERS
+        .iter()
+        .map(|marker| marker.to_string())
+        .collect()
 }
 
 /// Result of rendering a template with tokens.
@@ -162,3 +211,43 @@ pub struct ScratchPadContext {
     pub target: String,
     pub work_type: String,
 }
+
+/// What SpecMan knows, besides its name, about an artifact it creates from a template.
+#[derive(Clone, Copy, Debug)]
+pub enum CreationContext<'a> {
+    Specification {
+        title: &'a str,
+    },
+    Implementation {
+        target: &'a str,
+    },
+    ScratchPad {
+        target: &'a str,
+        work_type: &'a ScratchWorkType,
+    },
+}
+
+impl CreationContext<'_> {
+    /// Tokens passed to the template when creating `name`: `output_name`, then `title` for
+    /// specifications, or `target_path` and, for scratch pads, `work_type`, `work_type_kind`,
+    /// and `work_type_object`.
+    pub fn tokens(&self, name: &str) -> Result<TokenMap, SpecmanError> {
+        let mut tokens = TokenMap::new();
+        tokens.insert("output_name".into(), name.into());
+        match self {
+            CreationContext::Specification { title } => {
+                tokens.insert("title".into(), (*title).into());
+            }
+            CreationContext::Implementation { target } => {
+                tokens.insert("target_path".into(), (*target).into());
+            }
+            CreationContext::ScratchPad { target, work_type } => {
+                tokens.insert("target_path".into(), (*target).into());
+                tokens.insert("work_type".into(), work_type.name().into());
+                tokens.insert("work_type_kind".into(), work_type.kind().as_str().into());
+                tokens.insert("work_type_object".into(), serde_json::to_value(work_type)?);
+            }
+        }
+        Ok(tokens)
+    }
+}
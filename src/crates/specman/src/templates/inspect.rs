@@ -0,0 +1,326 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+
+use handlebars::template::{Parameter, Template, TemplateElement};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::metadata::frontmatter::{
+    CustomWorkType, ScratchFixMetadata, ScratchRefactorMetadata, ScratchRevisionMetadata,
+    ScratchWorkType, ScratchWorkloadExtras, split_front_matter,
+};
+use crate::templates::catalog::ResolvedTemplate;
+use crate::templates::engine::{
+    CreationContext, MarkdownTemplateEngine, TemplateEngine, TemplateLocator, TemplateProvenance,
+    TemplateScenario, TokenMap,
+};
+
+/// Block helpers that render their body against a nested context.
+const SCOPING_HELPERS: [&str; 2] = ["each", "with"];
+
+/// A context variable a template reads, found by walking its Handlebars AST.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TemplateToken {
+    /// Top-level context key; `{{work_type_object.feat}}` reads `work_type_object`.
+    pub name: String,
+    /// 1-based lines of the expressions that read the token.
+    pub lines: Vec<usize>,
+    /// SpecMan passes the token when it creates an artifact from the template's scenario.
+    pub provided: bool,
+}
+
+/// Outcome of rendering one resolved template with the sample context for its scenario.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TemplateValidation {
+    /// Scenario label, as accepted by [`TemplateScenario::from_label`].
+    pub template: String,
+    pub provenance: TemplateProvenance,
+    pub tokens: Vec<TemplateToken>,
+    pub errors: Vec<String>,
+}
+
+impl TemplateValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Reads the body of a resolved template from disk. The catalog resolves remote pointers to a
+/// file in the template cache, so only descriptors built by hand carry a URL, which has no
+/// body to read.
+pub fn template_source(resolved: &ResolvedTemplate) -> Result<String, SpecmanError> {
+    match &resolved.descriptor.locator {
+        TemplateLocator::FilePath(path) => Ok(fs::read_to_string(path)?),
+        TemplateLocator::Url(url) => Err(SpecmanError::Template(format!(
+            "remote template {url} has no local copy"
+        ))),
+    }
+}
+
+/// Lists the context variables `source` reads, sorted by name. Paths inside `each` and `with`
+/// blocks are relative to the block's item and only count when they climb out with `../` or
+/// start at `@root`.
+pub fn template_tokens(
+    source: &str,
+    scenario: &TemplateScenario,
+) -> Result<Vec<TemplateToken>, SpecmanError> {
+    let template = Template::compile(source)
+        .map_err(|err| SpecmanError::Template(format!("invalid template: {err}")))?;
+    let mut found = BTreeMap::new();
+    collect_template(&template, false, &mut found);
+
+    let provided = sample_tokens(scenario)?;
+    Ok(found
+        .into_iter()
+        .map(|(name, mut lines)| {
+            lines.dedup();
+            TemplateToken {
+                provided: provided.contains_key(&name),
+                name,
+                lines,
+            }
+        })
+        .collect())
+}
+
+/// The tokens SpecMan passes when creating an artifact from `scenario`, with sample values.
+/// The title carries YAML-significant characters so unquoted front matter fields fail
+/// validation.
+pub fn sample_tokens(scenario: &TemplateScenario) -> Result<TokenMap, SpecmanError> {
+    let work_type = sample_work_type(scenario.label());
+    let context = match scenario {
+        TemplateScenario::Specification => CreationContext::Specification {
+            title: "Sample: \"Quoted\" #Title",
+        },
+        TemplateScenario::Implementation => CreationContext::Implementation {
+            target: "spec/sample/spec.md",
+        },
+        TemplateScenario::ScratchPad | TemplateScenario::WorkType(_) => {
+            CreationContext::ScratchPad {
+                target: "impl/sample/impl.md",
+                work_type: &work_type,
+            }
+        }
+    };
+    context.tokens("sample-artifact")
+}
+
+/// Checks that every token the template reads is provided, that it renders with
+/// [`sample_tokens`], and that the rendered front matter parses as YAML.
+pub fn validate_template(resolved: &ResolvedTemplate) -> Result<TemplateValidation, SpecmanError> {
+    let scenario = &resolved.descriptor.scenario;
+    let source = template_source(resolved)?;
+    let mut errors = Vec::new();
+    let tokens = template_tokens(&source, scenario).unwrap_or_else(|err| {
+        errors.push(err.to_string());
+        Vec::new()
+    });
+    for token in tokens.iter().filter(|token| !token.provided) {
+        errors.push(format!(
+            "token `{}` at line {} is not provided for {} templates and renders empty",
+            token.name,
+            token.lines.first().copied().unwrap_or_default(),
+            scenario.label()
+        ));
+    }
+
+    if errors.is_empty() {
+        let engine = MarkdownTemplateEngine::new();
+        match sample_tokens(scenario)
+            .and_then(|tokens| engine.render(&resolved.descriptor, &tokens))
+        {
+            Ok(rendered) => errors.extend(check_front_matter(&rendered.body)),
+            Err(err) => errors.push(format!("render failed: {err}")),
+        }
+    }
+
+    Ok(TemplateValidation {
+        template: scenario.label().to_string(),
+        provenance: resolved.provenance.clone(),
+        tokens,
+        errors,
+    })
+}
+
+fn check_front_matter(body: &str) -> Option<String> {
+    let split = match split_front_matter(body) {
+        Ok(split) => split,
+        Err(err) => return Some(format!("rendered template has no front matter: {err}")),
+    };
+    match serde_yaml::from_str::<serde_yaml::Value>(split.yaml) {
+        Ok(serde_yaml::Value::Mapping(_)) => None,
+        Ok(_) => Some("rendered front matter is not a YAML mapping".to_string()),
+        Err(err) => Some(format!("rendered front matter is not valid YAML: {err}")),
+    }
+}
+
+fn sample_work_type(name: &str) -> ScratchWorkType {
+    match name {
+        "feat" => ScratchWorkType::Feat(ScratchWorkloadExtras::default()),
+        "ref" => ScratchWorkType::Refactor(ScratchRefactorMetadata::default()),
+        "fix" => ScratchWorkType::Fix(ScratchFixMetadata::default()),
+        "revision" => ScratchWorkType::Revision(ScratchRevisionMetadata::default()),
+        other => ScratchWorkType::Custom(CustomWorkType {
+            name: other.to_string(),
+            ..CustomWorkType::default()
+        }),
+    }
+}
+
+fn collect_template(template: &Template, scoped: bool, found: &mut BTreeMap<String, Vec<usize>>) {
+    for (idx, element) in template.elements.iter().enumerate() {
+        let line = template.mapping.get(idx).map_or(0, |mapping| mapping.0);
+        match element {
+            TemplateElement::Expression(helper) | TemplateElement::HtmlExpression(helper) => {
+                if helper.params.is_empty() && helper.hash.is_empty() {
+                    collect_parameter(&helper.name, line, scoped, found);
+                } else {
+                    collect_arguments(&helper.params, &helper.hash, line, scoped, found);
+                }
+            }
+            TemplateElement::HelperBlock(helper) => {
+                collect_arguments(&helper.params, &helper.hash, line, scoped, found);
+                let nested = scoped
+                    || helper
+                        .name
+                        .as_name()
+                        .is_some_and(|name| SCOPING_HELPERS.contains(&name));
+                for body in [&helper.template, &helper.inverse].into_iter().flatten() {
+                    collect_template(body, nested, found);
+                }
+            }
+            TemplateElement::DecoratorExpression(decorator)
+            | TemplateElement::DecoratorBlock(decorator)
+            | TemplateElement::PartialExpression(decorator)
+            | TemplateElement::PartialBlock(decorator) => {
+                collect_arguments(&decorator.params, &decorator.hash, line, scoped, found);
+                if let Some(body) = &decorator.template {
+                    collect_template(body, scoped, found);
+                }
+            }
+            TemplateElement::RawString(_) | TemplateElement::Comment(_) => {}
+        }
+    }
+}
+
+fn collect_arguments(
+    params: &[Parameter],
+    hash: &HashMap<String, Parameter>,
+    line: usize,
+    scoped: bool,
+    found: &mut BTreeMap<String, Vec<usize>>,
+) {
+    for param in params.iter().chain(hash.values()) {
+        collect_parameter(param, line, scoped, found);
+    }
+}
+
+fn collect_parameter(
+    param: &Parameter,
+    line: usize,
+    scoped: bool,
+    found: &mut BTreeMap<String, Vec<usize>>,
+) {
+    match param {
+        Parameter::Subexpression(sub) => {
+            let params = sub.params().map_or(&[][..], Vec::as_slice);
+            let empty = HashMap::new();
+            collect_arguments(params, sub.hash().unwrap_or(&empty), line, scoped, found);
+        }
+        Parameter::Literal(_) => {}
+        Parameter::Name(_) | Parameter::Path(_) => {
+            if let Some(key) = param.as_name().and_then(|raw| context_key(raw, scoped)) {
+                found.entry(key).or_default().push(line);
+            }
+        }
+    }
+}
+
+/// The root context key a path reads, if it reads the root context at all.
+fn context_key(raw: &str, scoped: bool) -> Option<String> {
+    let path = if let Some(rest) = raw.strip_prefix("@root") {
+        rest.trim_start_matches(['.', '/'])
+    } else if raw.starts_with('@') {
+        // `@index`, `@key`, and other block locals.
+        return None;
+    } else if scoped {
+        raw.strip_prefix("../")?.trim_start_matches("../")
+    } else {
+        ["this.", "this/", "./"]
+            .iter()
+            .find_map(|prefix| raw.strip_prefix(prefix))
+            .unwrap_or(raw)
+    };
+    let key = path.split(['.', '/', '[']).next().unwrap_or_default();
+    (!key.is_empty() && key != "this").then(|| key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::templates::engine::TemplateDescriptor;
+
+    #[test]
+    fn walks_blocks_helpers_and_scopes() {
+        let source = "---\nname: {{output_name}}\n---\n{{#if (eq work_type \"feat\")}}\n{{#each @root.items}}- {{label}} of {{../title}} ({{@index}})\n{{/each}}\n{{else}}\n{{this.work_type_object.feat}}\n{{/if}}\n{{{raw_html}}}\n";
+        let tokens = template_tokens(source, &TemplateScenario::WorkType("feat".into()))
+            .expect("template compiles");
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| (token.name.as_str(), token.lines.clone(), token.provided))
+                .collect::<Vec<_>>(),
+            vec![
+                ("items", vec![5], false),
+                ("output_name", vec![2], true),
+                ("raw_html", vec![10], false),
+                ("title", vec![5], false),
+                ("work_type", vec![4], true),
+                ("work_type_object", vec![8], true),
+            ]
+        );
+    }
+
+    #[test]
+    fn validation_reports_missing_tokens_and_broken_front_matter() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let resolved = |body: &str| {
+            let path = temp.path().join("spec.md");
+            fs::write(&path, body).expect("write template");
+            ResolvedTemplate {
+                descriptor: TemplateDescriptor {
+                    locator: TemplateLocator::FilePath(PathBuf::from(&path)),
+                    scenario: TemplateScenario::Specification,
+                    ..TemplateDescriptor::default()
+                },
+                provenance: TemplateProvenance::default(),
+            }
+        };
+
+        let report = validate_template(&resolved("---\nname: {{output_name}}\n---\n# {{title}}\n"))
+            .expect("validates");
+        assert!(report.is_valid(), "{:?}", report.errors);
+
+        let report = validate_template(&resolved("---\nname: {{name}}\n---\n")).expect("validates");
+        assert_eq!(
+            report.errors,
+            vec![
+                "token `name` at line 2 is not provided for spec templates and renders empty"
+                    .to_string()
+            ]
+        );
+
+        let report =
+            validate_template(&resolved("---\ntitle: {{title}}\n---\n")).expect("validates");
+        assert_eq!(report.errors.len(), 1);
+        assert!(
+            report.errors[0].starts_with("rendered front matter is not valid YAML"),
+            "{:?}",
+            report.errors
+        );
+    }
+}
@@ -1,6 +1,12 @@
-use crate::graph::tree::{ArtifactId, DependencyTree};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::graph::tree::{ArtifactId, ArtifactKind, DependencyTree};
 use crate::core::env::SpecmanEnv;
 use crate::core::error::SpecmanError;
+use crate::index::{ChangeKind, SpecDiff};
+use crate::validation::{ValidationTag, validate_compliance};
+use crate::workspace::{WorkspacePaths, workspace_relative_path};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -20,3 +26,126 @@ pub fn check_deletion_impact(
         blocked,
     })
 }
+
+/// Implementations affected by a specification change, with the `[ENSURES]` tags that cover
+/// each changed constraint group.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SpecImpact {
+    pub specification: ArtifactId,
+    pub diff: SpecDiff,
+    pub implementations: Vec<ImplementationImpact>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ImplementationImpact {
+    pub implementation: ArtifactId,
+    /// Specifications between the implementation and the changed specification, nearest to
+    /// the implementation first; empty when the implementation depends on it directly.
+    pub via: Vec<ArtifactId>,
+    /// Maps changed constraint group IDs to the tags covering them. Tag paths are
+    /// workspace-relative. Tags for removed groups are the implementation's orphans.
+    pub tags: BTreeMap<String, Vec<ValidationTag>>,
+    /// Why tags could not be collected, e.g. the implementation has no `location`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ImplementationImpact {
+    pub fn is_direct(&self) -> bool {
+        self.via.is_empty()
+    }
+}
+
+/// Follows downstream edges from `spec` through dependent specifications and reports every
+/// implementation reached, scanning each for tags on the groups changed in `diff`.
+pub fn analyze_spec_impact(
+    env: &SpecmanEnv,
+    workspace: &WorkspacePaths,
+    spec: &ArtifactId,
+    diff: SpecDiff,
+) -> Result<SpecImpact, SpecmanError> {
+    let mut via: BTreeMap<ArtifactId, Vec<ArtifactId>> = BTreeMap::new();
+    let mut implementations: BTreeSet<ArtifactId> = BTreeSet::new();
+    let mut queue = VecDeque::from([spec.clone()]);
+    via.insert(spec.clone(), Vec::new());
+
+    while let Some(current) = queue.pop_front() {
+        let path = via.get(&current).cloned().unwrap_or_default();
+        for edge in env.mapping.downstream(&current)? {
+            let dependent = edge.from.id;
+            if via.contains_key(&dependent) {
+                continue;
+            }
+            let mut chain = Vec::with_capacity(path.len() + 1);
+            if current != *spec {
+                chain.push(current.clone());
+            }
+            chain.extend(path.iter().cloned());
+            match dependent.kind {
+                ArtifactKind::Implementation => {
+                    implementations.insert(dependent.clone());
+                }
+                ArtifactKind::Specification => queue.push_back(dependent.clone()),
+                ArtifactKind::ScratchPad => continue,
+            }
+            via.insert(dependent, chain);
+        }
+    }
+
+    let changed: Vec<(&str, ChangeKind)> = diff
+        .constraints
+        .iter()
+        .map(|change| (change.group.as_str(), change.change))
+        .collect();
+
+    let implementations = implementations
+        .into_iter()
+        .map(|implementation| {
+            let mut impact = ImplementationImpact {
+                via: via.remove(&implementation).unwrap_or_default(),
+                implementation,
+                tags: BTreeMap::new(),
+                error: None,
+            };
+            match validate_compliance(workspace.root(), &impact.implementation) {
+                Ok(report) => {
+                    for (group, change) in &changed {
+                        let tags: Vec<ValidationTag> = match change {
+                            ChangeKind::Removed => report
+                                .orphans
+                                .iter()
+                                .filter(|tag| tag.identifier == *group)
+                                .cloned()
+                                .collect(),
+                            _ => report.coverage.get(*group).cloned().unwrap_or_default(),
+                        };
+                        if tags.is_empty() {
+                            continue;
+                        }
+                        let tags = tags
+                            .into_iter()
+                            .map(|mut tag| {
+                                let absolute = report.scan_root.join(&tag.location.file_path);
+                                if let Some(relative) =
+                                    workspace_relative_path(workspace.root(), &absolute)
+                                {
+                                    tag.location.file_path = relative.into();
+                                }
+                                tag
+                            })
+                            .collect();
+                        impact.tags.insert(group.to_string(), tags);
+                    }
+                }
+                Err(err) => impact.error = Some(err.to_string()),
+            }
+            impact
+        })
+        .collect();
+
+    Ok(SpecImpact {
+        specification: spec.clone(),
+        diff,
+        implementations,
+    })
+}
@@ -0,0 +1,467 @@
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::{FilesystemDependencyMapper, diff_paths};
+use crate::index::{FilesystemStructureIndexer, WorkspaceIndex};
+use crate::validation::references::{
+    IssueSeverity, ReachabilityPolicy, ReferenceIssueKind, ReferenceValidationIssue,
+    ReferenceValidator, ValidationMode,
+};
+use crate::workspace::{
+    FilesystemWorkspaceLocator, WorkspacePaths, normalize_workspace_path, workspace_relative_path,
+};
+
+/// Replacement destination inferred for a broken link.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceSuggestion {
+    /// Destination to write in place of the broken one.
+    pub destination: String,
+    pub reason: SuggestionReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SuggestionReason {
+    /// The heading slug in the target document closest to the broken fragment.
+    ClosestHeading { slug: String, distance: usize },
+    /// Git records the missing file as renamed; `to` is workspace-relative.
+    GitRename { from: String, to: String },
+}
+
+impl fmt::Display for SuggestionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuggestionReason::ClosestHeading { slug, distance } => {
+                write!(f, "closest heading `{slug}`, edit distance {distance}")
+            }
+            SuggestionReason::GitRename { from, to } => {
+                write!(f, "`{from}` was renamed to `{to}`")
+            }
+        }
+    }
+}
+
+/// Infers replacement destinations for broken fragments and missing files. Validation leaves
+/// [`ReferenceValidationIssue::suggestion`] empty; callers that want suggestions share one
+/// `SuggestionSources` across every report they fill in. The structure index and git history
+/// are read on first use, so they cost nothing while no fixable issue is present.
+pub struct SuggestionSources<'a> {
+    workspace: &'a WorkspacePaths,
+    root: PathBuf,
+    index: OnceCell<Option<WorkspaceIndex>>,
+    renames: OnceCell<BTreeMap<String, String>>,
+}
+
+impl<'a> SuggestionSources<'a> {
+    pub fn new(workspace: &'a WorkspacePaths) -> Self {
+        Self {
+            workspace,
+            // Validated documents are reported by canonical path.
+            root: fs::canonicalize(workspace.root())
+                .unwrap_or_else(|_| workspace.root().to_path_buf()),
+            index: OnceCell::new(),
+            renames: OnceCell::new(),
+        }
+    }
+
+    /// Fills in the suggestion of every broken fragment and missing file in `issues`.
+    pub fn attach<'i>(&self, issues: impl IntoIterator<Item = &'i mut ReferenceValidationIssue>) {
+        for issue in issues {
+            issue.suggestion = self.suggest(issue);
+        }
+    }
+
+    pub fn suggest(&self, issue: &ReferenceValidationIssue) -> Option<ReferenceSuggestion> {
+        if issue.severity != IssueSeverity::Error {
+            return None;
+        }
+        match issue.kind {
+            ReferenceIssueKind::InvalidFragment => self.closest_heading(issue),
+            ReferenceIssueKind::FileMissing => self.renamed_file(issue),
+            _ => None,
+        }
+    }
+
+    fn index(&self) -> Option<&WorkspaceIndex> {
+        self.index
+            .get_or_init(|| {
+                FilesystemStructureIndexer::new(FilesystemWorkspaceLocator::new(
+                    self.workspace.root().to_path_buf(),
+                ))
+                .build_cached_with_workspace(self.workspace)
+                .ok()
+            })
+            .as_ref()
+    }
+
+    fn renames(&self) -> &BTreeMap<String, String> {
+        self.renames.get_or_init(|| git_renames(&self.root))
+    }
+
+    fn closest_heading(&self, issue: &ReferenceValidationIssue) -> Option<ReferenceSuggestion> {
+        let destination = issue.destination.as_deref()?;
+        let (path_part, fragment) = destination.split_once('#')?;
+        let fragment = fragment.split('?').next().unwrap_or(fragment);
+        if fragment.is_empty() {
+            return None;
+        }
+
+        let source = Path::new(&issue.source.document);
+        let target = if path_part.is_empty() {
+            source.to_path_buf()
+        } else {
+            source.parent()?.join(path_part.split('?').next()?)
+        };
+        let target = workspace_relative_path(&self.root, &normalize_workspace_path(&target))?;
+
+        let (slug, distance) = self
+            .index()?
+            .headings
+            .keys()
+            .filter(|id| id.artifact.workspace_path == target)
+            .map(|id| (id.slug.as_str(), edit_distance(fragment, &id.slug)))
+            .min_by_key(|(slug, distance)| (*distance, *slug))?;
+        // Past a third of the longer slug the "closest" heading is usually unrelated.
+        let longest = fragment.chars().count().max(slug.chars().count());
+        if distance == 0 || distance * 3 > longest {
+            return None;
+        }
+
+        Some(ReferenceSuggestion {
+            destination: format!("{path_part}#{slug}"),
+            reason: SuggestionReason::ClosestHeading {
+                slug: slug.to_string(),
+                distance,
+            },
+        })
+    }
+
+    fn renamed_file(&self, issue: &ReferenceValidationIssue) -> Option<ReferenceSuggestion> {
+        let destination = issue.destination.as_deref()?;
+        let (path_part, fragment) = match destination.split_once('#') {
+            Some((path_part, fragment)) => (path_part, Some(fragment)),
+            None => (destination, None),
+        };
+        let path_part = path_part.split('?').next()?;
+        let source_dir = Path::new(&issue.source.document).parent()?;
+        let missing = normalize_workspace_path(&source_dir.join(path_part));
+        let from = workspace_relative_path(&self.root, &missing)?;
+
+        // Follow chains of renames until one lands on a file that still exists.
+        let renames = self.renames();
+        let mut to = renames.get(&from)?;
+        for _ in 0..renames.len() {
+            if self.root.join(to).is_file() {
+                let relative = diff_paths(&self.root.join(to), source_dir)?;
+                let mut replacement = relative.to_string_lossy().replace('\\', "/");
+                if let Some(fragment) = fragment {
+                    replacement.push('#');
+                    replacement.push_str(fragment);
+                }
+                return Some(ReferenceSuggestion {
+                    destination: replacement,
+                    reason: SuggestionReason::GitRename {
+                        from,
+                        to: to.clone(),
+                    },
+                });
+            }
+            to = renames.get(to)?;
+        }
+        None
+    }
+}
+
+/// Renames recorded by git under `root`, keyed by old root-relative path. Staged renames take
+/// precedence over committed ones, and newer commits over older ones. Outside a git checkout
+/// the map is empty.
+fn git_renames(root: &Path) -> BTreeMap<String, String> {
+    let mut renames = BTreeMap::new();
+    let commands: [&[&str]; 2] = [
+        &[
+            "diff",
+            "--cached",
+            "--relative",
+            "-M",
+            "--diff-filter=R",
+            "--name-status",
+        ],
+        &[
+            "log",
+            "--relative",
+            "-M",
+            "--diff-filter=R",
+            "--name-status",
+            "--format=",
+        ],
+    ];
+    for args in commands {
+        let Ok(output) = Command::new("git").args(args).current_dir(root).output() else {
+            return renames;
+        };
+        if !output.status.success() {
+            continue;
+        }
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let mut fields = line.split('\t');
+            if let (Some(status), Some(from), Some(to)) =
+                (fields.next(), fields.next(), fields.next())
+                && status.starts_with('R')
+            {
+                renames
+                    .entry(from.to_string())
+                    .or_insert_with(|| to.to_string());
+            }
+        }
+    }
+    renames
+}
+
+/// Levenshtein distance over characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceFixOptions {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// A link destination that was (or, in a dry run, would be) rewritten.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceFix {
+    /// Workspace-relative path of the document holding the link.
+    pub document: String,
+    /// Line of the rewritten destination; for reference-style links, the definition line.
+    pub line: usize,
+    pub before: String,
+    pub after: String,
+    pub reason: SuggestionReason,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceFixReport {
+    pub dry_run: bool,
+    pub fixes: Vec<ReferenceFix>,
+    /// Broken fragments and missing files left in place: no suggestion could be inferred, or
+    /// the destination could not be located in the document.
+    pub unresolved: Vec<ReferenceValidationIssue>,
+}
+
+/// Validates every artifact without network access and rewrites broken heading fragments and
+/// links to renamed files with their suggested destinations. Only the destination text changes;
+/// the rest of each document is written back byte for byte. Documents in peer workspaces are
+/// never modified.
+pub fn fix_references(
+    workspace: &WorkspacePaths,
+    options: &ReferenceFixOptions,
+) -> Result<ReferenceFixReport, SpecmanError> {
+    let locator = Arc::new(FilesystemWorkspaceLocator::new(
+        workspace.root().to_path_buf(),
+    ));
+    let inventory = FilesystemDependencyMapper::new(locator)
+        .dependency_graph()
+        .inventory_snapshot()?;
+    let validator = ReferenceValidator::with_mode(
+        workspace,
+        ValidationMode {
+            reachability: ReachabilityPolicy::Disabled,
+            ..ValidationMode::default()
+        },
+    );
+
+    // Transitive validation reports links in shared documents once per artifact reaching them.
+    let suggestions = SuggestionSources::new(workspace);
+    let mut seen = BTreeSet::new();
+    let mut broken = Vec::new();
+    for entry in inventory.entries.iter() {
+        let Some(path) = &entry.summary.resolved_path else {
+            continue;
+        };
+        for mut issue in validator.validate(path)?.issues {
+            let fixable = matches!(
+                issue.kind,
+                ReferenceIssueKind::InvalidFragment | ReferenceIssueKind::FileMissing
+            ) && issue.severity == IssueSeverity::Error;
+            if fixable && seen.insert((issue.source.clone(), issue.destination.clone())) {
+                issue.suggestion = suggestions.suggest(&issue);
+                broken.push(issue);
+            }
+        }
+    }
+
+    let root =
+        fs::canonicalize(workspace.root()).unwrap_or_else(|_| workspace.root().to_path_buf());
+    let mut by_document: BTreeMap<String, Vec<ReferenceValidationIssue>> = BTreeMap::new();
+    let mut unresolved = Vec::new();
+    for issue in broken {
+        match workspace_relative_path(&root, Path::new(&issue.source.document)) {
+            Some(_) if issue.suggestion.is_some() => by_document
+                .entry(issue.source.document.clone())
+                .or_default()
+                .push(issue),
+            _ => unresolved.push(issue),
+        }
+    }
+
+    let mut fixes = Vec::new();
+    for (document, issues) in by_document {
+        let path = PathBuf::from(&document);
+        let mut content = fs::read_to_string(&path)?;
+        let relative = workspace_relative_path(&root, &path).unwrap_or(document);
+
+        let mut edits: BTreeMap<usize, (usize, String)> = BTreeMap::new();
+        for issue in issues {
+            let (Some(before), Some(suggestion)) = (&issue.destination, &issue.suggestion) else {
+                continue;
+            };
+            let line = issue
+                .source
+                .range
+                .as_ref()
+                .map_or(1, |range| range.start.line);
+            let Some(start) = locate_destination(&content, line, before) else {
+                unresolved.push(issue);
+                continue;
+            };
+            if edits.contains_key(&start) {
+                continue;
+            }
+            edits.insert(start, (before.len(), suggestion.destination.clone()));
+            fixes.push(ReferenceFix {
+                document: relative.clone(),
+                line: content[..start].matches('\n').count() + 1,
+                before: before.clone(),
+                after: suggestion.destination.clone(),
+                reason: suggestion.reason.clone(),
+            });
+        }
+
+        if !options.dry_run && !edits.is_empty() {
+            for (start, (len, after)) in edits.into_iter().rev() {
+                content.replace_range(start..start + len, &after);
+            }
+            fs::write(&path, content)?;
+        }
+    }
+
+    fixes.sort_by(|a, b| (&a.document, a.line).cmp(&(&b.document, b.line)));
+    Ok(ReferenceFixReport {
+        dry_run: options.dry_run,
+        fixes,
+        unresolved,
+    })
+}
+
+/// Byte offset of `destination` in an inline link starting on `line`, or else in a
+/// reference definition anywhere in the document.
+pub(crate) fn locate_destination(content: &str, line: usize, destination: &str) -> Option<usize> {
+    let line_start = content
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>();
+    let ends_destination = |end: usize| {
+        content[end..]
+            .chars()
+            .next()
+            .is_none_or(|c| matches!(c, ')' | '>' | ' ' | '\t' | '\n' | '\r'))
+    };
+
+    for opener in ["](", "](<"] {
+        let needle = format!("{opener}{destination}");
+        if let Some(found) = content[line_start..].find(&needle) {
+            let start = line_start + found + opener.len();
+            if ends_destination(start + destination.len()) {
+                return Some(start);
+            }
+        }
+    }
+
+    let mut offset = 0;
+    for text in content.split_inclusive('\n') {
+        if text.trim_start().starts_with('[')
+            && let Some(colon) = text.find("]:")
+        {
+            let rest = &text[colon + 2..];
+            let value = rest.trim_start().trim_start_matches('<');
+            if value.starts_with(destination) {
+                let start = offset + colon + 2 + (rest.len() - value.len());
+                if ends_destination(start + destination.len()) {
+                    return Some(start);
+                }
+            }
+        }
+        offset += text.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("concept-roots", "concept-roots"), 0);
+        assert_eq!(edit_distance("concept-rots", "concept-roots"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn fixes_fragments_in_place_and_leaves_other_bytes_untouched() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        let original = "---\nname: core\n---\n# Core\r\n\n## Concept: Roots\n\nSee [roots](#concept-rots) and [walk][w].\n\n## Concept: Walk\n\nUnrelated [link](#nothing-like-it).\n\n[w]: #concept-wakl\n";
+        let spec = root.join("spec/core/spec.md");
+        fs::write(&spec, original).unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+
+        let report = fix_references(&workspace, &ReferenceFixOptions { dry_run: true }).unwrap();
+        assert_eq!(report.fixes.len(), 2, "{:?}", report.fixes);
+        assert_eq!(fs::read_to_string(&spec).unwrap(), original);
+        assert_eq!(report.unresolved.len(), 1);
+        assert_eq!(
+            report.unresolved[0].destination.as_deref(),
+            Some("#nothing-like-it")
+        );
+
+        let report = fix_references(&workspace, &ReferenceFixOptions::default()).unwrap();
+        let fixes: Vec<(usize, &str)> = report
+            .fixes
+            .iter()
+            .map(|fix| (fix.line, fix.after.as_str()))
+            .collect();
+        assert_eq!(fixes, vec![(8, "#concept-roots"), (14, "#concept-walk")]);
+        assert_eq!(
+            fs::read_to_string(&spec).unwrap(),
+            original
+                .replace("#concept-rots", "#concept-roots")
+                .replace("#concept-wakl", "#concept-walk")
+        );
+    }
+}
@@ -0,0 +1,433 @@
+//! JUnit XML export for compliance coverage so CI systems can render each constraint group
+//! as a test case.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::core::xml::escape;
+use crate::graph::tree::ArtifactKind;
+use crate::metadata::waiver::Waiver;
+use crate::validation::status::WorkspaceStatusReport;
+use crate::validation::{ComplianceReport, ValidationTag, ValidationType};
+
+/// A `<testsuites>` document with one suite per implementation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JUnitReport {
+    pub name: String,
+    pub suites: Vec<JUnitSuite>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JUnitSuite {
+    pub name: String,
+    pub cases: Vec<JUnitCase>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JUnitCase {
+    pub classname: String,
+    pub name: String,
+    pub outcome: JUnitOutcome,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JUnitOutcome {
+    /// The constraint group is covered by at least one TEST or CHECK tag.
+    Passed,
+    /// The constraint group has no covering tag.
+    Failed { message: String },
+    /// An orphaned tag, or compliance could not be evaluated at all.
+    Errored { message: String },
+    /// The constraint group is only covered by MANUAL tags, or is waived.
+    Skipped { message: String },
+}
+
+impl JUnitReport {
+    /// Builds a report with a single suite for one implementation.
+    pub fn from_compliance_report(report: &ComplianceReport) -> Self {
+        let mut suite = JUnitSuite::new(&report.implementation.name);
+        suite.push_coverage(&report.coverage, &report.missing, &report.scan_root);
+        suite.push_waived(&report.waived);
+        suite.push_orphans(&report.orphans, &report.scan_root);
+        Self::new(vec![suite])
+    }
+
+    /// Builds one suite per implementation from a workspace status run.
+    ///
+    /// Implementations whose compliance check could not run report a single errored case.
+    pub fn from_status_report(report: &WorkspaceStatusReport) -> Self {
+        let suites = report
+            .artifacts
+            .iter()
+            .filter(|(id, _)| id.kind == ArtifactKind::Implementation)
+            .map(|(id, status)| {
+                let mut suite = JUnitSuite::new(&id.name);
+                match status.compliance_scan_root.as_deref() {
+                    Some(scan_root) => {
+                        let scan_root = Path::new(scan_root);
+                        suite.push_coverage(
+                            &status.compliance_coverage,
+                            &status.compliance_missing,
+                            scan_root,
+                        );
+                        suite.push_waived(&status.compliance_waived);
+                        suite.push_orphans(&status.compliance_orphans, scan_root);
+                    }
+                    None => {
+                        for message in &status.compliance_missing {
+                            suite.cases.push(JUnitCase {
+                                classname: id.name.clone(),
+                                name: "compliance".to_string(),
+                                outcome: JUnitOutcome::Errored {
+                                    message: message.clone(),
+                                },
+                                file: None,
+                                line: None,
+                            });
+                        }
+                    }
+                }
+                suite
+            })
+            .collect();
+        Self::new(suites)
+    }
+
+    fn new(suites: Vec<JUnitSuite>) -> Self {
+        Self {
+            name: "specman compliance".to_string(),
+            suites,
+        }
+    }
+
+    pub fn tests(&self) -> usize {
+        self.suites.iter().map(|suite| suite.cases.len()).sum()
+    }
+
+    pub fn failures(&self) -> usize {
+        self.suites.iter().map(JUnitSuite::failures).sum()
+    }
+
+    pub fn errors(&self) -> usize {
+        self.suites.iter().map(JUnitSuite::errors).sum()
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.suites.iter().map(JUnitSuite::skipped).sum()
+    }
+
+    /// Renders the report as a JUnit XML document.
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            escape(&self.name),
+            self.tests(),
+            self.failures(),
+            self.errors(),
+            self.skipped()
+        );
+        for suite in &self.suites {
+            suite.write_xml(&mut xml);
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+impl JUnitSuite {
+    fn new(implementation: &str) -> Self {
+        Self {
+            name: implementation.to_string(),
+            cases: Vec::new(),
+        }
+    }
+
+    pub fn failures(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JUnitOutcome::Failed { .. }))
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JUnitOutcome::Errored { .. }))
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, JUnitOutcome::Skipped { .. }))
+    }
+
+    fn count(&self, predicate: impl Fn(&JUnitOutcome) -> bool) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| predicate(&case.outcome))
+            .count()
+    }
+
+    /// Adds one case per constraint group, ordered by group identifier.
+    fn push_coverage(
+        &mut self,
+        coverage: &BTreeMap<String, Vec<ValidationTag>>,
+        missing: &[String],
+        scan_root: &Path,
+    ) {
+        let mut groups: BTreeMap<&str, Option<&Vec<ValidationTag>>> = coverage
+            .iter()
+            .map(|(group, tags)| (group.as_str(), Some(tags)))
+            .collect();
+        for group in missing {
+            groups.entry(group.as_str()).or_insert(None);
+        }
+
+        for (group, tags) in groups {
+            let (outcome, first_tag) = match tags {
+                None => (
+                    JUnitOutcome::Failed {
+                        message: format!("no ENSURES tag covers constraint group `{group}`"),
+                    },
+                    None,
+                ),
+                Some(tags) => match tags
+                    .iter()
+                    .find(|tag| tag.tag_type != ValidationType::Manual)
+                {
+                    Some(tag) => (JUnitOutcome::Passed, Some(tag)),
+                    None => (
+                        JUnitOutcome::Skipped {
+                            message: "covered only by MANUAL tags".to_string(),
+                        },
+                        tags.first(),
+                    ),
+                },
+            };
+            self.cases.push(JUnitCase {
+                classname: self.name.clone(),
+                name: group.to_string(),
+                outcome,
+                file: first_tag.map(|tag| tag_file(scan_root, tag)),
+                line: first_tag.map(|tag| tag.location.line_number),
+            });
+        }
+    }
+
+    fn push_waived(&mut self, waivers: &[Waiver]) {
+        for waiver in waivers {
+            self.cases.push(JUnitCase {
+                classname: self.name.clone(),
+                name: waiver.constraint.clone(),
+                outcome: JUnitOutcome::Skipped {
+                    message: format!("waived: {}", waiver.reason),
+                },
+                file: None,
+                line: None,
+            });
+        }
+    }
+
+    fn push_orphans(&mut self, orphans: &[ValidationTag], scan_root: &Path) {
+        for orphan in orphans {
+            self.cases.push(JUnitCase {
+                classname: self.name.clone(),
+                name: orphan.identifier.clone(),
+                outcome: JUnitOutcome::Errored {
+                    message: format!(
+                        "ENSURES tag `{}` does not match any constraint group",
+                        orphan.identifier
+                    ),
+                },
+                file: Some(tag_file(scan_root, orphan)),
+                line: Some(orphan.location.line_number),
+            });
+        }
+    }
+
+    fn write_xml(&self, xml: &mut String) {
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            escape(&self.name),
+            self.cases.len(),
+            self.failures(),
+            self.errors(),
+            self.skipped()
+        );
+        for case in &self.cases {
+            let _ = write!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape(&case.classname),
+                escape(&case.name)
+            );
+            if let Some(file) = &case.file {
+                let _ = write!(xml, " file=\"{}\"", escape(file));
+            }
+            if let Some(line) = case.line {
+                let _ = write!(xml, " line=\"{line}\"");
+            }
+            let (element, kind, message) = match &case.outcome {
+                JUnitOutcome::Passed => {
+                    xml.push_str("/>\n");
+                    continue;
+                }
+                JUnitOutcome::Failed { message } => ("failure", Some("missing"), message),
+                JUnitOutcome::Errored { message } => ("error", None, message),
+                JUnitOutcome::Skipped { message } => ("skipped", None, message),
+            };
+            xml.push_str(">\n");
+            let _ = write!(xml, "      <{element} message=\"{}\"", escape(message));
+            if let Some(kind) = kind {
+                let _ = write!(xml, " type=\"{kind}\"");
+            }
+            xml.push_str("/>\n    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+}
+
+/// Tag locations are relative to the compliance scan root.
+fn tag_file(scan_root: &Path, tag: &ValidationTag) -> String {
+    scan_root
+        .join(&tag.location.file_path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::tree::ArtifactId;
+    use crate::validation::SourceLocation;
+    use crate::validation::status::{ArtifactStatus, StatusResult};
+    use std::path::PathBuf;
+
+    fn tag(identifier: &str, tag_type: ValidationType, line: usize) -> ValidationTag {
+        ValidationTag {
+            identifier: identifier.into(),
+            tag_type,
+            location: SourceLocation {
+                file_path: PathBuf::from("lib.rs"),
+                line_number: line,
+            },
+        }
+    }
+
+    fn implementation(name: &str) -> ArtifactId {
+        ArtifactId {
+            kind: ArtifactKind::Implementation,
+            name: name.into(),
+        }
+    }
+
+    #[test]
+    fn compliance_report_maps_groups_to_outcomes() {
+        let report = ComplianceReport {
+            specification: ArtifactId {
+                kind: ArtifactKind::Specification,
+                name: "core".into(),
+            },
+            implementation: implementation("core-rs"),
+            scan_root: PathBuf::from("src"),
+            coverage: BTreeMap::from([
+                (
+                    "core.alpha".to_string(),
+                    vec![tag("core.alpha", ValidationType::Test, 4)],
+                ),
+                (
+                    "core.gamma".to_string(),
+                    vec![tag("core.gamma", ValidationType::Manual, 9)],
+                ),
+            ]),
+            missing: vec!["core.beta".into()],
+            orphans: vec![tag("core.<gone>", ValidationType::Check, 12)],
+            waived: Vec::new(),
+            expired_waivers: Vec::new(),
+            unknown_waivers: Vec::new(),
+            concepts: BTreeMap::new(),
+            policy: Default::default(),
+            metrics: Default::default(),
+        };
+
+        let junit = JUnitReport::from_compliance_report(&report);
+        let outcomes: Vec<_> = junit.suites[0]
+            .cases
+            .iter()
+            .map(|case| (case.name.as_str(), &case.outcome))
+            .collect();
+        assert!(matches!(outcomes[0], ("core.alpha", JUnitOutcome::Passed)));
+        assert!(matches!(
+            outcomes[1],
+            ("core.beta", JUnitOutcome::Failed { .. })
+        ));
+        assert!(matches!(
+            outcomes[2],
+            ("core.gamma", JUnitOutcome::Skipped { .. })
+        ));
+        assert!(matches!(
+            outcomes[3],
+            ("core.<gone>", JUnitOutcome::Errored { .. })
+        ));
+        assert_eq!(
+            (
+                junit.tests(),
+                junit.failures(),
+                junit.errors(),
+                junit.skipped()
+            ),
+            (4, 1, 1, 1)
+        );
+
+        let xml = junit.to_xml();
+        assert!(xml.contains(
+            "<testsuites name=\"specman compliance\" tests=\"4\" failures=\"1\" errors=\"1\" skipped=\"1\">"
+        ));
+        assert!(xml.contains(
+            "<testcase classname=\"core-rs\" name=\"core.alpha\" file=\"src/lib.rs\" line=\"4\"/>"
+        ));
+        assert!(xml.contains("<failure message=\"no ENSURES tag covers constraint group `core.beta`\" type=\"missing\"/>"));
+        assert!(xml.contains("name=\"core.&lt;gone&gt;\" file=\"src/lib.rs\" line=\"12\""));
+        assert!(xml.contains("<skipped message=\"covered only by MANUAL tags\"/>"));
+    }
+
+    #[test]
+    fn status_report_emits_one_suite_per_implementation() {
+        let mut covered = ArtifactStatus::new();
+        covered.compliance_scan_root = Some("/work/src".into());
+        covered.compliance_coverage = BTreeMap::from([(
+            "core.alpha".to_string(),
+            vec![tag("core.alpha", ValidationType::Check, 1)],
+        )]);
+        let mut broken = ArtifactStatus::new();
+        broken
+            .compliance_missing
+            .push("Compliance check failed: missing location".into());
+
+        let report = WorkspaceStatusReport {
+            global_status: StatusResult::Fail,
+            spec_impl_status: StatusResult::Fail,
+            scratchpad_status: StatusResult::Pass,
+            artifacts: BTreeMap::from([
+                (implementation("a-rs"), covered),
+                (implementation("b-rs"), broken),
+                (
+                    ArtifactId {
+                        kind: ArtifactKind::Specification,
+                        name: "core".into(),
+                    },
+                    ArtifactStatus::new(),
+                ),
+            ]),
+            cycle_errors: Vec::new(),
+            structure_errors: Vec::new(),
+            artifact_count: 3,
+        };
+
+        let junit = JUnitReport::from_status_report(&report);
+        assert_eq!(junit.suites.len(), 2);
+        assert_eq!(junit.suites[0].name, "a-rs");
+        assert_eq!(junit.suites[0].cases[0].outcome, JUnitOutcome::Passed);
+        assert_eq!(junit.suites[1].errors(), 1);
+        assert_eq!(junit.suites[1].cases[0].name, "compliance");
+    }
+}
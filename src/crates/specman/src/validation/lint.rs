@@ -0,0 +1,502 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
+use crate::index::{FilesystemStructureIndexer, WorkspaceIndex, build_document_index};
+use crate::metadata::frontmatter::split_front_matter;
+use crate::workspace::{FilesystemWorkspaceLocator, WorkspacePaths};
+
+/// Workspace lint configuration, relative to `.specman/`.
+pub const LINT_FILE: &str = "lint.yaml";
+
+const RFC_2119_KEYWORDS: [&str; 7] = [
+    "MUST",
+    "SHALL",
+    "SHOULD",
+    "MAY",
+    "REQUIRED",
+    "RECOMMENDED",
+    "OPTIONAL",
+];
+
+const RESOURCE_HANDLE_SCHEMES: [&str; 3] = ["spec://", "impl://", "scratch://"];
+
+/// A named check applied to every specification in the workspace.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// Constraint groups contain at least one RFC 2119 keyword.
+    Rfc2119Keywords,
+    /// Concept and entity headings use the configured prefix followed by a space and a name.
+    ConceptHeadingFormat,
+    /// The first group of a constraint identifier is the slug of a concept or entity heading.
+    ConstraintPrefix,
+    /// `spec://`, `impl://`, and `scratch://` handles stay out of document bodies.
+    NoHandlesInBody,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 4] = [
+        LintRule::Rfc2119Keywords,
+        LintRule::ConceptHeadingFormat,
+        LintRule::ConstraintPrefix,
+        LintRule::NoHandlesInBody,
+    ];
+
+    /// Name used in `.specman/lint.yaml` and in reports.
+    pub fn name(self) -> &'static str {
+        match self {
+            LintRule::Rfc2119Keywords => "rfc2119-keywords",
+            LintRule::ConceptHeadingFormat => "concept-heading-format",
+            LintRule::ConstraintPrefix => "constraint-prefix",
+            LintRule::NoHandlesInBody => "no-handles-in-body",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            LintRule::Rfc2119Keywords => "Constraint group contains no RFC 2119 keyword",
+            LintRule::ConceptHeadingFormat => {
+                "Concept or entity heading does not follow the prefix convention"
+            }
+            LintRule::ConstraintPrefix => {
+                "Constraint identifier is not prefixed by a concept or entity heading slug"
+            }
+            LintRule::NoHandlesInBody => "Resource handle used in a document body",
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Severity of a lint rule. Only `error` fails the artifact in workspace status.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Off,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LintLevel::Off => "off",
+            LintLevel::Warning => "warning",
+            LintLevel::Error => "error",
+        })
+    }
+}
+
+/// Contents of `.specman/lint.yaml`.
+///
+/// ```yaml
+/// concept_prefix: "Concept:"
+/// entity_prefix: "Entity:"
+/// rules:
+///   rfc2119-keywords: error
+///   no-handles-in-body: off
+/// ```
+///
+/// Rules that are not listed run at `warning`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    pub rules: BTreeMap<LintRule, LintLevel>,
+    pub concept_prefix: String,
+    pub entity_prefix: String,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            rules: BTreeMap::new(),
+            concept_prefix: "Concept:".to_string(),
+            entity_prefix: "Entity:".to_string(),
+        }
+    }
+}
+
+impl LintConfig {
+    pub fn level(&self, rule: LintRule) -> LintLevel {
+        self.rules.get(&rule).copied().unwrap_or(LintLevel::Warning)
+    }
+
+    fn prefixes(&self) -> [&str; 2] {
+        [self.concept_prefix.as_str(), self.entity_prefix.as_str()]
+    }
+
+    fn is_concept_or_entity(&self, title: &str) -> bool {
+        self.prefixes()
+            .iter()
+            .any(|prefix| title.starts_with(prefix))
+    }
+}
+
+/// Reads `.specman/lint.yaml`; a missing file yields the default configuration.
+pub fn load_lint_config(workspace: &WorkspacePaths) -> Result<LintConfig, SpecmanError> {
+    let path = workspace.dot_specman().join(LINT_FILE);
+    if !path.is_file() {
+        return Ok(LintConfig::default());
+    }
+    let content = fs::read_to_string(&path)?;
+    serde_yaml::from_str(&content)
+        .map_err(|err| SpecmanError::Serialization(format!("invalid {}: {err}", path.display())))
+}
+
+/// One rule violation in a specification.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct LintIssue {
+    pub rule: LintRule,
+    pub level: LintLevel,
+    /// Workspace-relative path of the specification.
+    pub document: String,
+    /// 1-based line in the document, front matter included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}", self.document)?,
+            None => f.write_str(&self.document)?,
+        }
+        write!(f, ": {}[{}] {}", self.level, self.rule, self.message)
+    }
+}
+
+/// Lint results for every specification in a workspace.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintReport {
+    /// Number of specifications linted.
+    pub documents: usize,
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    pub fn errors(&self) -> usize {
+        self.count(LintLevel::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(LintLevel::Warning)
+    }
+
+    fn count(&self, level: LintLevel) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.level == level)
+            .count()
+    }
+}
+
+/// Lints every specification in the workspace against `.specman/lint.yaml`.
+pub fn lint_workspace(workspace: &WorkspacePaths) -> Result<LintReport, SpecmanError> {
+    let config = load_lint_config(workspace)?;
+    let index = FilesystemStructureIndexer::new(FilesystemWorkspaceLocator::new(
+        workspace.root().to_path_buf(),
+    ))
+    .build_cached_with_workspace(workspace)?;
+    let documents = index
+        .artifacts
+        .keys()
+        .filter(|key| key.kind == ArtifactKind::Specification)
+        .count();
+    Ok(LintReport {
+        documents,
+        issues: lint_index(&index, &config)?,
+    })
+}
+
+/// Lints the specification stored at `path` on its own.
+pub fn lint_document(
+    workspace: &WorkspacePaths,
+    config: &LintConfig,
+    path: &Path,
+) -> Result<Vec<LintIssue>, SpecmanError> {
+    let content = fs::read_to_string(path)?;
+    let index = build_document_index(workspace, ArtifactKind::Specification, path, &content)?;
+    lint_index(&index, config)
+}
+
+/// Lints the specifications held by `index`, re-reading each file for line numbers and body
+/// text. Issues are ordered by document and line.
+pub fn lint_index(
+    index: &WorkspaceIndex,
+    config: &LintConfig,
+) -> Result<Vec<LintIssue>, SpecmanError> {
+    let mut issues = Vec::new();
+    for (key, record) in &index.artifacts {
+        if key.kind != ArtifactKind::Specification {
+            continue;
+        }
+        let content = fs::read_to_string(&record.absolute_path)?;
+        let body = BodyLines::new(&content);
+        let mut push = |rule: LintRule, line: Option<usize>, message: String| {
+            let level = config.level(rule);
+            if level != LintLevel::Off {
+                issues.push(LintIssue {
+                    rule,
+                    level,
+                    document: key.workspace_path.clone(),
+                    line,
+                    message,
+                });
+            }
+        };
+
+        for heading in index.headings.values().filter(|h| &h.id.artifact == key) {
+            if let Some(message) = check_heading_prefix(config, &heading.title) {
+                push(
+                    LintRule::ConceptHeadingFormat,
+                    body.heading_line(heading.order),
+                    message,
+                );
+            }
+        }
+
+        for constraint in index.constraints.values().filter(|c| &c.id.artifact == key) {
+            let group = &constraint.id.group;
+            let line = body.constraint_line(group);
+
+            if !contains_rfc2119_keyword(&constraint.content) {
+                push(
+                    LintRule::Rfc2119Keywords,
+                    line,
+                    format!("constraint group `{group}` contains no RFC 2119 keyword"),
+                );
+            }
+
+            let first = group.split('.').next().unwrap_or_default();
+            let prefixed_by_concept = index
+                .headings
+                .values()
+                .find(|h| &h.id.artifact == key && h.id.slug == first)
+                .is_some_and(|h| config.is_concept_or_entity(&h.title));
+            if !prefixed_by_concept {
+                push(
+                    LintRule::ConstraintPrefix,
+                    line,
+                    format!(
+                        "constraint group `{group}` does not start with the slug of a `{}` or `{}` heading",
+                        config.concept_prefix, config.entity_prefix
+                    ),
+                );
+            }
+        }
+
+        for (line, handle) in body.resource_handles() {
+            push(
+                LintRule::NoHandlesInBody,
+                Some(line),
+                format!("resource handle `{handle}` in document body; link with a relative path"),
+            );
+        }
+    }
+    issues.sort_by(|a, b| (&a.document, a.line, a.rule).cmp(&(&b.document, b.line, b.rule)));
+    Ok(issues)
+}
+
+/// Explains why `title` breaks the concept/entity prefix convention, if it does.
+fn check_heading_prefix(config: &LintConfig, title: &str) -> Option<String> {
+    for prefix in config.prefixes() {
+        if let Some(rest) = title.strip_prefix(prefix) {
+            let named = rest.strip_prefix(' ').is_some_and(|name| {
+                !name.trim().is_empty() && !name.starts_with(char::is_whitespace)
+            });
+            return (!named).then(|| {
+                format!("heading `{title}` must follow `{prefix}` with a single space and a name")
+            });
+        }
+
+        let stem = prefix.trim_end_matches(':').trim();
+        if let Some((head, _)) = title.split_once(':')
+            && head.trim().eq_ignore_ascii_case(stem)
+        {
+            return Some(format!("heading `{title}` must start with `{prefix} `"));
+        }
+    }
+    None
+}
+
+fn contains_rfc2119_keyword(content: &str) -> bool {
+    content
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|word| RFC_2119_KEYWORDS.contains(&word))
+}
+
+/// Body lines of a document paired with their line number in the file.
+pub(super) struct BodyLines<'a> {
+    pub(super) lines: Vec<(usize, &'a str, bool)>,
+    pub(super) headings: Vec<usize>,
+}
+
+impl<'a> BodyLines<'a> {
+    /// Splits off front matter and marks lines inside (or delimiting) fenced code blocks.
+    pub(super) fn new(content: &'a str) -> Self {
+        let body = split_front_matter(content)
+            .map(|split| split.body)
+            .unwrap_or(content);
+        let offset = content[..content.len() - body.len()].matches('\n').count();
+
+        let mut lines = Vec::new();
+        let mut headings = Vec::new();
+        let mut fence: Option<(char, usize)> = None;
+        for (idx, line) in body.lines().enumerate() {
+            let line_no = offset + idx + 1;
+            let trimmed = line.strip_prefix("   ").unwrap_or(line);
+            let marker = ['`', '~'].into_iter().find_map(|ch| {
+                let run = trimmed.chars().take_while(|c| *c == ch).count();
+                (run >= 3).then_some((ch, run))
+            });
+            match (fence, marker) {
+                (None, Some(opened)) => {
+                    fence = Some(opened);
+                    lines.push((line_no, line, true));
+                    continue;
+                }
+                (Some((ch, len)), Some((closing, run))) if ch == closing && run >= len => {
+                    fence = None;
+                    lines.push((line_no, line, true));
+                    continue;
+                }
+                _ => {}
+            }
+            if fence.is_none() && is_atx_heading(trimmed) {
+                headings.push(line_no);
+            }
+            lines.push((line_no, line, fence.is_some()));
+        }
+        Self { lines, headings }
+    }
+
+    /// Line of the `order`-th heading, matching [`crate::index::HeadingRecord::order`].
+    fn heading_line(&self, order: usize) -> Option<usize> {
+        self.headings.get(order).copied()
+    }
+
+    /// Line of the `!<group>:` identifier line.
+    fn constraint_line(&self, group: &str) -> Option<usize> {
+        let identifier = format!("!{group}:");
+        self.lines
+            .iter()
+            .find(|(_, line, fenced)| !fenced && line.trim() == identifier)
+            .map(|(line_no, _, _)| *line_no)
+    }
+
+    /// Resource handles outside fenced blocks and inline code spans.
+    fn resource_handles(&self) -> Vec<(usize, String)> {
+        let mut found = Vec::new();
+        for (line_no, line, fenced) in &self.lines {
+            if *fenced {
+                continue;
+            }
+            let prose: String = line.split('`').step_by(2).collect::<Vec<_>>().join(" ");
+            for scheme in RESOURCE_HANDLE_SCHEMES {
+                for (start, _) in prose.match_indices(scheme) {
+                    let handle: String = prose[start..]
+                        .chars()
+                        .take_while(|c| !c.is_whitespace() && !matches!(c, ')' | ']' | '>' | '"'))
+                        .collect();
+                    found.push((*line_no, handle));
+                }
+            }
+        }
+        found
+    }
+}
+
+fn is_atx_heading(line: &str) -> bool {
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    (1..=6).contains(&hashes) && line[hashes..].starts_with(' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn workspace_with_spec(spec: &str) -> (TempDir, WorkspacePaths) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        fs::write(root.join("spec/core/spec.md"), spec).unwrap();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        (temp, workspace)
+    }
+
+    fn rules(issues: &[LintIssue]) -> Vec<(LintRule, Option<usize>)> {
+        issues
+            .iter()
+            .map(|issue| (issue.rule, issue.line))
+            .collect()
+    }
+
+    #[test]
+    fn reports_each_rule_with_file_line_numbers() {
+        let (_temp, workspace) = workspace_with_spec(
+            "---\nname: core\n---\n# Core\n\n## concept: Roots\n\nSee spec://core for details.\n\n```text\nspec://ignored\n```\n\n## Concept: Walk\n\n!concept-walk.order:\n- Visit `spec://core` roots first.\n\n!roots.rules:\n- Roots MUST exist.\n",
+        );
+        let path = workspace.root().join("spec/core/spec.md");
+        let issues = lint_document(&workspace, &LintConfig::default(), &path).unwrap();
+
+        assert_eq!(
+            rules(&issues),
+            vec![
+                (LintRule::ConceptHeadingFormat, Some(6)),
+                (LintRule::NoHandlesInBody, Some(8)),
+                (LintRule::Rfc2119Keywords, Some(16)),
+                (LintRule::ConstraintPrefix, Some(19)),
+            ]
+        );
+        assert!(issues.iter().all(|issue| issue.level == LintLevel::Warning));
+        assert!(issues[1].message.contains("`spec://core`"), "{}", issues[1]);
+    }
+
+    #[test]
+    fn workspace_config_sets_levels_and_prefixes() {
+        let (_temp, workspace) = workspace_with_spec(
+            "---\nname: core\n---\n# Core\n\n## Konzept: Wurzeln\n\n!konzept-wurzeln.regeln:\n- Wurzeln sind Ordner.\n",
+        );
+        fs::write(
+            workspace.dot_specman().join(LINT_FILE),
+            "concept_prefix: \"Konzept:\"\nrules:\n  rfc2119-keywords: error\n  constraint-prefix: off\n",
+        )
+        .unwrap();
+
+        let report = lint_workspace(&workspace).unwrap();
+        assert_eq!(report.documents, 1);
+        assert_eq!(
+            rules(&report.issues),
+            vec![(LintRule::Rfc2119Keywords, Some(8))]
+        );
+        assert_eq!(report.errors(), 1);
+
+        fs::write(
+            workspace.dot_specman().join(LINT_FILE),
+            "rules:\n  no-such-rule: error\n",
+        )
+        .unwrap();
+        let err = lint_workspace(&workspace).unwrap_err();
+        assert!(err.to_string().contains("invalid"), "{err}");
+    }
+}
@@ -1,6 +1,14 @@
 pub mod analysis;
+pub mod autofix;
+pub mod junit;
+pub mod lint;
+pub mod plan;
 pub mod references;
+pub mod sarif;
 pub mod status;
+pub mod unfilled;
+pub mod waivers;
+pub mod watch;
 
 use std::collections::{BTreeMap, HashSet};
 use std::fs;
@@ -16,9 +24,15 @@ use serde::{Deserialize, Serialize};
 use crate::core::error::SpecmanError;
 use crate::graph::tree::{ArtifactId, ArtifactKind, DependencyMapping, FilesystemDependencyMapper};
 use crate::index::build_workspace_index_for_artifacts;
-use crate::metadata::frontmatter::{ImplementationFrontMatter, split_front_matter};
+use crate::index::{HeadingIdentifier, WorkspaceIndex};
+use crate::metadata::frontmatter::{
+    CompliancePolicy, ImplementationFrontMatter, split_front_matter,
+};
+use crate::metadata::waiver::{Waiver, WaiverDate};
+use crate::validation::waivers::load_workspace_waivers;
 use crate::workspace::{
-    FilesystemWorkspaceLocator, WorkspaceLocator, normalize_workspace_path, workspace_relative_path,
+    FilesystemWorkspaceLocator, PeerWorkspace, WorkspaceFederation, WorkspaceLocator,
+    WorkspacePaths, normalize_workspace_path, workspace_relative_path,
 };
 
 const BINARY_CHECK_BYTES: usize = 8192;
@@ -75,6 +89,171 @@ pub struct ComplianceReport {
     pub missing: Vec<String>,
     /// Tags that reference non-existent constraints.
     pub orphans: Vec<ValidationTag>,
+    /// Uncovered constraint groups excused by an active waiver; these are not in `missing`.
+    #[serde(default)]
+    pub waived: Vec<Waiver>,
+    /// Waivers past their expiry date; their groups remain in `missing`.
+    #[serde(default)]
+    pub expired_waivers: Vec<Waiver>,
+    /// Waivers naming constraint groups the specification does not declare.
+    #[serde(default)]
+    pub unknown_waivers: Vec<Waiver>,
+    /// Maps constraint group IDs to the title of the top-level concept heading declaring them.
+    #[serde(default)]
+    pub concepts: BTreeMap<String, String>,
+    /// Thresholds declared by the implementation's `compliance` front matter.
+    #[serde(default)]
+    pub policy: CompliancePolicy,
+    /// Coverage figures derived from `coverage`, `missing`, and `concepts`.
+    #[serde(default)]
+    pub metrics: ComplianceMetrics,
+}
+
+/// Computed coverage figures for a [`ComplianceReport`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ComplianceMetrics {
+    /// Every constraint group of the specification, waived ones included.
+    pub total_groups: usize,
+    pub covered_groups: usize,
+    /// Uncovered groups excused by an active waiver.
+    #[serde(default)]
+    pub waived_groups: usize,
+    /// Fraction (0.0–1.0) of constraint groups covered; 1.0 when there are no groups. Waived
+    /// groups count as uncovered.
+    pub coverage: f64,
+    /// Number of constraint groups covered by at least one tag of each type.
+    pub by_type: BTreeMap<ValidationType, usize>,
+    /// Coverage per top-level concept heading, keyed by heading title.
+    pub concepts: BTreeMap<String, ConceptCoverage>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ConceptCoverage {
+    pub total_groups: usize,
+    pub covered_groups: usize,
+    pub coverage: f64,
+}
+
+fn coverage_ratio(covered: usize, total: usize) -> f64 {
+    if total == 0 {
+        1.0
+    } else {
+        covered as f64 / total as f64
+    }
+}
+
+impl ComplianceMetrics {
+    pub fn compute(
+        coverage: &BTreeMap<String, Vec<ValidationTag>>,
+        missing: &[String],
+        waived: &[Waiver],
+        concepts: &BTreeMap<String, String>,
+    ) -> Self {
+        let mut by_type: BTreeMap<ValidationType, usize> = BTreeMap::new();
+        for tags in coverage.values() {
+            let mut types: Vec<&ValidationType> = tags.iter().map(|tag| &tag.tag_type).collect();
+            types.sort();
+            types.dedup();
+            for tag_type in types {
+                *by_type.entry(tag_type.clone()).or_default() += 1;
+            }
+        }
+
+        let mut per_concept: BTreeMap<String, ConceptCoverage> = BTreeMap::new();
+        let groups = coverage
+            .keys()
+            .map(|group| (group, true))
+            .chain(missing.iter().map(|group| (group, false)))
+            .chain(waived.iter().map(|waiver| (&waiver.constraint, false)));
+        for (group, covered) in groups {
+            let Some(concept) = concepts.get(group) else {
+                continue;
+            };
+            let entry = per_concept.entry(concept.clone()).or_default();
+            entry.total_groups += 1;
+            if covered {
+                entry.covered_groups += 1;
+            }
+        }
+        for entry in per_concept.values_mut() {
+            entry.coverage = coverage_ratio(entry.covered_groups, entry.total_groups);
+        }
+
+        let total_groups = coverage.len() + missing.len() + waived.len();
+        Self {
+            total_groups,
+            covered_groups: coverage.len(),
+            waived_groups: waived.len(),
+            coverage: coverage_ratio(coverage.len(), total_groups),
+            by_type,
+            concepts: per_concept,
+        }
+    }
+}
+
+impl ComplianceReport {
+    /// Returns why the report does not satisfy its [`CompliancePolicy`] or waivers; empty
+    /// when it does.
+    ///
+    /// Without a declared policy every constraint group must be covered or waived. Expired
+    /// waivers are always violations.
+    pub fn policy_violations(&self) -> Vec<String> {
+        let mut violations: Vec<String> = self
+            .expired_waivers
+            .iter()
+            .map(|waiver| {
+                format!(
+                    "waiver for `{}` expired on {} ({})",
+                    waiver.constraint,
+                    waiver
+                        .expires
+                        .map(|date| date.to_string())
+                        .unwrap_or_default(),
+                    waiver.reason
+                )
+            })
+            .collect();
+        violations.extend(self.coverage_violation());
+        violations
+    }
+
+    fn coverage_violation(&self) -> Option<String> {
+        let min_coverage = self.policy.min_coverage.unwrap_or(1.0);
+        if !(0.0..=1.0).contains(&min_coverage) {
+            return Some(format!(
+                "compliance.min_coverage must be between 0.0 and 1.0, found {min_coverage}"
+            ));
+        }
+
+        let (covered, label) = if self.policy.require_test {
+            (
+                self.coverage
+                    .values()
+                    .filter(|tags| tags.iter().any(|tag| tag.tag_type == ValidationType::Test))
+                    .count(),
+                "TEST-covered",
+            )
+        } else {
+            (self.metrics.covered_groups, "covered")
+        };
+        // Waived groups satisfy the policy without counting as coverage.
+        let waived = self.metrics.waived_groups;
+        let (satisfied, label) = if waived == 0 {
+            (covered, label.to_string())
+        } else {
+            (covered + waived, format!("{label} or waived"))
+        };
+        let total = self.metrics.total_groups;
+        let coverage = coverage_ratio(satisfied, total);
+        // Tolerate float rounding so e.g. 9/10 satisfies `min_coverage: 0.9`.
+        (coverage + 1e-9 < min_coverage).then(|| {
+            format!(
+                "{:.1}% of constraint groups {label} ({satisfied}/{total}), below the required {:.1}%",
+                coverage * 100.0,
+                min_coverage * 100.0
+            )
+        })
+    }
 }
 
 pub fn parse_tags(line: &str, line_idx: usize, file_path: &Path) -> Vec<ValidationTag> {
@@ -124,6 +303,7 @@ pub fn generate_report(
     scan_root: PathBuf,
     spec_constraints: &[String],
     mut tags: Vec<ValidationTag>,
+    waivers: &[Waiver],
 ) -> ComplianceReport {
     // Sort tags first to ensure stable inputs if processing order matters,
     // or sort later in outputs.
@@ -156,6 +336,30 @@ pub fn generate_report(
     // Sort missing for determinism
     missing.sort();
 
+    // Active waivers move their groups out of `missing`; expired ones leave them in place.
+    let today = WaiverDate::today();
+    let mut waived = Vec::new();
+    let mut expired_waivers = Vec::new();
+    let mut unknown_waivers = Vec::new();
+    for waiver in waivers {
+        if !known_constraints.contains(&waiver.constraint) {
+            unknown_waivers.push(waiver.clone());
+            continue;
+        }
+        if !missing.contains(&waiver.constraint) {
+            continue;
+        }
+        if waiver.is_expired(today) {
+            expired_waivers.push(waiver.clone());
+        } else {
+            missing.retain(|group| *group != waiver.constraint);
+            waived.push(waiver.clone());
+        }
+    }
+    waived.sort_by(|a, b| a.constraint.cmp(&b.constraint));
+
+    let concepts = BTreeMap::new();
+    let metrics = ComplianceMetrics::compute(&coverage, &missing, &waived, &concepts);
     ComplianceReport {
         specification: spec_id,
         implementation: impl_id,
@@ -163,6 +367,12 @@ pub fn generate_report(
         coverage,
         missing,
         orphans,
+        waived,
+        expired_waivers,
+        unknown_waivers,
+        concepts,
+        policy: CompliancePolicy::default(),
+        metrics,
     }
 }
 
@@ -181,6 +391,7 @@ pub fn validate_compliance(
         workspace_root.to_path_buf(),
     ));
     let workspace = locator.workspace()?;
+    let federation = WorkspaceFederation::load(&workspace)?;
 
     let mapper = FilesystemDependencyMapper::new(locator.clone());
     let tree = mapper.dependency_tree(impl_id)?;
@@ -216,7 +427,15 @@ pub fn validate_compliance(
                 "spec locator must not be empty".into(),
             ));
         }
-        workspace.spec_dir().join(rest).join("spec.md")
+        match rest.split_once(':') {
+            Some((peer, slug)) => federation
+                .peer(peer)?
+                .paths()
+                .spec_dir()
+                .join(slug)
+                .join("spec.md"),
+            None => workspace.spec_dir().join(rest).join("spec.md"),
+        }
     } else if spec_ref.starts_with("https://") {
         return Err(SpecmanError::Dependency(
             "compliance reporting does not support remote specifications".into(),
@@ -259,8 +478,9 @@ pub fn validate_compliance(
         }
     }
 
-    let mut spec_artifacts: Vec<(ArtifactKind, PathBuf)> = Vec::new();
-    let mut spec_workspace_paths: HashSet<String> = HashSet::new();
+    // Specs are grouped by the workspace that owns them: this one, or a federated peer whose
+    // structure index is built against its own root.
+    let mut spec_groups: Vec<SpecGroup> = Vec::new();
 
     for summary in spec_summaries {
         let mut path = if let Some(resolved) = &summary.resolved_path {
@@ -286,26 +506,52 @@ pub fn validate_compliance(
         }
 
         path = normalize_workspace_path(&path);
-        let workspace_path = workspace_relative_path(workspace.root(), &path).ok_or_else(|| {
+        let home = match workspace_relative_path(workspace.root(), &path) {
+            Some(_) => &workspace,
+            None => federation
+                .owner_of(&path)
+                .map(PeerWorkspace::paths)
+                .unwrap_or(&workspace),
+        };
+        let workspace_path = workspace_relative_path(home.root(), &path).ok_or_else(|| {
             SpecmanError::Workspace(format!(
                 "failed to resolve workspace-relative path for '{}'",
                 path.display()
             ))
         })?;
 
-        spec_workspace_paths.insert(workspace_path);
-        spec_artifacts.push((ArtifactKind::Specification, path));
+        let group = match spec_groups
+            .iter()
+            .position(|group| group.workspace.root() == home.root())
+        {
+            Some(position) => &mut spec_groups[position],
+            None => {
+                spec_groups.push(SpecGroup {
+                    workspace: home.clone(),
+                    artifacts: Vec::new(),
+                    workspace_paths: HashSet::new(),
+                });
+                spec_groups.last_mut().expect("group just pushed")
+            }
+        };
+        group.workspace_paths.insert(workspace_path);
+        group.artifacts.push((ArtifactKind::Specification, path));
     }
 
     // 2. Load spec constraints from the structure index (scoped to spec + dependencies only)
-    let index = build_workspace_index_for_artifacts(&workspace, &spec_artifacts)?;
-
     let mut spec_constraints = Vec::new();
-    for (key, _) in &index.constraints {
-        if key.artifact.kind == ArtifactKind::Specification
-            && spec_workspace_paths.contains(&key.artifact.workspace_path)
-        {
-            spec_constraints.push(key.group.clone());
+    let mut concepts = BTreeMap::new();
+    for group in &spec_groups {
+        let index = build_workspace_index_for_artifacts(&group.workspace, &group.artifacts)?;
+        for (key, record) in &index.constraints {
+            if key.artifact.kind == ArtifactKind::Specification
+                && group.workspace_paths.contains(&key.artifact.workspace_path)
+            {
+                spec_constraints.push(key.group.clone());
+                if let Some(concept) = top_level_heading_title(&index, &record.heading) {
+                    concepts.insert(key.group.clone(), concept);
+                }
+            }
         }
     }
     spec_constraints.sort();
@@ -363,14 +609,53 @@ pub fn validate_compliance(
         )));
     }
 
+    let mut waivers = front.waivers;
+    let (scoped, unscoped): (Vec<_>, Vec<_>) = load_workspace_waivers(&workspace)?
+        .into_iter()
+        .filter(|waiver| waiver.applies_to(&impl_id.name))
+        .partition(|waiver| waiver.implementation.is_some());
+    waivers.extend(scoped);
+    waivers.extend(unscoped.iter().cloned());
+
     let tags = scan_source_root(&scan_root)?;
-    Ok(generate_report(
+    let mut report = generate_report(
         spec_id,
         impl_id.clone(),
         scan_root,
         &spec_constraints,
         tags,
-    ))
+        &waivers,
+    );
+    // Unscoped workspace waivers cover every implementation, so naming another
+    // specification's groups is expected there.
+    report
+        .unknown_waivers
+        .retain(|waiver| !unscoped.contains(waiver));
+    report.metrics =
+        ComplianceMetrics::compute(&report.coverage, &report.missing, &report.waived, &concepts);
+    report.concepts = concepts;
+    report.policy = front.compliance.unwrap_or_default();
+    Ok(report)
+}
+
+/// Specifications checked by a compliance report that live in the same workspace, indexed
+/// together against that workspace's root.
+struct SpecGroup {
+    workspace: WorkspacePaths,
+    artifacts: Vec<(ArtifactKind, PathBuf)>,
+    workspace_paths: HashSet<String>,
+}
+
+/// Walks up from `heading` to the outermost heading below the document title.
+fn top_level_heading_title(index: &WorkspaceIndex, heading: &HeadingIdentifier) -> Option<String> {
+    let mut current = index.headings.get(heading)?;
+    while let Some(parent) = current.parent.as_ref().and_then(|p| index.headings.get(p)) {
+        if parent.level <= 1 {
+            break;
+        }
+        current = parent;
+    }
+    Some(current.title.clone())
 }
 
 pub fn scan_source_root(root: &Path) -> Result<Vec<ValidationTag>, SpecmanError> {
@@ -515,6 +800,7 @@ mod tests {
             scan_root.clone(),
             &constraints,
             tags,
+            &[],
         );
 
         assert_eq!(report.specification, spec_id);
@@ -536,6 +822,59 @@ mod tests {
         assert_eq!(report.orphans[0].identifier, "req.orphan");
     }
 
+    #[test]
+    fn waived_groups_stay_in_the_coverage_denominator() {
+        let spec_id = ArtifactId {
+            kind: ArtifactKind::Specification,
+            name: "spec".into(),
+        };
+        let impl_id = ArtifactId {
+            kind: ArtifactKind::Implementation,
+            name: "impl".into(),
+        };
+        let constraints: Vec<String> = (0..10).map(|idx| format!("req.{idx}")).collect();
+        let tags = vec![ValidationTag {
+            identifier: "req.0".to_string(),
+            tag_type: ValidationType::Test,
+            location: SourceLocation {
+                file_path: PathBuf::from("a.rs"),
+                line_number: 1,
+            },
+        }];
+        let waiver = |constraint: &str| Waiver {
+            constraint: constraint.to_string(),
+            reason: "deferred".to_string(),
+            expires: None,
+            implementation: None,
+        };
+        let mut waivers: Vec<Waiver> = constraints[1..].iter().map(|c| waiver(c)).collect();
+        waivers.push(waiver("req.typo"));
+
+        let report = generate_report(
+            spec_id,
+            impl_id,
+            PathBuf::from("src"),
+            &constraints,
+            tags,
+            &waivers,
+        );
+
+        assert!(report.missing.is_empty());
+        assert_eq!(report.waived.len(), 9);
+        assert_eq!(report.unknown_waivers, vec![waiver("req.typo")]);
+        assert_eq!(
+            (
+                report.metrics.covered_groups,
+                report.metrics.waived_groups,
+                report.metrics.total_groups
+            ),
+            (1, 9, 10)
+        );
+        assert!((report.metrics.coverage - 0.1).abs() < 1e-9);
+        // Waived groups still satisfy the default policy.
+        assert!(report.policy_violations().is_empty());
+    }
+
     fn write_workspace_fixture(
         root: &Path,
         impl_location: Option<&str>,
@@ -0,0 +1,300 @@
+//! Orders specifications so that each one follows everything it depends on, and reports how
+//! far along their implementations are.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::core::shared::SemVer;
+use crate::graph::tree::{
+    ArtifactId, ArtifactKind, ArtifactLocator, DependencyGraphServices, FilesystemDependencyMapper,
+};
+use crate::validation::validate_compliance;
+use crate::workspace::{FilesystemWorkspaceLocator, WorkspaceLocator};
+
+/// Specifications in dependency order with their implementation progress.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImplementationPlan {
+    /// Upstream specifications come before the specifications that depend on them.
+    pub steps: Vec<PlanStep>,
+    /// Specifications that cannot be ordered because they sit on or behind a dependency cycle.
+    pub unordered: Vec<ArtifactId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlanStep {
+    pub specification: ArtifactId,
+    pub version: Option<SemVer>,
+    /// Specifications named in this specification's `dependencies`.
+    pub depends_on: Vec<ArtifactId>,
+    pub implementations: Vec<PlannedImplementation>,
+    /// At least one implementation covers every constraint group it is checked against.
+    pub implemented: bool,
+    /// Every transitive upstream specification is implemented.
+    pub upstream_implemented: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlannedImplementation {
+    pub implementation: ArtifactId,
+    /// Fraction (0.0–1.0) of constraint groups covered; `None` when compliance failed.
+    pub coverage: Option<f64>,
+    pub covered_groups: usize,
+    /// Uncovered groups excused by an active waiver.
+    #[serde(default)]
+    pub waived_groups: usize,
+    pub total_groups: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PlannedImplementation {
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none() && self.covered_groups + self.waived_groups == self.total_groups
+    }
+}
+
+impl PlanStep {
+    /// Ready to build: not implemented yet, but nothing upstream is outstanding.
+    pub fn is_ready(&self) -> bool {
+        self.upstream_implemented && !self.implemented
+    }
+}
+
+impl ImplementationPlan {
+    /// Steps that can be worked on now, in plan order.
+    pub fn next(&self) -> impl Iterator<Item = &PlanStep> {
+        self.steps.iter().filter(|step| step.is_ready())
+    }
+}
+
+pub fn plan_workspace(workspace_root: PathBuf) -> Result<ImplementationPlan, SpecmanError> {
+    let locator = Arc::new(FilesystemWorkspaceLocator::new(workspace_root));
+    let mapper = FilesystemDependencyMapper::new(locator.clone());
+    let workspace = locator.workspace()?;
+    plan_with_graph(workspace.root(), mapper.dependency_graph())
+}
+
+/// Builds the plan from the inventory held by `graph`, running compliance for every
+/// implementation.
+pub(crate) fn plan_with_graph<L: WorkspaceLocator>(
+    workspace_root: &Path,
+    graph: &DependencyGraphServices<L>,
+) -> Result<ImplementationPlan, SpecmanError> {
+    let inventory = graph.inventory_snapshot()?;
+    let by_path: BTreeMap<&str, &ArtifactId> = inventory
+        .entries
+        .iter()
+        .filter_map(|entry| Some((entry.summary.resolved_path.as_deref()?, &entry.summary.id)))
+        .collect();
+    let target = |locator: &ArtifactLocator| match locator {
+        ArtifactLocator::File(path) => path.to_str().and_then(|path| by_path.get(path)).copied(),
+        ArtifactLocator::Url(_) => None,
+    };
+
+    let mut specs: BTreeMap<ArtifactId, (Option<SemVer>, BTreeSet<ArtifactId>)> = BTreeMap::new();
+    let mut implementations: BTreeMap<ArtifactId, Vec<ArtifactId>> = BTreeMap::new();
+    for entry in inventory.entries.iter() {
+        let id = &entry.summary.id;
+        match id.kind {
+            ArtifactKind::Specification => {
+                let depends_on = entry
+                    .dependencies
+                    .iter()
+                    .filter(|dependency| dependency.field == "dependencies")
+                    .filter_map(|dependency| target(&dependency.locator))
+                    .filter(|target| target.kind == ArtifactKind::Specification && *target != id)
+                    .cloned()
+                    .collect();
+                specs.insert(id.clone(), (entry.summary.version.clone(), depends_on));
+            }
+            ArtifactKind::Implementation => {
+                let spec = entry
+                    .dependencies
+                    .iter()
+                    .filter(|dependency| dependency.field == "spec")
+                    .find_map(|dependency| target(&dependency.locator));
+                if let Some(spec) = spec {
+                    implementations
+                        .entry(spec.clone())
+                        .or_default()
+                        .push(id.clone());
+                }
+            }
+            ArtifactKind::ScratchPad => {}
+        }
+    }
+
+    let order = dependency_order(specs.iter().map(|(id, (_, depends_on))| (id, depends_on)));
+    let placed: BTreeSet<&ArtifactId> = order.iter().collect();
+
+    let mut implemented: BTreeMap<ArtifactId, bool> = BTreeMap::new();
+    let mut upstream_done: BTreeMap<ArtifactId, bool> = BTreeMap::new();
+    let mut steps = Vec::new();
+    for id in order.iter().cloned() {
+        let (version, depends_on) = specs[&id].clone();
+        let planned: Vec<PlannedImplementation> = implementations
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .map(|implementation| plan_implementation(workspace_root, implementation))
+            .collect();
+        let done = planned.iter().any(PlannedImplementation::is_complete);
+        let upstream_implemented = depends_on.iter().all(|dependency| {
+            implemented.get(dependency).copied().unwrap_or(true)
+                && upstream_done.get(dependency).copied().unwrap_or(true)
+        });
+        implemented.insert(id.clone(), done);
+        upstream_done.insert(id.clone(), upstream_implemented);
+        steps.push(PlanStep {
+            specification: id,
+            version,
+            depends_on: depends_on.into_iter().collect(),
+            implementations: planned,
+            implemented: done,
+            upstream_implemented,
+        });
+    }
+
+    let unordered = specs
+        .into_keys()
+        .filter(|id| !placed.contains(id))
+        .collect();
+    Ok(ImplementationPlan { steps, unordered })
+}
+
+/// Kahn's algorithm over in-degrees, taking the alphabetically first ready specification
+/// each round. Specifications on or behind a cycle are left out; dependencies outside the
+/// set are treated as satisfied.
+fn dependency_order<'a>(
+    specs: impl IntoIterator<Item = (&'a ArtifactId, &'a BTreeSet<ArtifactId>)>,
+) -> Vec<ArtifactId> {
+    let specs: BTreeMap<&ArtifactId, &BTreeSet<ArtifactId>> = specs.into_iter().collect();
+    let mut in_degree: BTreeMap<&ArtifactId, usize> = BTreeMap::new();
+    let mut dependents: BTreeMap<&ArtifactId, Vec<&ArtifactId>> = BTreeMap::new();
+    for (&id, depends_on) in &specs {
+        let upstream: Vec<&ArtifactId> = depends_on
+            .iter()
+            .filter(|dependency| specs.contains_key(dependency))
+            .collect();
+        in_degree.insert(id, upstream.len());
+        for dependency in upstream {
+            dependents.entry(dependency).or_default().push(id);
+        }
+    }
+
+    let mut ready: BTreeSet<&ArtifactId> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(id, _)| *id)
+        .collect();
+    let mut order = Vec::with_capacity(specs.len());
+    while let Some(id) = ready.pop_first() {
+        order.push(id.clone());
+        for dependent in dependents.get(id).into_iter().flatten() {
+            let degree = in_degree
+                .get_mut(dependent)
+                .expect("dependent is a specification");
+            *degree -= 1;
+            if *degree == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+    order
+}
+
+fn plan_implementation(
+    workspace_root: &Path,
+    implementation: &ArtifactId,
+) -> PlannedImplementation {
+    match validate_compliance(workspace_root, implementation) {
+        Ok(report) => PlannedImplementation {
+            implementation: implementation.clone(),
+            coverage: Some(report.metrics.coverage),
+            covered_groups: report.metrics.covered_groups,
+            waived_groups: report.metrics.waived_groups,
+            total_groups: report.metrics.total_groups,
+            error: None,
+        },
+        Err(err) => PlannedImplementation {
+            implementation: implementation.clone(),
+            coverage: None,
+            covered_groups: 0,
+            waived_groups: 0,
+            total_groups: 0,
+            error: Some(err.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(name: &str) -> ArtifactId {
+        ArtifactId {
+            kind: ArtifactKind::Specification,
+            name: name.to_string(),
+        }
+    }
+
+    fn graph(edges: &[(&str, &[&str])]) -> BTreeMap<ArtifactId, BTreeSet<ArtifactId>> {
+        edges
+            .iter()
+            .map(|(name, depends_on)| {
+                (spec(name), depends_on.iter().map(|dep| spec(dep)).collect())
+            })
+            .collect()
+    }
+
+    fn names(order: Vec<ArtifactId>) -> Vec<String> {
+        order.into_iter().map(|id| id.name).collect()
+    }
+
+    #[test]
+    fn orders_upstream_first_and_breaks_ties_alphabetically() {
+        let specs = graph(&[
+            ("api", &["core"]),
+            ("cli", &["api", "core"]),
+            ("core", &[]),
+            ("docs", &["external"]),
+        ]);
+        assert_eq!(
+            names(dependency_order(&specs)),
+            vec!["core", "api", "cli", "docs"]
+        );
+    }
+
+    #[test]
+    fn leaves_cycles_and_their_dependents_unordered() {
+        let specs = graph(&[
+            ("alpha", &["beta"]),
+            ("beta", &["alpha"]),
+            ("core", &[]),
+            ("gamma", &["beta", "core"]),
+        ]);
+        assert_eq!(names(dependency_order(&specs)), vec!["core"]);
+    }
+
+    #[test]
+    fn orders_long_chains() {
+        let names_by_index: Vec<String> = (0..500).map(|idx| format!("spec-{idx:03}")).collect();
+        let specs: BTreeMap<ArtifactId, BTreeSet<ArtifactId>> = names_by_index
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let upstream = names_by_index.get(idx + 1).map(|next| spec(next));
+                (spec(name), upstream.into_iter().collect())
+            })
+            .collect();
+        let order = names(dependency_order(&specs));
+        assert_eq!(order.len(), 500);
+        assert_eq!(order.first().map(String::as_str), Some("spec-499"));
+        assert_eq!(order.last().map(String::as_str), Some("spec-000"));
+    }
+}
@@ -1,9 +1,14 @@
-use crate::{SpecmanError, WorkspacePaths};
+use crate::core::network::{NetworkPolicy, governed_fetcher};
+use crate::graph::lock::DependencyLock;
+use crate::graph::tree::ContentFetcher;
+use crate::validation::autofix::ReferenceSuggestion;
+use crate::{SpecmanError, WorkspaceFederation, WorkspacePaths};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use unicode_normalization::UnicodeNormalization;
 use url::Url;
@@ -277,9 +282,12 @@ pub struct ReferenceValidationIssue {
     pub message: String,
     pub source: ReferenceSource,
     pub destination: Option<String>,
+    /// Replacement destination for broken fragments and moved files, when one can be inferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<ReferenceSuggestion>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum ReferenceIssueKind {
     UnsupportedScheme,
     DisallowedHandle,
@@ -422,18 +430,27 @@ impl Default for ReferenceValidationOptions {
 pub struct ReferenceValidator<'a> {
     workspace: &'a WorkspacePaths,
     mode: ValidationMode,
+    network: NetworkPolicy,
 }
 
 impl<'a> ReferenceValidator<'a> {
     pub fn new(workspace: &'a WorkspacePaths) -> Self {
+        Self::with_mode(workspace, ValidationMode::default())
+    }
+
+    pub fn with_mode(workspace: &'a WorkspacePaths, mode: ValidationMode) -> Self {
         Self {
             workspace,
-            mode: ValidationMode::default(),
+            mode,
+            network: NetworkPolicy::for_workspace_or_deny(workspace),
         }
     }
 
-    pub fn with_mode(workspace: &'a WorkspacePaths, mode: ValidationMode) -> Self {
-        Self { workspace, mode }
+    /// Overrides the workspace network policy. Outside [`NetworkPolicy::Allow`], reachability
+    /// checks resolve `https://` destinations from `.specman/vendor` instead of the network.
+    pub fn with_network_policy(mut self, network: NetworkPolicy) -> Self {
+        self.network = network;
+        self
     }
 
     pub fn mode(&self) -> &ValidationMode {
@@ -446,6 +463,7 @@ impl<'a> ReferenceValidator<'a> {
             self.workspace,
             self.mode.clone().into(),
             self.mode.resolve_fragments,
+            self.network,
         )
     }
 }
@@ -461,7 +479,50 @@ pub fn validate_references(
     options: ReferenceValidationOptions,
 ) -> Result<ReferenceValidationReport, SpecmanError> {
     // [ENSURES: concept-reference-validation.requirements:CHECK]
-    validate_references_internal(locator, workspace, options, true)
+    let network = NetworkPolicy::for_workspace(workspace)?;
+    validate_references_internal(locator, workspace, options, true, network)
+}
+
+/// The validated workspace and the canonical roots of its federated peers, loaded once per
+/// validation run.
+struct WorkspaceScope<'a> {
+    workspace: &'a WorkspacePaths,
+    federation: WorkspaceFederation,
+    peer_roots: Vec<PathBuf>,
+}
+
+impl<'a> WorkspaceScope<'a> {
+    fn load(workspace: &'a WorkspacePaths) -> Self {
+        // An invalid `.specman/workspaces.yaml` is reported elsewhere; links then resolve as if
+        // no peers were declared.
+        let federation = WorkspaceFederation::load(workspace).unwrap_or_default();
+        let peer_roots = federation
+            .peers()
+            .iter()
+            .map(|peer| {
+                fs::canonicalize(peer.paths().root())
+                    .unwrap_or_else(|_| peer.paths().root().to_path_buf())
+            })
+            .collect();
+        Self {
+            workspace,
+            federation,
+            peer_roots,
+        }
+    }
+
+    /// Whether `path` lies inside a peer declared in `.specman/workspaces.yaml`; links into
+    /// peers are followed read-only like links within the workspace.
+    fn inside_peer(&self, path: &Path) -> bool {
+        self.peer_roots.iter().any(|root| path.starts_with(root))
+    }
+}
+
+/// Where `https://` content comes from during one validation run.
+struct RemoteContent {
+    lock: Option<DependencyLock>,
+    /// Set when the network policy forbids fetching.
+    offline: Option<Arc<dyn ContentFetcher>>,
 }
 
 fn validate_references_internal(
@@ -469,10 +530,16 @@ fn validate_references_internal(
     workspace: &WorkspacePaths,
     options: ReferenceValidationOptions,
     resolve_fragments: bool,
+    network: NetworkPolicy,
 ) -> Result<ReferenceValidationReport, SpecmanError> {
     let mut report = ReferenceValidationReport::new();
+    let remote = RemoteContent {
+        lock: DependencyLock::load(workspace)?,
+        offline: (!network.allows_network()).then(|| governed_fetcher(workspace, network)),
+    };
 
-    let root = resolve_input_locator(locator, workspace);
+    let scope = WorkspaceScope::load(workspace);
+    let root = resolve_input_locator(locator, &scope);
 
     let mut queue = VecDeque::new();
     queue.push_back(root);
@@ -507,15 +574,17 @@ fn validate_references_internal(
                     range: None,
                 },
                 destination: None,
+                suggestion: None,
             });
             break;
         }
 
-        let content = match load_document_content(&target, &options.https.mode) {
+        let content = match load_document_content(&target, &options.https.mode, workspace, &remote)
+        {
             Ok(Some(content)) => content,
             Ok(None) => continue,
-            Err(issue) => {
-                report.issues.push(issue);
+            Err(failure) => {
+                report.issues.push(failure.into_issue(&target));
                 continue;
             }
         };
@@ -523,7 +592,8 @@ fn validate_references_internal(
         let (discovered, issues, next_docs, heading_slugs) = validate_document(
             &target,
             &content,
-            workspace,
+            &scope,
+            &remote,
             &options,
             resolve_fragments,
             &mut pending_fragments,
@@ -546,6 +616,7 @@ fn validate_references_internal(
                             ),
                             source: check.source,
                             destination: Some(check.destination),
+                            suggestion: None,
                         });
                     }
                 }
@@ -592,18 +663,19 @@ impl ResolvedDocument {
     }
 }
 
-fn resolve_input_locator(locator: &str, workspace: &WorkspacePaths) -> ResolvedDocument {
+fn resolve_input_locator(locator: &str, scope: &WorkspaceScope) -> ResolvedDocument {
     if let Ok(url) = parse_https_url(locator) {
         return ResolvedDocument::Url { url };
     }
 
+    let workspace = scope.workspace;
     let path = if let Some(rest) = locator.strip_prefix("spec://") {
-        resolve_handle_path(workspace.spec_dir(), rest, "spec.md")
+        resolve_handle_path(scope, WorkspacePaths::spec_dir, rest, "spec.md")
     } else if let Some(rest) = locator.strip_prefix("impl://") {
         // [ENSURES: concept-implementations.locator-schemes.resolution:CHECK]
-        resolve_handle_path(workspace.impl_dir(), rest, "impl.md")
+        resolve_handle_path(scope, WorkspacePaths::impl_dir, rest, "impl.md")
     } else if let Some(rest) = locator.strip_prefix("scratch://") {
-        resolve_handle_path(workspace.scratchpad_dir(), rest, "scratch.md")
+        resolve_handle_path(scope, WorkspacePaths::scratchpad_dir, rest, "scratch.md")
     } else {
         // workspace-relative or absolute path
         if Path::new(locator).is_absolute() {
@@ -631,36 +703,78 @@ fn resolve_input_locator(locator: &str, workspace: &WorkspacePaths) -> ResolvedD
     ResolvedDocument::File { path, base_dir }
 }
 
-fn resolve_handle_path(base: PathBuf, raw_slug: &str, leaf: &str) -> PathBuf {
+fn resolve_handle_path(
+    scope: &WorkspaceScope,
+    dir: fn(&WorkspacePaths) -> PathBuf,
+    raw_slug: &str,
+    leaf: &str,
+) -> PathBuf {
     // Canonicalize using the same constraints as `ResourceHandle::canonical_slug`.
     // Any error is surfaced later as a validation error during loading.
     let slug = raw_slug.trim().to_ascii_lowercase();
-    base.join(slug).join(leaf)
+    if let Some((peer, peer_slug)) = slug.split_once(':')
+        && let Ok(peer) = scope.federation.peer(peer)
+    {
+        return dir(peer.paths()).join(peer_slug).join(leaf);
+    }
+    dir(scope.workspace).join(slug).join(leaf)
+}
+
+/// Why a document could not be loaded; the caller knows which link or document it belongs to.
+struct LoadFailure {
+    kind: ReferenceIssueKind,
+    severity: IssueSeverity,
+    message: String,
+}
+
+impl LoadFailure {
+    fn fetch(severity: IssueSeverity, message: String) -> Self {
+        Self {
+            kind: ReferenceIssueKind::Fetch,
+            severity,
+            message,
+        }
+    }
+
+    fn into_issue(self, target: &ResolvedDocument) -> ReferenceValidationIssue {
+        let destination = match target {
+            ResolvedDocument::File { .. } => None,
+            ResolvedDocument::Url { url } => Some(url.as_str().to_string()),
+        };
+        ReferenceValidationIssue {
+            kind: self.kind,
+            severity: self.severity,
+            message: self.message,
+            source: ReferenceSource {
+                document: target.describe(),
+                range: None,
+            },
+            destination,
+            suggestion: None,
+        }
+    }
 }
 
 fn load_document_content(
     target: &ResolvedDocument,
     https_mode: &HttpsValidationMode,
-) -> Result<Option<String>, ReferenceValidationIssue> {
+    workspace: &WorkspacePaths,
+    remote: &RemoteContent,
+) -> Result<Option<String>, LoadFailure> {
     match target {
         ResolvedDocument::File { path, .. } => match fs::read_to_string(path) {
             Ok(s) => Ok(Some(s)),
-            Err(err) => Err(ReferenceValidationIssue {
+            Err(err) => Err(LoadFailure {
                 kind: ReferenceIssueKind::Io,
                 severity: IssueSeverity::Error,
                 message: format!("failed to read file {}: {err}", path.display()),
-                source: ReferenceSource {
-                    document: path_to_forward_slashes(path),
-                    range: None,
-                },
-                destination: None,
             }),
         },
         ResolvedDocument::Url { url } => match https_mode {
             HttpsValidationMode::SyntaxOnly => Ok(None),
             HttpsValidationMode::Reachability { .. } => {
                 // We only fetch content if reachability mode is enabled.
-                match fetch_url(url, https_mode) {
+                match fetch_remote(url, https_mode, workspace, remote) {
                     Ok(text) => Ok(Some(text)),
                     Err(issue) => Err(issue),
                 }
@@ -669,7 +783,28 @@ fn load_document_content(
     }
 }
 
-fn fetch_url(url: &Url, mode: &HttpsValidationMode) -> Result<String, ReferenceValidationIssue> {
+/// Serves URLs from the vendor directory when the network policy forbids fetching, and URLs
+/// pinned in `.specman/deps.lock` from their cached copy; everything else is fetched.
+fn fetch_remote(
+    url: &Url,
+    mode: &HttpsValidationMode,
+    workspace: &WorkspacePaths,
+    remote: &RemoteContent,
+) -> Result<String, LoadFailure> {
+    if let Some(offline) = &remote.offline {
+        return offline
+            .fetch(url)
+            .map_err(|err| LoadFailure::fetch(IssueSeverity::Diagnostic, err.to_string()));
+    }
+    match remote.lock.as_ref().and_then(|lock| lock.get(url.as_str())) {
+        Some(entry) => entry
+            .read(workspace)
+            .map_err(|err| LoadFailure::fetch(IssueSeverity::Error, err.to_string())),
+        None => fetch_url(url, mode),
+    }
+}
+
+fn fetch_url(url: &Url, mode: &HttpsValidationMode) -> Result<String, LoadFailure> {
     let (timeout, max_redirects, method) = match mode {
         HttpsValidationMode::Reachability {
             timeout,
@@ -677,16 +812,10 @@ fn fetch_url(url: &Url, mode: &HttpsValidationMode) -> Result<String, ReferenceV
             method,
         } => (*timeout, *max_redirects, method.clone()),
         HttpsValidationMode::SyntaxOnly => {
-            return Err(ReferenceValidationIssue {
-                kind: ReferenceIssueKind::Fetch,
-                severity: IssueSeverity::Diagnostic,
-                message: "fetch_url called in syntax-only mode".into(),
-                source: ReferenceSource {
-                    document: url.as_str().to_string(),
-                    range: None,
-                },
-                destination: Some(url.as_str().to_string()),
-            });
+            return Err(LoadFailure::fetch(
+                IssueSeverity::Diagnostic,
+                "fetch_url called in syntax-only mode".into(),
+            ));
         }
     };
 
@@ -710,16 +839,10 @@ fn fetch_url(url: &Url, mode: &HttpsValidationMode) -> Result<String, ReferenceV
 
             match response.into_string() {
                 Ok(s) => Ok(s),
-                Err(err) => Err(ReferenceValidationIssue {
-                    kind: ReferenceIssueKind::Fetch,
-                    severity: IssueSeverity::Diagnostic,
-                    message: format!("failed reading response body: {err}"),
-                    source: ReferenceSource {
-                        document: url.as_str().to_string(),
-                        range: None,
-                    },
-                    destination: Some(url.as_str().to_string()),
-                }),
+                Err(err) => Err(LoadFailure::fetch(
+                    IssueSeverity::Diagnostic,
+                    format!("failed reading response body: {err}"),
+                )),
             }
         }
         Err(ureq::Error::Status(code, response)) => {
@@ -730,26 +853,16 @@ fn fetch_url(url: &Url, mode: &HttpsValidationMode) -> Result<String, ReferenceV
             };
 
             let hint = response.status_text().to_string();
-            Err(ReferenceValidationIssue {
+            Err(LoadFailure {
                 kind: ReferenceIssueKind::UnreachableUrl,
                 severity,
                 message: format!("https reachability failed with status {code} ({hint})"),
-                source: ReferenceSource {
-                    document: url.as_str().to_string(),
-                    range: None,
-                },
-                destination: Some(url.as_str().to_string()),
             })
         }
-        Err(err) => Err(ReferenceValidationIssue {
+        Err(err) => Err(LoadFailure {
             kind: ReferenceIssueKind::UnreachableUrl,
             severity: IssueSeverity::Diagnostic,
             message: format!("https request failed: {err}"),
-            source: ReferenceSource {
-                document: url.as_str().to_string(),
-                range: None,
-            },
-            destination: Some(url.as_str().to_string()),
         }),
     }
 }
@@ -757,7 +870,8 @@ fn fetch_url(url: &Url, mode: &HttpsValidationMode) -> Result<String, ReferenceV
 fn validate_document(
     target: &ResolvedDocument,
     markdown_source: &str,
-    workspace: &WorkspacePaths,
+    scope: &WorkspaceScope,
+    remote: &RemoteContent,
     options: &ReferenceValidationOptions,
     resolve_fragments: bool,
     pending_fragments: &mut BTreeMap<DocumentKey, BTreeSet<PendingFragmentCheck>>,
@@ -787,6 +901,7 @@ fn validate_document(
                     range: None,
                 },
                 destination: None,
+                suggestion: None,
             });
             return (discovered, issues, Vec::new(), BTreeSet::new());
         }
@@ -810,6 +925,7 @@ fn validate_document(
                     range: extracted.range,
                 },
                 destination: None,
+                suggestion: None,
             });
             continue;
         }
@@ -847,6 +963,7 @@ fn validate_document(
                             message: "empty fragment is invalid".into(),
                             source,
                             destination: Some(destination),
+                            suggestion: None,
                         });
                     } else if !heading_slugs.contains(fragment) {
                         issues.push(ReferenceValidationIssue {
@@ -857,6 +974,7 @@ fn validate_document(
                             ),
                             source,
                             destination: Some(destination),
+                            suggestion: None,
                         });
                     }
                 }
@@ -871,12 +989,16 @@ fn validate_document(
                         message: "invalid https url".into(),
                         source,
                         destination: Some(destination),
+                        suggestion: None,
                     });
                 } else if let HttpsValidationMode::Reachability { .. } = options.https.mode {
                     // Reachability is validated at extraction time only for the target document.
                     // For discovered references, we optionally validate now.
                     if let Ok(url) = Url::parse(&destination) {
-                        if let Err(issue) = fetch_url(&url, &options.https.mode).map(|_| ()) {
+                        if let Err(issue) =
+                            fetch_remote(&url, &options.https.mode, scope.workspace, remote)
+                                .map(|_| ())
+                        {
                             issues.push(ReferenceValidationIssue {
                                 kind: issue.kind,
                                 severity: issue.severity,
@@ -886,6 +1008,7 @@ fn validate_document(
                                     range: source.range.clone(),
                                 },
                                 destination: Some(destination),
+                                suggestion: None,
                             });
                         }
                     }
@@ -894,7 +1017,7 @@ fn validate_document(
             ReferenceKind::WorkspacePath => {
                 let (path_part, fragment_opt) = split_path_and_fragment(&destination);
 
-                match resolve_markdown_destination_path(target, &path_part, workspace) {
+                match resolve_markdown_destination_path(target, &path_part, scope) {
                     Ok(resolved) => {
                         record.normalized = Some(path_to_forward_slashes(&resolved));
 
@@ -908,6 +1031,7 @@ fn validate_document(
                                 ),
                                 source: source.clone(),
                                 destination: Some(destination.clone()),
+                                suggestion: None,
                             });
                         }
 
@@ -922,6 +1046,7 @@ fn validate_document(
                                             message: "empty fragment is invalid".into(),
                                             source: source.clone(),
                                             destination: Some(destination.clone()),
+                                            suggestion: None,
                                         });
                                     } else {
                                         // If this resolves to the current document, validate now.
@@ -938,6 +1063,7 @@ fn validate_document(
                                                         ),
                                                         source: source.clone(),
                                                         destination: Some(destination.clone()),
+                                                        suggestion: None,
                                                     });
                                                 }
                                             } else if options.transitive.enabled {
@@ -956,6 +1082,7 @@ fn validate_document(
                                                     message: "cross-document fragment validation skipped (transitive disabled)".into(),
                                                     source: source.clone(),
                                                     destination: Some(destination.clone()),
+                                                    suggestion: None,
                                                 });
                                             }
                                         }
@@ -992,6 +1119,7 @@ fn validate_document(
                             message: msg,
                             source,
                             destination: Some(destination),
+                            suggestion: None,
                         })
                     }
                 }
@@ -1003,6 +1131,7 @@ fn validate_document(
                     message: "SpecMan handles are not valid markdown destinations".into(),
                     source,
                     destination: Some(destination),
+                    suggestion: None,
                 });
             }
             ReferenceKind::UnsupportedScheme => {
@@ -1012,6 +1141,7 @@ fn validate_document(
                     message: "unsupported or invalid destination".into(),
                     source,
                     destination: Some(destination),
+                    suggestion: None,
                 });
             }
         }
@@ -1114,7 +1244,7 @@ fn split_path_and_fragment(dest: &str) -> (String, Option<String>) {
 fn resolve_markdown_destination_path(
     source: &ResolvedDocument,
     dest: &str,
-    workspace: &WorkspacePaths,
+    scope: &WorkspaceScope,
 ) -> Result<PathBuf, String> {
     let dest = dest.trim();
     if dest.is_empty() {
@@ -1135,8 +1265,8 @@ fn resolve_markdown_destination_path(
         }
     };
 
-    let workspace_root =
-        fs::canonicalize(workspace.root()).unwrap_or_else(|_| workspace.root().to_path_buf());
+    let root = scope.workspace.root();
+    let workspace_root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
 
     // Use canonical base_dir for consistent comparisons (normalizes 8.3 names and casing on Windows).
     let base_dir = fs::canonicalize(base_dir).unwrap_or_else(|_| base_dir.to_path_buf());
@@ -1150,7 +1280,7 @@ fn resolve_markdown_destination_path(
 
     // If the target exists, canonicalize and compare canonical paths.
     if let Ok(canonical) = fs::canonicalize(&resolved) {
-        if !canonical.starts_with(&workspace_root) {
+        if !canonical.starts_with(&workspace_root) && !scope.inside_peer(&canonical) {
             return Err(format!(
                 "filesystem destination escapes workspace: {}",
                 path_to_forward_slashes(&canonical)
@@ -1161,7 +1291,7 @@ fn resolve_markdown_destination_path(
 
     // Missing target: apply lexical normalization and compare against canonical workspace root.
     let normalized = normalize_path_lexical(&resolved);
-    if !normalized.starts_with(&workspace_root) {
+    if !normalized.starts_with(&workspace_root) && !scope.inside_peer(&normalized) {
         return Err(format!(
             "filesystem destination escapes workspace: {}",
             path_to_forward_slashes(&normalized)
@@ -1226,6 +1356,23 @@ fn collect_definitions(root: &markdown::mdast::Node) -> BTreeMap<String, String>
     defs
 }
 
+/// Link destinations in `markdown` paired with the line each link starts on, for callers that
+/// rewrite destinations in place. Unresolved reference links are skipped.
+pub(crate) fn link_destinations(markdown: &str) -> Vec<(String, usize)> {
+    let Ok(root) = markdown::to_mdast(markdown, &markdown::ParseOptions::default()) else {
+        return Vec::new();
+    };
+    let definitions = collect_definitions(&root);
+    extract_destinations(&root, &definitions)
+        .into_iter()
+        .filter(|extracted| extracted.unresolved_reference_identifier.is_none())
+        .map(|extracted| {
+            let line = extracted.range.map_or(1, |range| range.start.line);
+            (extracted.destination, line)
+        })
+        .collect()
+}
+
 struct ExtractedDestination {
     destination: String,
     range: Option<SourceRange>,
@@ -1386,6 +1533,7 @@ fn collect_heading_slugs(
                                 range: None,
                             },
                             destination: None,
+                            suggestion: None,
                         });
                     }
                 }
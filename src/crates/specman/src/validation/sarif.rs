@@ -0,0 +1,750 @@
+//! SARIF 2.1.0 export for validation reports so code-scanning dashboards can ingest
+//! SpecMan findings alongside other static analysis results.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph::tree::{ArtifactId, ArtifactKind};
+use crate::validation::ComplianceReport;
+use crate::validation::lint::{LintLevel, LintRule};
+use crate::validation::references::{
+    IssueSeverity, ReferenceIssueKind, ReferenceValidationIssue, ReferenceValidationReport,
+    SourceRange,
+};
+use crate::validation::status::WorkspaceStatusReport;
+use crate::workspace::{WorkspacePaths, workspace_relative_path};
+
+pub const SARIF_VERSION: &str = "2.1.0";
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Base URI identifier that every workspace-relative result location is resolved against.
+const SRCROOT: &str = "SRCROOT";
+
+/// Top-level SARIF document (`sarifLog`).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub original_uri_base_ids: BTreeMap<String, SarifArtifactLocation>,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub name: String,
+    pub short_description: SarifMessage,
+    pub default_configuration: SarifRuleConfiguration,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SarifRuleConfiguration {
+    pub level: SarifLevel,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SarifLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: SarifLevel,
+    pub message: SarifMessage,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<SarifLocation>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+}
+
+/// Line and column numbers are 1-based, matching [`SourceRange`] and
+/// [`crate::validation::SourceLocation`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+}
+
+impl SarifLog {
+    /// Converts a workspace status run into a single-run SARIF log.
+    pub fn from_status_report(report: &WorkspaceStatusReport, workspace: &WorkspacePaths) -> Self {
+        let mut builder = SarifBuilder::new(workspace);
+
+        for error in &report.structure_errors {
+            builder.push(Rule::Structure, error.clone(), None);
+        }
+        for cycle in &report.cycle_errors {
+            // Point at the entry that closes the cycle back to its first artifact.
+            let closing = cycle.edges.last();
+            let location = closing.and_then(|edge| {
+                let path = edge.path.as_deref()?;
+                Some(builder.file_location(Path::new(path), edge.line))
+            });
+            let message = match closing {
+                Some(edge) => format!(
+                    "dependency cycle {cycle}; closed by `{}` entry `{}` in {}",
+                    edge.field, edge.reference, edge.from
+                ),
+                None => format!("dependency cycle {cycle}"),
+            };
+            builder.push(Rule::DependencyCycle, message, location);
+        }
+
+        for (id, status) in &report.artifacts {
+            let artifact_file = artifact_file(workspace, id);
+            for error in &status.structure_errors {
+                let location = builder.file_location(&artifact_file, None);
+                builder.push(Rule::Structure, error.clone(), Some(location));
+            }
+            for issue in &status.reference_errors {
+                builder.push_reference_issue(issue);
+            }
+            let missing_level = missing_level(status.compliance_failed());
+            for constraint in &status.compliance_missing {
+                let location = builder.file_location(&artifact_file, None);
+                builder.push_with_level(
+                    Rule::MissingConstraint,
+                    missing_level,
+                    missing_message(id, constraint),
+                    Some(location),
+                );
+            }
+            for error in &status.compliance_errors {
+                let location = builder.file_location(&artifact_file, None);
+                builder.push(Rule::CoverageThreshold, error.clone(), Some(location));
+            }
+            let scan_root = status.compliance_scan_root.as_deref().map(PathBuf::from);
+            for orphan in &status.compliance_orphans {
+                let file = scan_root
+                    .as_ref()
+                    .map(|root| root.join(&orphan.location.file_path))
+                    .unwrap_or_else(|| orphan.location.file_path.clone());
+                let location = builder.file_location(&file, Some(orphan.location.line_number));
+                builder.push(
+                    Rule::OrphanTag,
+                    orphan_message(id, &orphan.identifier),
+                    Some(location),
+                );
+            }
+            for issue in &status.lint_issues {
+                let location = builder.file_location(Path::new(&issue.document), issue.line);
+                let level = match issue.level {
+                    LintLevel::Error => SarifLevel::Error,
+                    _ => SarifLevel::Warning,
+                };
+                builder.push_with_level(
+                    Rule::Lint(issue.rule),
+                    level,
+                    issue.message.clone(),
+                    Some(location),
+                );
+            }
+        }
+
+        builder.finish()
+    }
+
+    /// Converts the issues of a reference validation run into a single-run SARIF log.
+    pub fn from_reference_report(
+        report: &ReferenceValidationReport,
+        workspace: &WorkspacePaths,
+    ) -> Self {
+        let mut builder = SarifBuilder::new(workspace);
+        for issue in &report.issues {
+            builder.push_reference_issue(issue);
+        }
+        builder.finish()
+    }
+
+    /// Converts missing constraints and orphan tags from a compliance report into a
+    /// single-run SARIF log.
+    pub fn from_compliance_report(report: &ComplianceReport, workspace: &WorkspacePaths) -> Self {
+        let mut builder = SarifBuilder::new(workspace);
+        let impl_file = artifact_file(workspace, &report.implementation);
+        let violations = report.policy_violations();
+        let missing_level = missing_level(!violations.is_empty());
+        for constraint in &report.missing {
+            let location = builder.file_location(&impl_file, None);
+            builder.push_with_level(
+                Rule::MissingConstraint,
+                missing_level,
+                missing_message(&report.implementation, constraint),
+                Some(location),
+            );
+        }
+        for violation in violations {
+            let location = builder.file_location(&impl_file, None);
+            builder.push(Rule::CoverageThreshold, violation, Some(location));
+        }
+        for orphan in &report.orphans {
+            let file = report.scan_root.join(&orphan.location.file_path);
+            let location = builder.file_location(&file, Some(orphan.location.line_number));
+            builder.push(
+                Rule::OrphanTag,
+                orphan_message(&report.implementation, &orphan.identifier),
+                Some(location),
+            );
+        }
+        builder.finish()
+    }
+
+    /// Returns true when any result carries the `error` level, i.e. the run would fail
+    /// `specman status`.
+    pub fn has_errors(&self) -> bool {
+        self.runs
+            .iter()
+            .flat_map(|run| &run.results)
+            .any(|result| result.level == SarifLevel::Error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rule {
+    Structure,
+    DependencyCycle,
+    Reference(ReferenceIssueKind),
+    MissingConstraint,
+    CoverageThreshold,
+    OrphanTag,
+    Lint(LintRule),
+}
+
+/// SARIF rule name and description for each kind of reference issue.
+fn reference_rule(kind: ReferenceIssueKind) -> (&'static str, &'static str) {
+    match kind {
+        ReferenceIssueKind::UnsupportedScheme => (
+            "UnsupportedScheme",
+            "Reference uses an unsupported URI scheme",
+        ),
+        ReferenceIssueKind::DisallowedHandle => (
+            "DisallowedHandle",
+            "Reference uses a resource handle that is not allowed in documents",
+        ),
+        ReferenceIssueKind::WorkspaceBoundary => {
+            ("WorkspaceBoundary", "Reference escapes the workspace root")
+        }
+        ReferenceIssueKind::FileMissing => (
+            "FileMissing",
+            "Reference points to a file that does not exist",
+        ),
+        ReferenceIssueKind::InvalidFragment => (
+            "InvalidFragment",
+            "Reference fragment does not match a heading in the target document",
+        ),
+        ReferenceIssueKind::MalformedUrl => ("MalformedUrl", "Reference is not a well-formed URL"),
+        ReferenceIssueKind::UnreachableUrl => {
+            ("UnreachableUrl", "Referenced URL could not be reached")
+        }
+        ReferenceIssueKind::ParseFailure => ("ParseFailure", "Document could not be parsed"),
+        ReferenceIssueKind::TraversalLimit => (
+            "TraversalLimit",
+            "Transitive reference traversal hit its document limit",
+        ),
+        ReferenceIssueKind::Io => ("Io", "Document could not be read"),
+        ReferenceIssueKind::UnresolvedReference => (
+            "UnresolvedReference",
+            "Reference-style link has no matching definition",
+        ),
+        ReferenceIssueKind::EmptyDestination => {
+            ("EmptyDestination", "Link has an empty destination")
+        }
+        ReferenceIssueKind::CrossDocumentFragmentSkipped => (
+            "CrossDocumentFragmentSkipped",
+            "Fragment in another document was not validated",
+        ),
+        ReferenceIssueKind::Fetch => ("Fetch", "Remote document could not be fetched"),
+        ReferenceIssueKind::Unknown => ("Unknown", "Reference validation failed"),
+    }
+}
+
+impl Rule {
+    fn id(self) -> String {
+        match self {
+            Rule::Structure => "specman/structure".into(),
+            Rule::DependencyCycle => "specman/dependency-cycle".into(),
+            Rule::Reference(kind) => {
+                format!("specman/reference/{}", kebab_case(reference_rule(kind).0))
+            }
+            Rule::MissingConstraint => "specman/compliance/missing-constraint".into(),
+            Rule::CoverageThreshold => "specman/compliance/coverage-threshold".into(),
+            Rule::OrphanTag => "specman/compliance/orphan-tag".into(),
+            Rule::Lint(rule) => format!("specman/lint/{}", rule.name()),
+        }
+    }
+
+    fn describe(self) -> (&'static str, &'static str, SarifLevel) {
+        match self {
+            Rule::Structure => (
+                "StructureError",
+                "Artifact structure or front matter is invalid",
+                SarifLevel::Error,
+            ),
+            Rule::DependencyCycle => (
+                "DependencyCycle",
+                "Artifacts depend on each other in a cycle",
+                SarifLevel::Error,
+            ),
+            Rule::Reference(kind) => {
+                let (name, description) = reference_rule(kind);
+                (name, description, SarifLevel::Error)
+            }
+            Rule::MissingConstraint => (
+                "MissingConstraint",
+                "Specification constraint has no ENSURES tag in the implementation",
+                SarifLevel::Error,
+            ),
+            Rule::CoverageThreshold => (
+                "CoverageThreshold",
+                "Implementation covers fewer constraint groups than its compliance policy requires",
+                SarifLevel::Error,
+            ),
+            Rule::OrphanTag => (
+                "OrphanTag",
+                "ENSURES tag references a constraint that does not exist",
+                SarifLevel::Warning,
+            ),
+            Rule::Lint(rule) => (rule.name(), rule.description(), SarifLevel::Warning),
+        }
+    }
+}
+
+/// Accumulates results and registers each rule the first time it is used so the driver
+/// only advertises rules that appear in the log.
+struct SarifBuilder<'a> {
+    workspace: &'a WorkspacePaths,
+    rules: Vec<Rule>,
+    results: Vec<SarifResult>,
+}
+
+impl<'a> SarifBuilder<'a> {
+    fn new(workspace: &'a WorkspacePaths) -> Self {
+        Self {
+            workspace,
+            rules: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    fn rule_index(&mut self, rule: Rule) -> usize {
+        match self.rules.iter().position(|known| *known == rule) {
+            Some(index) => index,
+            None => {
+                self.rules.push(rule);
+                self.rules.len() - 1
+            }
+        }
+    }
+
+    fn push(&mut self, rule: Rule, message: String, location: Option<SarifLocation>) {
+        self.push_with_level(rule, rule.describe().2, message, location);
+    }
+
+    fn push_with_level(
+        &mut self,
+        rule: Rule,
+        level: SarifLevel,
+        message: String,
+        location: Option<SarifLocation>,
+    ) {
+        let rule_index = self.rule_index(rule);
+        self.results.push(SarifResult {
+            rule_id: rule.id(),
+            rule_index,
+            level,
+            message: SarifMessage { text: message },
+            locations: location.into_iter().collect(),
+        });
+    }
+
+    fn push_reference_issue(&mut self, issue: &ReferenceValidationIssue) {
+        let level = match issue.severity {
+            IssueSeverity::Error => SarifLevel::Error,
+            IssueSeverity::Diagnostic => SarifLevel::Note,
+        };
+        let location = self.document_location(&issue.source.document, issue.source.range.as_ref());
+        self.push_with_level(
+            Rule::Reference(issue.kind),
+            level,
+            issue.message.clone(),
+            Some(location),
+        );
+    }
+
+    fn document_location(&self, document: &str, range: Option<&SourceRange>) -> SarifLocation {
+        let region = range.map(|range| SarifRegion {
+            start_line: range.start.line,
+            start_column: Some(range.start.column),
+            end_line: Some(range.end.line),
+            end_column: Some(range.end.column),
+        });
+        if document.contains("://") {
+            return SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: document.to_string(),
+                        uri_base_id: None,
+                    },
+                    region,
+                },
+            };
+        }
+        let mut location = self.file_location(Path::new(document), None);
+        location.physical_location.region = region;
+        location
+    }
+
+    /// Builds a location for a file, relative to the workspace root when it lies inside it.
+    fn file_location(&self, path: &Path, line: Option<usize>) -> SarifLocation {
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.workspace.root().join(path)
+        };
+        let artifact_location = match workspace_relative_path(self.workspace.root(), &path) {
+            Some(relative) => SarifArtifactLocation {
+                uri: relative,
+                uri_base_id: Some(SRCROOT.to_string()),
+            },
+            None => SarifArtifactLocation {
+                uri: url::Url::from_file_path(&path)
+                    .map(String::from)
+                    .unwrap_or_else(|_| path.display().to_string()),
+                uri_base_id: None,
+            },
+        };
+        SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location,
+                region: line.map(|line| SarifRegion {
+                    start_line: line,
+                    start_column: None,
+                    end_line: None,
+                    end_column: None,
+                }),
+            },
+        }
+    }
+
+    fn finish(self) -> SarifLog {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let (name, description, level) = rule.describe();
+                SarifRule {
+                    id: rule.id(),
+                    name: name.to_string(),
+                    short_description: SarifMessage {
+                        text: description.to_string(),
+                    },
+                    default_configuration: SarifRuleConfiguration { level },
+                }
+            })
+            .collect();
+
+        let mut original_uri_base_ids = BTreeMap::new();
+        if let Ok(root) = url::Url::from_directory_path(self.workspace.root()) {
+            original_uri_base_ids.insert(
+                SRCROOT.to_string(),
+                SarifArtifactLocation {
+                    uri: root.to_string(),
+                    uri_base_id: None,
+                },
+            );
+        }
+
+        SarifLog {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "specman".to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        information_uri: "https://github.com/justinbrick/specman".to_string(),
+                        rules,
+                    },
+                },
+                original_uri_base_ids,
+                results: self.results,
+            }],
+        }
+    }
+}
+
+fn artifact_file(workspace: &WorkspacePaths, id: &ArtifactId) -> PathBuf {
+    match id.kind {
+        ArtifactKind::Specification => workspace.spec_dir().join(&id.name).join("spec.md"),
+        ArtifactKind::Implementation => workspace.impl_dir().join(&id.name).join("impl.md"),
+        ArtifactKind::ScratchPad => workspace.scratchpad_dir().join(&id.name).join("scratch.md"),
+    }
+}
+
+/// Missing groups are only errors when they push the implementation below its policy.
+fn missing_level(compliance_failed: bool) -> SarifLevel {
+    if compliance_failed {
+        SarifLevel::Error
+    } else {
+        SarifLevel::Warning
+    }
+}
+
+fn missing_message(implementation: &ArtifactId, constraint: &str) -> String {
+    format!(
+        "implementation {} has no ENSURES tag for constraint `{constraint}`",
+        implementation.name
+    )
+}
+
+fn orphan_message(implementation: &ArtifactId, identifier: &str) -> String {
+    format!(
+        "ENSURES tag `{identifier}` in implementation {} does not match any constraint",
+        implementation.name
+    )
+}
+
+fn kebab_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (idx, ch) in name.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if idx > 0 {
+                out.push('-');
+            }
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::tree::{CycleEdge, DependencyCycle};
+    use crate::validation::references::{ReferenceSource, SourcePoint};
+    use crate::validation::status::{ArtifactStatus, StatusResult};
+    use crate::validation::{SourceLocation, ValidationTag, ValidationType, generate_report};
+
+    fn workspace() -> WorkspacePaths {
+        let root = PathBuf::from("/work");
+        WorkspacePaths::new(root.clone(), root.join(".specman"))
+    }
+
+    fn implementation() -> ArtifactId {
+        ArtifactId {
+            kind: ArtifactKind::Implementation,
+            name: "core-rs".into(),
+        }
+    }
+
+    fn cycle() -> DependencyCycle {
+        let spec = |name: &str| ArtifactId {
+            kind: ArtifactKind::Specification,
+            name: name.into(),
+        };
+        let edge = |from: &str, to: &str| CycleEdge {
+            from: spec(from),
+            to: spec(to),
+            field: "dependencies".into(),
+            reference: format!("../{to}/spec.md"),
+            path: Some(format!("/work/spec/{from}/spec.md")),
+            line: Some(5),
+        };
+        DependencyCycle {
+            artifacts: vec![spec("a"), spec("b")],
+            edges: vec![edge("a", "b"), edge("b", "a")],
+        }
+    }
+
+    #[test]
+    fn status_report_maps_issues_to_rules_and_locations() {
+        let workspace = workspace();
+        let mut status = ArtifactStatus::new();
+        status.reference_errors.push(ReferenceValidationIssue {
+            kind: ReferenceIssueKind::FileMissing,
+            severity: IssueSeverity::Error,
+            message: "missing filesystem target".into(),
+            source: ReferenceSource {
+                document: "/work/impl/core-rs/impl.md".into(),
+                range: Some(SourceRange {
+                    start: SourcePoint { line: 7, column: 1 },
+                    end: SourcePoint {
+                        line: 7,
+                        column: 20,
+                    },
+                }),
+            },
+            destination: Some("missing.md".into()),
+            suggestion: None,
+        });
+        status.compliance_missing.push("core.alpha".into());
+        status.compliance_scan_root = Some("/work/src".into());
+        status.compliance_orphans.push(ValidationTag {
+            identifier: "core.gone".into(),
+            tag_type: ValidationType::Check,
+            location: SourceLocation {
+                file_path: PathBuf::from("lib.rs"),
+                line_number: 3,
+            },
+        });
+
+        let report = WorkspaceStatusReport {
+            global_status: StatusResult::Fail,
+            spec_impl_status: StatusResult::Fail,
+            scratchpad_status: StatusResult::Pass,
+            artifacts: BTreeMap::from([(implementation(), status)]),
+            cycle_errors: vec![cycle()],
+            structure_errors: Vec::new(),
+            artifact_count: 1,
+        };
+
+        let log = SarifLog::from_status_report(&report, &workspace);
+        assert!(log.has_errors());
+        let run = &log.runs[0];
+        let rule_ids: Vec<_> = run
+            .tool
+            .driver
+            .rules
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(
+            rule_ids,
+            [
+                "specman/dependency-cycle",
+                "specman/reference/file-missing",
+                "specman/compliance/missing-constraint",
+                "specman/compliance/orphan-tag",
+            ]
+        );
+
+        let cycle = &run.results[0];
+        assert!(
+            cycle
+                .message
+                .text
+                .contains("`dependencies` entry `../a/spec.md`")
+        );
+        let location = &cycle.locations[0].physical_location;
+        assert_eq!(location.artifact_location.uri, "spec/b/spec.md");
+        assert_eq!(location.region.as_ref().unwrap().start_line, 5);
+
+        let reference = &run.results[1];
+        assert_eq!(reference.rule_index, 1);
+        let location = &reference.locations[0].physical_location;
+        assert_eq!(location.artifact_location.uri, "impl/core-rs/impl.md");
+        assert_eq!(
+            location.artifact_location.uri_base_id.as_deref(),
+            Some(SRCROOT)
+        );
+        let region = location.region.as_ref().unwrap();
+        assert_eq!((region.start_line, region.end_column), (7, Some(20)));
+
+        // Without a recorded policy violation a missing group does not fail the run.
+        assert_eq!(run.results[2].level, SarifLevel::Warning);
+
+        let orphan = &run.results[3];
+        assert_eq!(orphan.level, SarifLevel::Warning);
+        let location = &orphan.locations[0].physical_location;
+        assert_eq!(location.artifact_location.uri, "src/lib.rs");
+        assert_eq!(location.region.as_ref().unwrap().start_line, 3);
+        assert_eq!(run.original_uri_base_ids[SRCROOT].uri, "file:///work/");
+    }
+
+    #[test]
+    fn serializes_sarif_field_names() {
+        let workspace = workspace();
+        let report = generate_report(
+            ArtifactId {
+                kind: ArtifactKind::Specification,
+                name: "core".into(),
+            },
+            implementation(),
+            PathBuf::from("/work/src"),
+            &["core.alpha".to_string()],
+            Vec::new(),
+            &[],
+        );
+
+        let value =
+            serde_json::to_value(SarifLog::from_compliance_report(&report, &workspace)).unwrap();
+        assert_eq!(value["version"], "2.1.0");
+        assert_eq!(value["$schema"], SARIF_SCHEMA);
+        let result = &value["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "specman/compliance/missing-constraint");
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "impl/core-rs/impl.md"
+        );
+        assert!(result["locations"][0]["physicalLocation"]["region"].is_null());
+        assert_eq!(
+            value["runs"][0]["results"][1]["ruleId"],
+            "specman/compliance/coverage-threshold"
+        );
+    }
+}
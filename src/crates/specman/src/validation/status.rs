@@ -1,16 +1,26 @@
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::core::error::SpecmanError;
-use crate::graph::tree::{ArtifactId, ArtifactKind, FilesystemDependencyMapper};
+use crate::graph::tree::{
+    ArtifactId, ArtifactKind, DependencyCycle, DependencyGraphServices, FilesystemDependencyMapper,
+    InventoryEntry, WorkspaceInventorySnapshot,
+};
+use crate::metadata::frontmatter::{ArtifactFrontMatter, ScratchWorkType, split_front_matter};
+use crate::metadata::waiver::Waiver;
+use crate::scratchpad::{WORK_TYPES_FILE, load_work_types};
+use crate::validation::lint::{LintIssue, LintLevel, lint_document, load_lint_config};
 use crate::validation::references::{
     IssueSeverity, ReferenceIssueKind, ReferenceSource, ReferenceValidationIssue,
     ReferenceValidationOptions, ReferenceValidator,
 };
-use crate::validation::{ValidationTag, validate_compliance};
-use crate::workspace::{FilesystemWorkspaceLocator, WorkspaceLocator};
+use crate::validation::unfilled::TemplateScaffolding;
+use crate::validation::{ComplianceMetrics, ValidationTag, validate_compliance};
+use crate::workspace::{FilesystemWorkspaceLocator, WorkspaceLocator, WorkspacePaths};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct WorkspaceStatusConfig {
@@ -19,6 +29,9 @@ pub struct WorkspaceStatusConfig {
     pub cycles: bool,
     pub compliance: bool,
     pub scratchpads: bool,
+    /// Lint specifications with the rules in `.specman/lint.yaml`.
+    #[serde(default = "default_true")]
+    pub lint: bool,
     #[serde(skip)]
     pub reference_options: Option<ReferenceValidationOptions>,
 }
@@ -31,11 +44,36 @@ impl Default for WorkspaceStatusConfig {
             cycles: true,
             compliance: true,
             scratchpads: true,
+            lint: true,
             reference_options: None,
         }
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn serialize_artifacts<S>(
+    artifacts: &BTreeMap<ArtifactId, ArtifactStatus>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(artifacts)
+}
+
+fn deserialize_artifacts<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<ArtifactId, ArtifactStatus>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pairs = Vec::<(ArtifactId, ArtifactStatus)>::deserialize(deserializer)?;
+    Ok(pairs.into_iter().collect())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum StatusResult {
     Pass,
@@ -53,11 +91,17 @@ pub struct WorkspaceStatusReport {
     /// Status specifically for scratch pad artifacts.
     pub scratchpad_status: StatusResult,
 
-    /// Detailed status per artifact.
+    /// Detailed status per artifact, serialized as `[id, status]` pairs because JSON object
+    /// keys must be strings.
+    #[serde(
+        serialize_with = "serialize_artifacts",
+        deserialize_with = "deserialize_artifacts"
+    )]
+    #[schemars(with = "Vec<(ArtifactId, ArtifactStatus)>")]
     pub artifacts: BTreeMap<ArtifactId, ArtifactStatus>,
 
-    /// Global errors that aren't tied to a specific artifact (e.g. cycles).
-    pub cycle_errors: Vec<String>,
+    /// Dependency cycles across the workspace, with the front-matter entry behind each edge.
+    pub cycle_errors: Vec<DependencyCycle>,
 
     /// Global structure errors (e.g., failure to build index).
     pub structure_errors: Vec<String>,
@@ -80,8 +124,29 @@ pub struct ArtifactStatus {
     /// Orphaned compliance tags (for implementations).
     pub compliance_orphans: Vec<ValidationTag>,
 
+    /// Covered constraint groups and the tags that cover them (for implementations).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub compliance_coverage: BTreeMap<String, Vec<ValidationTag>>,
+
+    /// Coverage figures for the compliance run (implementations only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compliance_metrics: Option<ComplianceMetrics>,
+
+    /// Uncovered constraint groups excused by an active waiver (implementations only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compliance_waived: Vec<Waiver>,
+
+    /// Violations of the implementation's compliance policy, including expired waivers. Missing groups only fail the
+    /// artifact through these, so coverage above the declared threshold passes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compliance_errors: Vec<String>,
+
     /// Resolved scan root used for compliance validation (implementations only).
     pub compliance_scan_root: Option<String>,
+
+    /// Lint rule violations (specifications only). Only `error`-level issues fail the artifact.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lint_issues: Vec<LintIssue>,
 }
 
 impl ArtifactStatus {
@@ -91,7 +156,12 @@ impl ArtifactStatus {
             reference_errors: Vec::new(),
             compliance_missing: Vec::new(),
             compliance_orphans: Vec::new(),
+            compliance_coverage: BTreeMap::new(),
+            compliance_metrics: None,
+            compliance_waived: Vec::new(),
+            compliance_errors: Vec::new(),
             compliance_scan_root: None,
+            lint_issues: Vec::new(),
         }
     }
 
@@ -101,7 +171,18 @@ impl ArtifactStatus {
                 .reference_errors
                 .iter()
                 .all(|e| e.severity != IssueSeverity::Error)
-            && self.compliance_missing.is_empty()
+            && !self.compliance_failed()
+            && self
+                .lint_issues
+                .iter()
+                .all(|issue| issue.level != LintLevel::Error)
+    }
+
+    /// True when the compliance policy is violated or compliance could not be evaluated
+    /// (reported through `compliance_missing` without a scan root).
+    pub fn compliance_failed(&self) -> bool {
+        !self.compliance_errors.is_empty()
+            || (self.compliance_scan_root.is_none() && !self.compliance_missing.is_empty())
     }
 }
 
@@ -118,14 +199,23 @@ pub fn validate_workspace_status(
     let locator = Arc::new(FilesystemWorkspaceLocator::new(workspace_root.clone()));
     let mapper = FilesystemDependencyMapper::new(locator.clone());
     let workspace = locator.workspace()?;
+    status_with_graph(&workspace, mapper.dependency_graph(), &config)
+}
 
+/// Runs every enabled check against the inventory held by `graph`.
+pub(crate) fn status_with_graph<L: WorkspaceLocator>(
+    workspace: &WorkspacePaths,
+    graph: &DependencyGraphServices<L>,
+    config: &WorkspaceStatusConfig,
+) -> Result<WorkspaceStatusReport, SpecmanError> {
     let mut artifacts = BTreeMap::new();
     let mut global_structure_errors = Vec::new();
 
     // We get the inventory. Note that we rely on the method .
     // We assume that the inventory contains all artifacts even if they have some errors
     // (though severe structure errors might exclude them,  will capture metadata errors).
-    let inventory = mapper.dependency_graph().inventory_snapshot()?;
+    let inventory = graph.inventory_snapshot()?;
+    let templates = TemplateScaffolding::new(workspace);
 
     for entry in inventory.entries.iter() {
         let id = entry.summary.id.clone();
@@ -135,92 +225,232 @@ pub fn validate_workspace_status(
             continue;
         }
 
-        let mut status = ArtifactStatus::new();
+        artifacts.insert(
+            id,
+            check_artifact(workspace, &inventory, &templates, entry, config),
+        );
+    }
 
-        // Structure Check
-        if config.structure {
-            if let Some(error) = entry.summary.metadata.get("metadata_status") {
-                if error != "ok" {
-                    status.structure_errors.push(error.clone());
-                }
+    // Cycle Check
+    let mut cycle_errors = Vec::new();
+    if config.cycles {
+        match graph.detect_cycles() {
+            Ok(cycles) => cycle_errors = cycles,
+            Err(e) => global_structure_errors.push(format!("Cycle detection failed: {}", e)),
+        }
+    }
+
+    Ok(aggregate_status(
+        artifacts,
+        cycle_errors,
+        global_structure_errors,
+        config,
+    ))
+}
+
+/// Runs the per-artifact checks (structure, references, compliance, lint) for one inventory entry.
+/// `inventory` supplies the versions that the entry's dependency requirements are checked against,
+/// `templates` the templates that unfilled scaffolding is detected against.
+pub(crate) fn check_artifact(
+    workspace: &WorkspacePaths,
+    inventory: &WorkspaceInventorySnapshot,
+    templates: &TemplateScaffolding,
+    entry: &InventoryEntry,
+    config: &WorkspaceStatusConfig,
+) -> ArtifactStatus {
+    let mut status = ArtifactStatus::new();
+
+    // Structure Check
+    if config.structure {
+        if let Some(error) = entry.summary.metadata.get("metadata_status") {
+            if error != "ok" {
+                status.structure_errors.push(error.clone());
             }
-            if let Some(version_error) = entry.summary.metadata.get("version_error") {
-                status
+        }
+        if let Some(version_error) = entry.summary.metadata.get("version_error") {
+            status
+                .structure_errors
+                .push(format!("Invalid version: {}", version_error));
+        }
+        if let Some(dep_errors) = entry.summary.metadata.get("dependency_errors") {
+            status
+                .structure_errors
+                .push(format!("Dependency errors: {}", dep_errors));
+        }
+        for mismatch in inventory.unmet_requirements(entry) {
+            status
+                .structure_errors
+                .push(format!("Version requirement not met: {}", mismatch));
+        }
+        if let Some(path_str) = &entry.summary.resolved_path {
+            match templates.check(entry.summary.id.kind, Path::new(path_str)) {
+                Ok(markers) => status.structure_errors.extend(
+                    markers
+                        .into_iter()
+                        .map(|marker| format!("Unfilled template: {}", marker)),
+                ),
+                Err(e) => status
                     .structure_errors
-                    .push(format!("Invalid version: {}", version_error));
+                    .push(format!("Template check failed: {}", e)),
             }
-            if let Some(dep_errors) = entry.summary.metadata.get("dependency_errors") {
-                status
-                    .structure_errors
-                    .push(format!("Dependency errors: {}", dep_errors));
+            if entry.summary.id.kind == ArtifactKind::ScratchPad {
+                match check_custom_work_type(workspace, Path::new(path_str)) {
+                    Ok(errors) => status.structure_errors.extend(errors),
+                    Err(e) => status
+                        .structure_errors
+                        .push(format!("Work type check failed: {}", e)),
+                }
             }
         }
-
-        artifacts.insert(id, status);
     }
 
     // Reference Check
     if config.references {
-        let validator = if let Some(opts) = config.reference_options {
-            ReferenceValidator::with_mode(&workspace, opts.into())
+        let validator = if let Some(opts) = config.reference_options.clone() {
+            ReferenceValidator::with_mode(workspace, opts.into())
         } else {
-            ReferenceValidator::new(&workspace)
+            ReferenceValidator::new(workspace)
         };
 
-        for (id, status) in artifacts.iter_mut() {
-            if let Some(entry) = inventory.entries.iter().find(|e| e.summary.id == *id) {
-                if let Some(path_str) = &entry.summary.resolved_path {
-                    match validator.validate(path_str) {
-                        Ok(report) => {
-                            status.reference_errors.extend(report.issues);
-                        }
-                        Err(e) => {
-                            status.reference_errors.push(ReferenceValidationIssue {
-                                kind: ReferenceIssueKind::Unknown,
-                                severity: IssueSeverity::Error,
-                                message: e.to_string(),
-                                source: ReferenceSource {
-                                    document: path_str.clone(),
-                                    range: None,
-                                },
-                                destination: None,
-                            });
-                        }
-                    }
+        if let Some(path_str) = &entry.summary.resolved_path {
+            match validator.validate(path_str) {
+                Ok(report) => {
+                    status.reference_errors.extend(report.issues);
+                }
+                Err(e) => {
+                    status.reference_errors.push(ReferenceValidationIssue {
+                        kind: ReferenceIssueKind::Unknown,
+                        severity: IssueSeverity::Error,
+                        message: e.to_string(),
+                        source: ReferenceSource {
+                            document: path_str.clone(),
+                            range: None,
+                        },
+                        destination: None,
+                        suggestion: None,
+                    });
                 }
             }
         }
     }
 
-    // Cycle Check
-    let mut cycle_errors = Vec::new();
-    if config.cycles {
-        match mapper.dependency_graph().detect_cycles() {
-            Ok(cycles) => cycle_errors = cycles,
-            Err(e) => global_structure_errors.push(format!("Cycle detection failed: {}", e)),
+    // Compliance Check
+    if config.compliance {
+        check_compliance(workspace.root(), &entry.summary.id, &mut status);
+    }
+
+    // Lint Check
+    if config.lint
+        && entry.summary.id.kind == ArtifactKind::Specification
+        && let Some(path_str) = &entry.summary.resolved_path
+    {
+        let issues = load_lint_config(workspace)
+            .and_then(|lint| lint_document(workspace, &lint, Path::new(path_str)));
+        match issues {
+            Ok(issues) => status.lint_issues = issues,
+            Err(e) => status.structure_errors.push(format!("Lint failed: {}", e)),
         }
     }
 
-    // Compliance Check
-    if config.compliance {
-        for (id, status) in artifacts.iter_mut() {
-            if id.kind == ArtifactKind::Implementation {
-                match validate_compliance(&workspace_root, id) {
-                    Ok(report) => {
-                        status.compliance_scan_root = Some(report.scan_root.display().to_string());
-                        status.compliance_missing.extend(report.missing);
-                        status.compliance_orphans.extend(report.orphans);
-                    }
-                    Err(e) => {
-                        status
-                            .compliance_missing
-                            .push(format!("Compliance check failed: {}", e));
-                    }
-                }
-            }
+    status
+}
+
+/// Checks a scratch pad with a custom work type against its declaration in
+/// `.specman/work-types.yaml`: the type must be declared and its required metadata set.
+fn check_custom_work_type(
+    workspace: &WorkspacePaths,
+    path: &Path,
+) -> Result<Vec<String>, SpecmanError> {
+    let content = fs::read_to_string(path)?;
+    // Malformed front matter is already reported through `metadata_status`.
+    let Some(front_matter) = split_front_matter(&content)
+        .and_then(|split| ArtifactFrontMatter::from_yaml_str(split.yaml))
+        .ok()
+    else {
+        return Ok(Vec::new());
+    };
+    let Some(ScratchWorkType::Custom(custom)) = front_matter
+        .as_scratch()
+        .and_then(|scratch| scratch.work_type.as_ref())
+    else {
+        return Ok(Vec::new());
+    };
+
+    let registry = load_work_types(workspace)?;
+    let Some(definition) = registry.get(&custom.name) else {
+        return Ok(vec![format!(
+            "Unknown work type `{}`: not declared in .specman/{}",
+            custom.name, WORK_TYPES_FILE
+        )]);
+    };
+    let missing = definition.missing_metadata(&custom.metadata);
+    if missing.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(vec![format!(
+        "Work type `{}` requires metadata: {}",
+        custom.name,
+        missing.join(", ")
+    )])
+}
+
+/// Prefix of the structure errors [`check_compliance`] reports for waivers naming constraint
+/// groups the specification lacks.
+const UNKNOWN_WAIVER_ERROR: &str = "Waiver names unknown constraint group";
+
+/// Replaces the compliance fields of `status`, along with the structure errors for unknown
+/// waivers; a no-op for non-implementation artifacts.
+pub(crate) fn check_compliance(
+    workspace_root: &Path,
+    id: &ArtifactId,
+    status: &mut ArtifactStatus,
+) {
+    if id.kind != ArtifactKind::Implementation {
+        return;
+    }
+
+    status
+        .structure_errors
+        .retain(|error| !error.starts_with(UNKNOWN_WAIVER_ERROR));
+    status.compliance_scan_root = None;
+    status.compliance_missing.clear();
+    status.compliance_orphans.clear();
+    status.compliance_coverage.clear();
+    status.compliance_metrics = None;
+    status.compliance_waived.clear();
+    status.compliance_errors.clear();
+    match validate_compliance(workspace_root, id) {
+        Ok(report) => {
+            status
+                .structure_errors
+                .extend(report.unknown_waivers.iter().map(|waiver| {
+                    format!(
+                        "{UNKNOWN_WAIVER_ERROR} `{}` ({})",
+                        waiver.constraint, waiver.reason
+                    )
+                }));
+            status.compliance_errors = report.policy_violations();
+            status.compliance_scan_root = Some(report.scan_root.display().to_string());
+            status.compliance_missing.extend(report.missing);
+            status.compliance_orphans.extend(report.orphans);
+            status.compliance_metrics = Some(report.metrics);
+            status.compliance_waived = report.waived;
+            status.compliance_coverage = report.coverage;
+        }
+        Err(e) => {
+            status
+                .compliance_missing
+                .push(format!("Compliance check failed: {}", e));
         }
     }
+}
 
+pub(crate) fn aggregate_status(
+    artifacts: BTreeMap<ArtifactId, ArtifactStatus>,
+    cycle_errors: Vec<DependencyCycle>,
+    global_structure_errors: Vec<String>,
+    config: &WorkspaceStatusConfig,
+) -> WorkspaceStatusReport {
     // Aggregate Status
     let spec_impl_program_pass = artifacts
         .iter()
@@ -240,7 +470,7 @@ pub fn validate_workspace_status(
 
     let artifact_count = artifacts.len();
 
-    Ok(WorkspaceStatusReport {
+    WorkspaceStatusReport {
         // Scratch pad status is reported separately and MUST NOT affect global pass/fail.
         global_status: if spec_impl_program_pass {
             StatusResult::Pass
@@ -261,5 +491,5 @@ pub fn validate_workspace_status(
         cycle_errors,
         structure_errors: global_structure_errors,
         artifact_count,
-    })
+    }
 }
@@ -0,0 +1,339 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::ArtifactKind;
+use crate::metadata::frontmatter::{
+    ArtifactFrontMatter, ScratchWorkType, ScratchWorkTypeKind, split_front_matter,
+};
+use crate::templates::catalog::TemplateCatalog;
+use crate::templates::engine::{TemplateDescriptor, TemplateLocator, TemplateScenario};
+use crate::validation::lint::BodyLines;
+use crate::workspace::WorkspacePaths;
+
+/// Longest excerpt of a guard comment quoted in a report.
+const EXCERPT_CHARS: usize = 60;
+
+/// The kind of template scaffolding left behind in an artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnfilledKind {
+    /// An HTML comment opening with one of the descriptor's unfilled markers.
+    GuardComment,
+    /// A Handlebars expression that was never rendered.
+    Token,
+    /// A template section whose heading and guidance were copied without changes.
+    PlaceholderSection,
+}
+
+/// One piece of template scaffolding found in an artifact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UnfilledMarker {
+    pub kind: UnfilledKind,
+    /// 1-based line in the artifact file.
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for UnfilledMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            UnfilledKind::GuardComment => write!(f, "template guard comment `{}`", self.text)?,
+            UnfilledKind::Token => write!(f, "unrendered template token `{}`", self.text)?,
+            UnfilledKind::PlaceholderSection => write!(
+                f,
+                "placeholder section `{}` copied from the template",
+                self.text
+            )?,
+        }
+        write!(f, " at line {}", self.line)
+    }
+}
+
+/// Resolves the template the artifact at `path` was created from and reports the scaffolding
+/// it still contains. Draft scratch pads and undeclared work types have no template, so only
+/// guard comments and tokens are checked for them.
+pub fn check_unfilled_template(
+    workspace: &WorkspacePaths,
+    kind: ArtifactKind,
+    path: &Path,
+) -> Result<Vec<UnfilledMarker>, SpecmanError> {
+    let content = fs::read_to_string(path)?;
+    let template = match template_scenario(kind, &content) {
+        Some(scenario) => load_template(&TemplateCatalog::new(workspace.clone()), scenario)?,
+        None => None,
+    };
+    Ok(unfilled_in(&content, template.as_ref()))
+}
+
+/// The templates of one status run, each resolved and read at most once per scenario no
+/// matter how many artifacts were created from it.
+pub(crate) struct TemplateScaffolding {
+    catalog: TemplateCatalog,
+    templates: RefCell<BTreeMap<TemplateScenario, Result<Option<ScenarioTemplate>, String>>>,
+}
+
+impl TemplateScaffolding {
+    pub(crate) fn new(workspace: &WorkspacePaths) -> Self {
+        Self {
+            catalog: TemplateCatalog::new(workspace.clone()),
+            templates: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// [`check_unfilled_template`] against the templates resolved so far; failures are
+    /// reported as messages since they repeat for every artifact sharing the template.
+    pub(crate) fn check(
+        &self,
+        kind: ArtifactKind,
+        path: &Path,
+    ) -> Result<Vec<UnfilledMarker>, String> {
+        let content =
+            fs::read_to_string(path).map_err(|err| SpecmanError::from(err).to_string())?;
+        let Some(scenario) = template_scenario(kind, &content) else {
+            return Ok(unfilled_in(&content, None));
+        };
+        let mut templates = self.templates.borrow_mut();
+        let template = templates
+            .entry(scenario)
+            .or_insert_with_key(|scenario| {
+                load_template(&self.catalog, scenario.clone()).map_err(|err| err.to_string())
+            })
+            .as_ref()
+            .map_err(Clone::clone)?;
+        Ok(unfilled_in(&content, template.as_ref()))
+    }
+}
+
+/// A resolved template and, for templates on disk, its content.
+struct ScenarioTemplate {
+    descriptor: TemplateDescriptor,
+    content: Option<String>,
+}
+
+fn template_scenario(kind: ArtifactKind, content: &str) -> Option<TemplateScenario> {
+    match kind {
+        ArtifactKind::Specification => Some(TemplateScenario::Specification),
+        ArtifactKind::Implementation => Some(TemplateScenario::Implementation),
+        ArtifactKind::ScratchPad => scratch_work_type(content)
+            .filter(|work_type| work_type.kind() != ScratchWorkTypeKind::Draft)
+            .map(|work_type| TemplateScenario::WorkType(work_type.name().to_string())),
+    }
+}
+
+/// Resolves and reads the template for `scenario`; `None` for undeclared work types.
+fn load_template(
+    catalog: &TemplateCatalog,
+    scenario: TemplateScenario,
+) -> Result<Option<ScenarioTemplate>, SpecmanError> {
+    let resolved = match catalog.resolve(scenario) {
+        Ok(resolved) => resolved,
+        Err(SpecmanError::UnknownWorkType(_)) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let content = match &resolved.descriptor.locator {
+        TemplateLocator::FilePath(template_path) => Some(fs::read_to_string(template_path)?),
+        TemplateLocator::Url(_) => None,
+    };
+    Ok(Some(ScenarioTemplate {
+        descriptor: resolved.descriptor,
+        content,
+    }))
+}
+
+fn unfilled_in(content: &str, template: Option<&ScenarioTemplate>) -> Vec<UnfilledMarker> {
+    match template {
+        Some(template) => find_unfilled(content, &template.descriptor, template.content.as_deref()),
+        None => find_unfilled(content, &TemplateDescriptor::default(), None),
+    }
+}
+
+/// Reports guard comments declared by `descriptor`, unrendered `{{...}}` expressions, and
+/// sections of `template` whose guidance comments were copied verbatim into `content`.
+/// Fenced code blocks and inline code spans are ignored. Markers inside a placeholder section
+/// are folded into the section.
+pub fn find_unfilled(
+    content: &str,
+    descriptor: &TemplateDescriptor,
+    template: Option<&str>,
+) -> Vec<UnfilledMarker> {
+    let body = BodyLines::new(content);
+    let sections = match template {
+        Some(template) => placeholder_sections(&body, &BodyLines::new(template)),
+        None => Vec::new(),
+    };
+    let in_section = |line: usize| {
+        sections
+            .iter()
+            .any(|(start, end)| (*start..*end).contains(&line))
+    };
+
+    let mut found: Vec<UnfilledMarker> = sections
+        .iter()
+        .map(|(start, _)| UnfilledMarker {
+            kind: UnfilledKind::PlaceholderSection,
+            line: *start,
+            text: line_text(&body, *start).trim().to_string(),
+        })
+        .collect();
+    for (line_no, line, fenced) in &body.lines {
+        if *fenced || in_section(*line_no) {
+            continue;
+        }
+        let prose: String = line.split('`').step_by(2).collect::<Vec<_>>().join(" ");
+        for (start, _) in prose.match_indices("<!--") {
+            let comment = prose[start + 4..].trim_start();
+            if descriptor
+                .unfilled_markers
+                .iter()
+                .any(|marker| comment.starts_with(marker.as_str()))
+            {
+                found.push(UnfilledMarker {
+                    kind: UnfilledKind::GuardComment,
+                    line: *line_no,
+                    text: excerpt(&prose[start..]),
+                });
+            }
+        }
+        let mut rest = prose.as_str();
+        while let Some(open) = rest.find("{{") {
+            let Some(close) = rest[open..].find("}}") else {
+                break;
+            };
+            found.push(UnfilledMarker {
+                kind: UnfilledKind::Token,
+                line: *line_no,
+                text: rest[open..open + close + 2].to_string(),
+            });
+            rest = &rest[open + close + 2..];
+        }
+    }
+    found.sort_by_key(|marker| marker.line);
+    found
+}
+
+/// `(heading line, end line)` ranges of document sections that match a template section
+/// carrying an HTML comment, heading and body alike.
+fn placeholder_sections(body: &BodyLines<'_>, template: &BodyLines<'_>) -> Vec<(usize, usize)> {
+    let guided: Vec<(String, String)> = sections(template)
+        .into_iter()
+        .map(|(start, end)| {
+            (
+                line_text(template, start).trim().to_string(),
+                section_body(template, start, end),
+            )
+        })
+        .filter(|(_, text)| text.contains("<!--"))
+        .collect();
+
+    sections(body)
+        .into_iter()
+        .filter(|(start, end)| {
+            let heading = line_text(body, *start).trim();
+            let text = section_body(body, *start, *end);
+            guided
+                .iter()
+                .any(|(candidate, guidance)| candidate == heading && *guidance == text)
+        })
+        .collect()
+}
+
+/// `(heading line, end line)` for every heading, where the section runs until the next heading.
+fn sections(lines: &BodyLines<'_>) -> Vec<(usize, usize)> {
+    let end = lines.lines.last().map_or(0, |(line_no, _, _)| line_no + 1);
+    lines
+        .headings
+        .iter()
+        .enumerate()
+        .map(|(idx, start)| (*start, lines.headings.get(idx + 1).copied().unwrap_or(end)))
+        .collect()
+}
+
+/// Non-blank lines between a heading and the end of its section, trimmed.
+fn section_body(lines: &BodyLines<'_>, start: usize, end: usize) -> String {
+    lines
+        .lines
+        .iter()
+        .filter(|(line_no, _, _)| *line_no > start && *line_no < end)
+        .map(|(_, line, _)| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn line_text<'a>(lines: &BodyLines<'a>, line_no: usize) -> &'a str {
+    lines
+        .lines
+        .iter()
+        .find(|(candidate, _, _)| *candidate == line_no)
+        .map_or("", |(_, line, _)| line)
+}
+
+fn excerpt(comment: &str) -> String {
+    let comment = comment.trim();
+    if comment.chars().count() <= EXCERPT_CHARS {
+        return comment.to_string();
+    }
+    let mut short: String = comment.chars().take(EXCERPT_CHARS).collect();
+    short.push('…');
+    short
+}
+
+fn scratch_work_type(content: &str) -> Option<ScratchWorkType> {
+    let split = split_front_matter(content).ok()?;
+    let front_matter = ArtifactFrontMatter::from_yaml_str(split.yaml).ok()?;
+    front_matter.as_scratch()?.work_type.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "---\nname: {{output_name}}\n---\n\n# Title\n\n## Concepts\n\n### Concept: Placeholder\n\n<!-- AI INSTRUCTIONS: Describe the concept. -->\n\n## Notes\n\nFree text.\n";
+
+    #[test]
+    fn reports_guard_comments_tokens_and_placeholder_sections() {
+        let content = "---\nname: core\n---\n\n# Core\n\n<!-- AI TODO: Fill in the scope. -->\n\nOwned by {{owner}}, see `{{not_a_token}}`.\n\n## Concepts\n\n### Concept: Placeholder\n\n<!-- AI INSTRUCTIONS: Describe the concept. -->\n\n## Notes\n\nFree text.\n\n```text\n{{fenced}}\n```\n";
+        let found = find_unfilled(content, &TemplateDescriptor::default(), Some(TEMPLATE));
+
+        assert_eq!(
+            found
+                .iter()
+                .map(|marker| (marker.kind, marker.line, marker.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    UnfilledKind::GuardComment,
+                    7,
+                    "<!-- AI TODO: Fill in the scope. -->"
+                ),
+                (UnfilledKind::Token, 9, "{{owner}}"),
+                (
+                    UnfilledKind::PlaceholderSection,
+                    13,
+                    "### Concept: Placeholder"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn edited_sections_and_custom_markers() {
+        let descriptor = TemplateDescriptor {
+            unfilled_markers: vec!["TBD".into()],
+            ..TemplateDescriptor::default()
+        };
+        let content = "---\nname: core\n---\n\n# Core\n\n<!-- AI TODO: not a marker here -->\n\n### Concept: Placeholder\n\nRoots are directories.\n\n<!-- TBD -->\n";
+        let found = find_unfilled(content, &descriptor, Some(TEMPLATE));
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, UnfilledKind::GuardComment);
+        assert_eq!(found[0].line, 13);
+    }
+}
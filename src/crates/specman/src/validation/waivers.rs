@@ -0,0 +1,57 @@
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::core::error::SpecmanError;
+use crate::metadata::waiver::Waiver;
+use crate::workspace::WorkspacePaths;
+
+/// Workspace-wide waiver file, relative to `.specman/`.
+pub const WAIVERS_FILE: &str = "waivers.yaml";
+
+#[derive(Debug, Default, Deserialize)]
+struct WaiverFile {
+    #[serde(default)]
+    waivers: Vec<Waiver>,
+}
+
+/// Reads `.specman/waivers.yaml`; a missing file yields no waivers.
+pub fn load_workspace_waivers(workspace: &WorkspacePaths) -> Result<Vec<Waiver>, SpecmanError> {
+    let path = workspace.dot_specman().join(WAIVERS_FILE);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path)?;
+    let file: WaiverFile = serde_yaml::from_str(&content)
+        .map_err(|err| SpecmanError::Serialization(format!("invalid {}: {err}", path.display())))?;
+    Ok(file.waivers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::waiver::WaiverDate;
+    use tempfile::tempdir;
+
+    #[test]
+    fn loads_workspace_waivers_and_scopes_them() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let workspace = WorkspacePaths::new(root.clone(), root.join(".specman"));
+        assert!(load_workspace_waivers(&workspace).unwrap().is_empty());
+
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::write(
+            root.join(".specman/waivers.yaml"),
+            "waivers:\n  - constraint: concept-alpha.one\n    reason: next release\n    expires: 2020-01-31\n    implementation: core-rs\n",
+        )
+        .unwrap();
+
+        let waivers = load_workspace_waivers(&workspace).unwrap();
+        assert_eq!(waivers.len(), 1);
+        assert!(waivers[0].applies_to("core-rs"));
+        assert!(!waivers[0].applies_to("core-go"));
+        assert!(waivers[0].is_expired(WaiverDate::parse("2020-02-01").unwrap()));
+        assert!(!waivers[0].is_expired(WaiverDate::parse("2020-01-31").unwrap()));
+    }
+}
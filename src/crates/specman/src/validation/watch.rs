@@ -0,0 +1,663 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::{ArtifactId, ArtifactKind, DependencyGraphServices};
+use crate::index::{RelationshipKind, WorkspaceIndex};
+use crate::validation::lint::{LINT_FILE, LintLevel};
+use crate::validation::references::IssueSeverity;
+use crate::validation::status::{
+    StatusResult, WorkspaceStatusConfig, WorkspaceStatusReport, aggregate_status, check_artifact,
+    check_compliance, status_with_graph,
+};
+use crate::validation::unfilled::TemplateScaffolding;
+use crate::validation::waivers::WAIVERS_FILE;
+use crate::watch::{FileWatcher, NotifyFileWatcher, WatchDelta, WorkspaceWatcher};
+use crate::workspace::{
+    FilesystemWorkspaceLocator, WorkspaceLocator, WorkspacePaths, normalize_workspace_path,
+    workspace_relative_path,
+};
+
+/// Check family that produced a [`StatusIssue`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusCheck {
+    Structure,
+    References,
+    Cycles,
+    Compliance,
+    Lint,
+}
+
+/// One issue from a [`WorkspaceStatusReport`], flattened so runs can be compared.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct StatusIssue {
+    /// Artifact the issue belongs to; `None` for workspace-wide issues such as cycles.
+    pub artifact: Option<ArtifactId>,
+    pub check: StatusCheck,
+    /// Whether the issue fails the artifact (diagnostics and orphan tags do not).
+    pub blocking: bool,
+    pub message: String,
+}
+
+impl WorkspaceStatusReport {
+    /// Flattens every reported issue into a comparable set.
+    pub fn issues(&self) -> BTreeSet<StatusIssue> {
+        let mut issues = BTreeSet::new();
+        let global = |check, message: &String| StatusIssue {
+            artifact: None,
+            check,
+            blocking: true,
+            message: message.clone(),
+        };
+        issues.extend(
+            self.structure_errors
+                .iter()
+                .map(|msg| global(StatusCheck::Structure, msg)),
+        );
+        issues.extend(
+            self.cycle_errors
+                .iter()
+                .map(|cycle| global(StatusCheck::Cycles, &cycle.to_string())),
+        );
+
+        for (id, status) in &self.artifacts {
+            let issue = |check, blocking, message: String| StatusIssue {
+                artifact: Some(id.clone()),
+                check,
+                blocking,
+                message,
+            };
+            for error in &status.structure_errors {
+                issues.insert(issue(StatusCheck::Structure, true, error.clone()));
+            }
+            for error in &status.reference_errors {
+                let location = error
+                    .source
+                    .range
+                    .as_ref()
+                    .map(|r| format!(":{}:{}", r.start.line, r.start.column))
+                    .unwrap_or_default();
+                issues.insert(issue(
+                    StatusCheck::References,
+                    error.severity == IssueSeverity::Error,
+                    format!("{}{}", error.message, location),
+                ));
+            }
+            // Missing groups only block when compliance could not run at all; otherwise the
+            // policy decides through `compliance_errors`.
+            let missing_blocks = status.compliance_scan_root.is_none();
+            for missing in &status.compliance_missing {
+                issues.insert(issue(
+                    StatusCheck::Compliance,
+                    missing_blocks,
+                    format!("Missing: {missing}"),
+                ));
+            }
+            for error in &status.compliance_errors {
+                issues.insert(issue(StatusCheck::Compliance, true, error.clone()));
+            }
+            for orphan in &status.compliance_orphans {
+                issues.insert(issue(
+                    StatusCheck::Compliance,
+                    false,
+                    format!(
+                        "Orphan tag: {} at {}:{}",
+                        orphan.identifier,
+                        orphan.location.file_path.display(),
+                        orphan.location.line_number
+                    ),
+                ));
+            }
+            for lint in &status.lint_issues {
+                issues.insert(issue(
+                    StatusCheck::Lint,
+                    lint.level == LintLevel::Error,
+                    lint.to_string(),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+/// Issues introduced and resolved between two status runs.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StatusDiff {
+    pub previous_status: StatusResult,
+    pub global_status: StatusResult,
+    /// Artifacts whose checks were re-run to produce this diff.
+    pub rechecked: Vec<ArtifactId>,
+    pub introduced: Vec<StatusIssue>,
+    pub resolved: Vec<StatusIssue>,
+}
+
+impl StatusDiff {
+    pub fn between(
+        previous: &WorkspaceStatusReport,
+        current: &WorkspaceStatusReport,
+        rechecked: Vec<ArtifactId>,
+    ) -> Self {
+        let before = previous.issues();
+        let after = current.issues();
+        Self {
+            previous_status: previous.global_status,
+            global_status: current.global_status,
+            rechecked,
+            introduced: after.difference(&before).cloned().collect(),
+            resolved: before.difference(&after).cloned().collect(),
+        }
+    }
+
+    /// True when neither the issue set nor the overall status changed.
+    pub fn is_empty(&self) -> bool {
+        self.introduced.is_empty()
+            && self.resolved.is_empty()
+            && self.previous_status == self.global_status
+    }
+}
+
+/// Keeps a [`WorkspaceStatusReport`] current as files change, re-running only the checks
+/// affected by each batch of edits.
+///
+/// Changed artifacts, their dependents, and artifacts whose bodies link into them get
+/// structure, reference, compliance, and lint checks again; edits to source files only re-run
+/// compliance for implementations whose scan root contains them. Cycle detection runs again
+/// whenever an artifact file changed.
+pub struct StatusWatcher {
+    config: WorkspaceStatusConfig,
+    watcher: WorkspaceWatcher<Arc<FilesystemWorkspaceLocator>>,
+    report: WorkspaceStatusReport,
+    paths: BTreeMap<ArtifactId, PathBuf>,
+    /// Structure index the current report was computed from.
+    index: Arc<WorkspaceIndex>,
+}
+
+impl StatusWatcher {
+    /// Runs a full status pass and starts consuming changes from `source`.
+    pub fn new(
+        workspace_root: PathBuf,
+        config: WorkspaceStatusConfig,
+        source: Box<dyn FileWatcher>,
+    ) -> Result<Self, SpecmanError> {
+        let locator = Arc::new(FilesystemWorkspaceLocator::new(workspace_root));
+        let workspace = locator.workspace()?;
+        let graph = Arc::new(DependencyGraphServices::new(locator));
+        let watcher = WorkspaceWatcher::new(workspace.clone(), graph.clone(), source)?;
+        let report = status_with_graph(&workspace, &graph, &config)?;
+        let index = watcher.current_index();
+
+        let mut status = Self {
+            config,
+            watcher,
+            report,
+            paths: BTreeMap::new(),
+            index,
+        };
+        status.paths = status.artifact_paths()?;
+        Ok(status)
+    }
+
+    /// Watches the workspace with the platform notifier.
+    pub fn with_notify(
+        workspace_root: PathBuf,
+        config: WorkspaceStatusConfig,
+    ) -> Result<Self, SpecmanError> {
+        let workspace = FilesystemWorkspaceLocator::new(workspace_root.clone()).workspace()?;
+        let source = NotifyFileWatcher::new(workspace.root())?;
+        Self::new(workspace_root, config, Box::new(source))
+    }
+
+    pub fn report(&self) -> &WorkspaceStatusReport {
+        &self.report
+    }
+
+    /// Waits up to `timeout` for changes; returns `None` when nothing changed.
+    pub fn poll(&mut self, timeout: Duration) -> Result<Option<StatusDiff>, SpecmanError> {
+        let delta = self.watcher.poll(timeout)?;
+        if delta.is_empty() {
+            return Ok(None);
+        }
+        self.recheck(&delta).map(Some)
+    }
+
+    fn recheck(&mut self, delta: &WatchDelta) -> Result<StatusDiff, SpecmanError> {
+        let workspace = self.watcher.workspace().clone();
+        let graph = self.watcher.graph().clone();
+        let inventory = graph.inventory_snapshot()?;
+        let current_paths = self.artifact_paths()?;
+
+        let changed: Vec<PathBuf> = delta
+            .changes
+            .iter()
+            .map(|change| normalize_workspace_path(&change.path))
+            .collect();
+
+        // Artifacts that appeared, disappeared, or whose files (or directories) changed.
+        let mut touched: BTreeSet<ArtifactId> = BTreeSet::new();
+        for (id, path) in current_paths.iter().chain(self.paths.iter()) {
+            let moved = self.paths.get(id) != current_paths.get(id);
+            if moved || changed.iter().any(|change| path.starts_with(change)) {
+                touched.insert(id.clone());
+            }
+        }
+
+        // Dependents may gain or lose reference and dependency errors.
+        let mut full = touched.clone();
+        let mut documents = BTreeSet::new();
+        for id in &touched {
+            let Some(path) = current_paths.get(id).or_else(|| self.paths.get(id)) else {
+                continue;
+            };
+            for dependent in inventory.dependents_of(path) {
+                full.insert(dependent.summary.id);
+            }
+            documents.extend(workspace_relative_path(workspace.root(), path));
+        }
+
+        // So may artifacts whose bodies link into a touched document without declaring it. A
+        // link that just broke only shows in the previous index, one that was just fixed only
+        // in the current one.
+        let index = self.watcher.current_index();
+        let sources = linking_documents([&self.index, &index], &documents);
+        for (id, path) in current_paths.iter() {
+            if workspace_relative_path(workspace.root(), path)
+                .is_some_and(|document| sources.contains(document.as_str()))
+            {
+                full.insert(id.clone());
+            }
+        }
+
+        // Lint configuration applies to every specification.
+        if changed.contains(&workspace_file(&workspace, LINT_FILE)) {
+            full.extend(
+                current_paths
+                    .keys()
+                    .filter(|id| id.kind == ArtifactKind::Specification)
+                    .cloned(),
+            );
+        }
+
+        // Source edits only matter to compliance of implementations scanning them; workspace
+        // waivers may apply to any implementation.
+        let waivers_changed = changed.contains(&workspace_file(&workspace, WAIVERS_FILE));
+        let mut compliance_only: BTreeSet<ArtifactId> = BTreeSet::new();
+        if self.config.compliance {
+            for (id, status) in &self.report.artifacts {
+                if full.contains(id) || id.kind != ArtifactKind::Implementation {
+                    continue;
+                }
+                let scans_change = status.compliance_scan_root.as_ref().is_some_and(|root| {
+                    let scan_root = normalize_workspace_path(Path::new(root));
+                    changed.iter().any(|path| path.starts_with(&scan_root))
+                });
+                if waivers_changed || scans_change {
+                    compliance_only.insert(id.clone());
+                }
+            }
+        }
+
+        let templates = TemplateScaffolding::new(&workspace);
+        let mut artifacts = self.report.artifacts.clone();
+        artifacts.retain(|id, _| current_paths.contains_key(id));
+        for entry in inventory.entries.iter() {
+            let id = &entry.summary.id;
+            if !current_paths.contains_key(id) {
+                continue;
+            }
+            if full.contains(id) {
+                let status =
+                    check_artifact(&workspace, &inventory, &templates, entry, &self.config);
+                artifacts.insert(id.clone(), status);
+            } else if compliance_only.contains(id)
+                && let Some(status) = artifacts.get_mut(id)
+            {
+                check_compliance(workspace.root(), id, status);
+            }
+        }
+
+        let (cycle_errors, structure_errors) = if self.config.cycles && !touched.is_empty() {
+            match graph.detect_cycles() {
+                Ok(cycles) => (cycles, Vec::new()),
+                Err(e) => (Vec::new(), vec![format!("Cycle detection failed: {}", e)]),
+            }
+        } else {
+            (
+                self.report.cycle_errors.clone(),
+                self.report.structure_errors.clone(),
+            )
+        };
+
+        let report = aggregate_status(artifacts, cycle_errors, structure_errors, &self.config);
+        let rechecked = full
+            .into_iter()
+            .chain(compliance_only)
+            .filter(|id| current_paths.contains_key(id))
+            .collect();
+        let diff = StatusDiff::between(&self.report, &report, rechecked);
+
+        self.report = report;
+        self.paths = current_paths;
+        self.index = index;
+        Ok(diff)
+    }
+
+    /// Artifact file paths from the live inventory, honoring the scratch pad toggle.
+    fn artifact_paths(&self) -> Result<BTreeMap<ArtifactId, PathBuf>, SpecmanError> {
+        let inventory = self.watcher.graph().inventory_snapshot()?;
+        Ok(inventory
+            .entries
+            .iter()
+            .filter(|entry| {
+                self.config.scratchpads || entry.summary.id.kind != ArtifactKind::ScratchPad
+            })
+            .filter_map(|entry| {
+                let path = entry.summary.resolved_path.as_ref()?;
+                Some((
+                    entry.summary.id.clone(),
+                    normalize_workspace_path(Path::new(path)),
+                ))
+            })
+            .collect())
+    }
+}
+
+/// Normalized path of a workspace configuration file under `.specman/`.
+fn workspace_file(workspace: &WorkspacePaths, name: &str) -> PathBuf {
+    normalize_workspace_path(&workspace.dot_specman().join(name))
+}
+
+/// Documents (workspace-relative paths) with a resolved heading, constraint, or file link into
+/// one of `documents` in any of `indexes`.
+fn linking_documents<'a>(
+    indexes: [&'a WorkspaceIndex; 2],
+    documents: &BTreeSet<String>,
+) -> BTreeSet<&'a str> {
+    indexes
+        .into_iter()
+        .flat_map(|index| index.relationships.iter())
+        .filter(|edge| {
+            matches!(
+                edge.kind,
+                RelationshipKind::HeadingToHeading
+                    | RelationshipKind::HeadingToFile
+                    | RelationshipKind::ConstraintToHeading
+            )
+        })
+        .filter(|edge| documents.contains(document_of(&edge.to)))
+        .map(|edge| document_of(&edge.from))
+        .collect()
+}
+
+/// Strips the `#slug` or `!group` suffix from a relationship endpoint.
+fn document_of(endpoint: &str) -> &str {
+    endpoint
+        .split_once(['#', '!'])
+        .map_or(endpoint, |(document, _)| document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watch::{FileChange, FileChangeKind, ManualFileWatcher};
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn local_config() -> WorkspaceStatusConfig {
+        WorkspaceStatusConfig {
+            reference_options: Some(crate::validation::references::ReferenceValidationOptions {
+                https: crate::validation::references::HttpsValidationOptions {
+                    mode: crate::validation::references::HttpsValidationMode::SyntaxOnly,
+                },
+                transitive: Default::default(),
+            }),
+            ..WorkspaceStatusConfig::default()
+        }
+    }
+
+    fn write(path: PathBuf, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn status_watcher_reports_introduced_and_resolved_issues() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n\
+             !concept-alpha.requirements:\n- MUST work.\n",
+        );
+        write(
+            root.join("impl/core-rs/impl.md"),
+            "---\nname: core-rs\nspec: spec://core\nlocation: ../../src\n---\n# Core RS\n\n\
+             See [alpha](../../spec/core/spec.md#concept-alpha).\n",
+        );
+        write(root.join("src/lib.rs"), "// nothing yet\n");
+
+        let manual = Arc::new(ManualFileWatcher::new());
+        let mut status =
+            StatusWatcher::new(root.clone(), local_config(), Box::new(manual.clone())).unwrap();
+        assert_eq!(status.report().global_status, StatusResult::Fail);
+        assert!(status.poll(Duration::ZERO).unwrap().is_none());
+
+        // Covering the constraint from source re-runs compliance only.
+        write(
+            root.join("src/lib.rs"),
+            "// [ENSURES: concept-alpha.requirements:TEST]\n",
+        );
+        manual.push(FileChange::new(
+            root.join("src/lib.rs"),
+            FileChangeKind::Modified,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert_eq!(diff.global_status, StatusResult::Pass);
+        assert_eq!(diff.rechecked.len(), 1);
+        assert_eq!(diff.rechecked[0].name, "core-rs");
+        assert!(diff.introduced.is_empty());
+        // Both the missing group and the resulting coverage violation are resolved.
+        assert_eq!(diff.resolved.len(), 2);
+        assert!(
+            diff.resolved
+                .iter()
+                .all(|issue| issue.check == StatusCheck::Compliance)
+        );
+
+        // Renaming the heading breaks the implementation's link, a dependent of the spec.
+        write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Beta\n\n\
+             !concept-alpha.requirements:\n- MUST work.\n",
+        );
+        manual.push(FileChange::new(
+            root.join("spec/core/spec.md"),
+            FileChangeKind::Modified,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert_eq!(diff.global_status, StatusResult::Fail);
+        assert!(
+            diff.introduced
+                .iter()
+                .any(|issue| issue.check == StatusCheck::References
+                    && issue
+                        .artifact
+                        .as_ref()
+                        .is_some_and(|id| id.name == "core-rs")),
+            "{diff:?}"
+        );
+        assert!(diff.resolved.is_empty(), "{diff:?}");
+
+        let full =
+            crate::validation::status::validate_workspace_status(root, local_config()).unwrap();
+        assert_eq!(full.issues(), status.report().issues());
+    }
+
+    #[test]
+    fn body_links_recheck_the_linking_artifact() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("spec/a/spec.md"),
+            "---\nname: a\nversion: \"1.0.0\"\n---\n# A\n\n## Heading\n\nText.\n",
+        );
+        write(
+            root.join("spec/b/spec.md"),
+            "---\nname: b\nversion: \"1.0.0\"\n---\n# B\n\nSee [a](../a/spec.md#heading).\n",
+        );
+
+        let manual = Arc::new(ManualFileWatcher::new());
+        let mut status =
+            StatusWatcher::new(root.clone(), local_config(), Box::new(manual.clone())).unwrap();
+        assert_eq!(status.report().global_status, StatusResult::Pass);
+
+        // `b` does not declare `a` as a dependency; only its body links there.
+        write(
+            root.join("spec/a/spec.md"),
+            "---\nname: a\nversion: \"1.0.0\"\n---\n# A\n\n## Renamed\n\nText.\n",
+        );
+        manual.push(FileChange::new(
+            root.join("spec/a/spec.md"),
+            FileChangeKind::Modified,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert!(diff.rechecked.iter().any(|id| id.name == "b"), "{diff:?}");
+        assert_eq!(diff.global_status, StatusResult::Fail);
+        assert!(
+            diff.introduced
+                .iter()
+                .any(|issue| issue.check == StatusCheck::References
+                    && issue.artifact.as_ref().is_some_and(|id| id.name == "b")),
+            "{diff:?}"
+        );
+
+        // Restoring the heading resolves the link again.
+        write(
+            root.join("spec/a/spec.md"),
+            "---\nname: a\nversion: \"1.0.0\"\n---\n# A\n\n## Heading\n\nText.\n",
+        );
+        manual.push(FileChange::new(
+            root.join("spec/a/spec.md"),
+            FileChangeKind::Modified,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert_eq!(diff.global_status, StatusResult::Pass, "{diff:?}");
+        assert!(diff.introduced.is_empty(), "{diff:?}");
+    }
+
+    #[test]
+    fn waiver_changes_recheck_compliance() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n\
+             !concept-alpha.requirements:\n- MUST work.\n",
+        );
+        write(
+            root.join("impl/core-rs/impl.md"),
+            "---\nname: core-rs\nspec: spec://core\nlocation: ../../src\n---\n# Core RS\n",
+        );
+        write(root.join("src/lib.rs"), "// nothing yet\n");
+
+        let manual = Arc::new(ManualFileWatcher::new());
+        let mut status =
+            StatusWatcher::new(root.clone(), local_config(), Box::new(manual.clone())).unwrap();
+        assert_eq!(status.report().global_status, StatusResult::Fail);
+
+        write(
+            root.join(".specman/waivers.yaml"),
+            "waivers:\n  - constraint: concept-alpha.requirements\n    reason: later\n",
+        );
+        manual.push(FileChange::new(
+            root.join(".specman/waivers.yaml"),
+            FileChangeKind::Created,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert_eq!(diff.global_status, StatusResult::Pass, "{diff:?}");
+        assert_eq!(diff.rechecked.len(), 1);
+        assert_eq!(diff.rechecked[0].name, "core-rs");
+        assert!(
+            diff.resolved
+                .iter()
+                .all(|issue| issue.check == StatusCheck::Compliance)
+        );
+    }
+
+    #[test]
+    fn lint_config_changes_recheck_specifications() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n\
+             !concept-alpha.requirements:\n- Alpha works.\n",
+        );
+
+        let manual = Arc::new(ManualFileWatcher::new());
+        let mut status =
+            StatusWatcher::new(root.clone(), local_config(), Box::new(manual.clone())).unwrap();
+        assert_eq!(status.report().global_status, StatusResult::Pass);
+
+        write(
+            root.join(".specman/lint.yaml"),
+            "rules:\n  rfc2119-keywords: error\n",
+        );
+        manual.push(FileChange::new(
+            root.join(".specman/lint.yaml"),
+            FileChangeKind::Created,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert_eq!(diff.global_status, StatusResult::Fail, "{diff:?}");
+        assert_eq!(diff.rechecked.len(), 1);
+        assert_eq!(diff.rechecked[0].name, "core");
+        assert!(
+            diff.introduced
+                .iter()
+                .all(|issue| issue.check == StatusCheck::Lint)
+        );
+    }
+
+    #[test]
+    fn removed_artifacts_resolve_their_issues() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        write(
+            root.join("spec/broken/spec.md"),
+            "---\nname: broken\nversion: \"1.0.0\"\n---\n# Broken\n\n\
+             See [gone](../missing/spec.md).\n",
+        );
+
+        let manual = Arc::new(ManualFileWatcher::new());
+        let mut status =
+            StatusWatcher::new(root.clone(), local_config(), Box::new(manual.clone())).unwrap();
+        assert_eq!(status.report().global_status, StatusResult::Fail);
+
+        fs::remove_dir_all(root.join("spec/broken")).unwrap();
+        manual.push(FileChange::new(
+            root.join("spec/broken"),
+            FileChangeKind::Removed,
+        ));
+        let diff = status.poll(Duration::ZERO).unwrap().expect("diff");
+        assert_eq!(diff.previous_status, StatusResult::Fail);
+        assert_eq!(diff.global_status, StatusResult::Pass);
+        assert!(diff.introduced.is_empty());
+        assert!(!diff.resolved.is_empty());
+        assert!(status.report().artifacts.is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+mod source;
+mod watcher;
+
+pub use source::{FileChange, FileChangeKind, FileWatcher, ManualFileWatcher, NotifyFileWatcher};
+pub use watcher::{WatchDelta, WorkspaceWatcher};
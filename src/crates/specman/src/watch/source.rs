@@ -0,0 +1,261 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use notify::event::{EventKind, ModifyKind};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use parking_lot::Mutex;
+
+use crate::core::error::SpecmanError;
+
+/// How long a burst of filesystem events may keep arriving before a batch is handed out.
+const SETTLE_WINDOW: Duration = Duration::from_millis(50);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Removed,
+    /// The platform dropped events; consumers should rescan `path` and everything below it.
+    Rescan,
+}
+
+/// A single path-level change reported by a [`FileWatcher`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub kind: FileChangeKind,
+}
+
+impl FileChange {
+    pub fn new(path: impl Into<PathBuf>, kind: FileChangeKind) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+/// Source of filesystem change batches.
+///
+/// The watch subsystem only depends on this trait so that the platform watcher can be
+/// replaced (for example by [`ManualFileWatcher`] in tests or embedders that already
+/// receive change notifications from elsewhere).
+pub trait FileWatcher: Send + Sync {
+    /// Waits up to `timeout` for changes and returns them coalesced per path.
+    ///
+    /// An empty batch means nothing changed before the timeout elapsed.
+    fn next_batch(&self, timeout: Duration) -> Result<Vec<FileChange>, SpecmanError>;
+}
+
+impl<W> FileWatcher for Arc<W>
+where
+    W: FileWatcher,
+{
+    fn next_batch(&self, timeout: Duration) -> Result<Vec<FileChange>, SpecmanError> {
+        (**self).next_batch(timeout)
+    }
+}
+
+/// Recursive watcher over a directory tree backed by the platform notifier
+/// (inotify on Linux).
+pub struct NotifyFileWatcher {
+    // Dropping the watcher stops event delivery, so it must live as long as the receiver.
+    _watcher: RecommendedWatcher,
+    events: Mutex<Receiver<notify::Result<Event>>>,
+    root: PathBuf,
+}
+
+impl NotifyFileWatcher {
+    pub fn new(root: impl AsRef<Path>) -> Result<Self, SpecmanError> {
+        let root = root.as_ref().to_path_buf();
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            events: Mutex::new(receiver),
+            root,
+        })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl FileWatcher for NotifyFileWatcher {
+    fn next_batch(&self, timeout: Duration) -> Result<Vec<FileChange>, SpecmanError> {
+        let events = self.events.lock();
+        let mut batch = ChangeBatch::default();
+
+        let first = match events.recv_timeout(timeout) {
+            Ok(event) => event,
+            Err(RecvTimeoutError::Timeout) => return Ok(Vec::new()),
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(SpecmanError::Workspace(format!(
+                    "file watcher for {} stopped",
+                    self.root.display()
+                )));
+            }
+        };
+        batch.record(first, &self.root);
+
+        // Editors tend to write files in several steps; wait for the burst to settle.
+        let deadline = Instant::now() + SETTLE_WINDOW;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            match events.recv_timeout(remaining) {
+                Ok(event) => batch.record(event, &self.root),
+                Err(_) => break,
+            }
+        }
+
+        Ok(batch.into_changes())
+    }
+}
+
+/// In-memory [`FileWatcher`] fed explicitly through [`ManualFileWatcher::push`].
+pub struct ManualFileWatcher {
+    sender: Sender<FileChange>,
+    receiver: Mutex<Receiver<FileChange>>,
+}
+
+impl Default for ManualFileWatcher {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+        }
+    }
+}
+
+impl ManualFileWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, change: FileChange) {
+        // The receiver lives in `self`, so sending cannot fail.
+        let _ = self.sender.send(change);
+    }
+}
+
+impl FileWatcher for ManualFileWatcher {
+    fn next_batch(&self, timeout: Duration) -> Result<Vec<FileChange>, SpecmanError> {
+        let receiver = self.receiver.lock();
+        let mut batch = BTreeMap::new();
+        if let Ok(change) = receiver.recv_timeout(timeout) {
+            batch.insert(change.path, change.kind);
+            while let Ok(change) = receiver.try_recv() {
+                batch.insert(change.path, change.kind);
+            }
+        }
+        Ok(batch
+            .into_iter()
+            .map(|(path, kind)| FileChange { path, kind })
+            .collect())
+    }
+}
+
+#[derive(Default)]
+struct ChangeBatch {
+    changes: BTreeMap<PathBuf, FileChangeKind>,
+}
+
+impl ChangeBatch {
+    fn record(&mut self, event: notify::Result<Event>, root: &Path) {
+        let event = match event {
+            Ok(event) => event,
+            Err(_) => {
+                self.changes
+                    .insert(root.to_path_buf(), FileChangeKind::Rescan);
+                return;
+            }
+        };
+
+        if event.need_rescan() {
+            let path = event
+                .paths
+                .first()
+                .cloned()
+                .unwrap_or_else(|| root.to_path_buf());
+            self.changes.insert(path, FileChangeKind::Rescan);
+            return;
+        }
+
+        let kind = match event.kind {
+            EventKind::Create(_) => FileChangeKind::Created,
+            EventKind::Remove(_) => FileChangeKind::Removed,
+            EventKind::Modify(ModifyKind::Metadata(_)) | EventKind::Access(_) => return,
+            EventKind::Modify(_) | EventKind::Any | EventKind::Other => FileChangeKind::Modified,
+        };
+
+        for path in event.paths {
+            // A rescan request for a path outranks anything more specific.
+            let entry = self.changes.entry(path).or_insert(kind);
+            if *entry != FileChangeKind::Rescan {
+                *entry = kind;
+            }
+        }
+    }
+
+    fn into_changes(self) -> Vec<FileChange> {
+        self.changes
+            .into_iter()
+            .map(|(path, kind)| FileChange { path, kind })
+            .collect()
+    }
+}
+
+fn watch_error(err: notify::Error) -> SpecmanError {
+    SpecmanError::Workspace(format!("file watcher error: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn notify_watcher_reports_file_writes() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let watcher = NotifyFileWatcher::new(&root).unwrap();
+
+        let file = root.join("spec.md");
+        fs::write(&file, "# Spec\n").unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut seen = false;
+        while !seen && Instant::now() < deadline {
+            let batch = watcher.next_batch(Duration::from_millis(250)).unwrap();
+            seen = batch.iter().any(|change| change.path == file);
+        }
+        assert!(seen, "expected a change event for {}", file.display());
+    }
+
+    #[test]
+    fn manual_watcher_coalesces_changes_per_path() {
+        let watcher = ManualFileWatcher::new();
+        watcher.push(FileChange::new("a.md", FileChangeKind::Created));
+        watcher.push(FileChange::new("a.md", FileChangeKind::Modified));
+        watcher.push(FileChange::new("b.md", FileChangeKind::Removed));
+
+        let batch = watcher.next_batch(Duration::ZERO).unwrap();
+        assert_eq!(
+            batch,
+            vec![
+                FileChange::new("a.md", FileChangeKind::Modified),
+                FileChange::new("b.md", FileChangeKind::Removed),
+            ]
+        );
+        assert!(watcher.next_batch(Duration::ZERO).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+
+use crate::core::error::SpecmanError;
+use crate::graph::tree::DependencyGraphServices;
+use crate::index::{IncrementalStructureIndex, StructureDelta, WorkspaceIndex};
+use crate::workspace::{WorkspaceLocator, WorkspacePaths, normalize_workspace_path};
+
+use super::source::{FileChange, FileChangeKind, FileWatcher, NotifyFileWatcher};
+
+/// Result of applying one batch of filesystem changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WatchDelta {
+    /// Raw changes as reported by the underlying [`FileWatcher`].
+    pub changes: Vec<FileChange>,
+    /// Effect of the batch on the structure index.
+    pub structure: StructureDelta,
+    /// Monotonic counter bumped whenever a batch touches the artifact trees.
+    pub generation: u64,
+}
+
+impl WatchDelta {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Long-lived view of a workspace that keeps the structure index and the dependency
+/// inventory current by applying per-file deltas from a [`FileWatcher`].
+///
+/// Readers call [`WorkspaceWatcher::index`], which first drains any pending changes, so
+/// results reflect edits as soon as the watcher has observed them.
+pub struct WorkspaceWatcher<L: WorkspaceLocator> {
+    workspace: WorkspacePaths,
+    graph: Arc<DependencyGraphServices<L>>,
+    source: Box<dyn FileWatcher>,
+    state: RwLock<WatchState>,
+}
+
+struct WatchState {
+    index: IncrementalStructureIndex,
+    generation: u64,
+    stale: bool,
+}
+
+impl<L: WorkspaceLocator> WorkspaceWatcher<L> {
+    /// Builds the initial index and starts consuming changes from `source`.
+    pub fn new(
+        workspace: WorkspacePaths,
+        graph: Arc<DependencyGraphServices<L>>,
+        source: Box<dyn FileWatcher>,
+    ) -> Result<Self, SpecmanError> {
+        let index = IncrementalStructureIndex::build(&workspace)?;
+        Ok(Self {
+            workspace,
+            graph,
+            source,
+            state: RwLock::new(WatchState {
+                index,
+                generation: 0,
+                stale: false,
+            }),
+        })
+    }
+
+    /// Watches the workspace root with the platform notifier.
+    pub fn with_notify(
+        workspace: WorkspacePaths,
+        graph: Arc<DependencyGraphServices<L>>,
+    ) -> Result<Self, SpecmanError> {
+        let source = NotifyFileWatcher::new(workspace.root())?;
+        Self::new(workspace, graph, Box::new(source))
+    }
+
+    pub fn workspace(&self) -> &WorkspacePaths {
+        &self.workspace
+    }
+
+    pub fn graph(&self) -> &Arc<DependencyGraphServices<L>> {
+        &self.graph
+    }
+
+    /// Number of change batches applied so far. Callers can cache derived data keyed by it.
+    pub fn generation(&self) -> Result<u64, SpecmanError> {
+        self.sync()?;
+        Ok(self.state.read().generation)
+    }
+
+    /// The structure index as of the last applied batch, leaving queued changes pending.
+    pub fn current_index(&self) -> Arc<WorkspaceIndex> {
+        self.state.read().index.index()
+    }
+
+    /// Returns the current structure index after applying pending changes.
+    pub fn index(&self) -> Result<Arc<WorkspaceIndex>, SpecmanError> {
+        self.sync()?;
+        Ok(self.state.read().index.index())
+    }
+
+    /// Applies every change that is already queued without waiting for more.
+    pub fn sync(&self) -> Result<WatchDelta, SpecmanError> {
+        self.poll(Duration::ZERO)
+    }
+
+    /// Waits up to `timeout` for the next batch of changes and applies it.
+    pub fn poll(&self, timeout: Duration) -> Result<WatchDelta, SpecmanError> {
+        let changes = self.source.next_batch(timeout)?;
+        self.apply(changes)
+    }
+
+    /// Applies an explicit batch of changes, e.g. for writes the caller performed itself.
+    ///
+    /// Every change is echoed back in the delta, but only paths inside the specification,
+    /// implementation, and scratch pad trees touch the index, the inventory, or the generation.
+    pub fn apply(&self, changes: Vec<FileChange>) -> Result<WatchDelta, SpecmanError> {
+        let paths: Vec<PathBuf> = changes
+            .iter()
+            .filter_map(|change| match change.kind {
+                FileChangeKind::Rescan => Some(self.workspace.root().to_path_buf()),
+                _ if self.is_relevant(&change.path) => Some(change.path.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let mut state = self.state.write();
+        if state.stale {
+            state.index = IncrementalStructureIndex::build(&self.workspace)?;
+            state.stale = false;
+            state.generation += 1;
+        }
+        if paths.is_empty() {
+            return Ok(WatchDelta {
+                changes,
+                structure: StructureDelta::default(),
+                generation: state.generation,
+            });
+        }
+
+        let structure = state.index.apply_changes(&paths)?;
+        state.generation += 1;
+        self.graph.refresh_inventory_paths(&paths)?;
+
+        Ok(WatchDelta {
+            changes,
+            structure,
+            generation: state.generation,
+        })
+    }
+
+    fn is_relevant(&self, path: &Path) -> bool {
+        let path = normalize_workspace_path(path);
+        let artifact_roots = [
+            self.workspace.spec_dir(),
+            self.workspace.impl_dir(),
+            self.workspace.scratchpad_dir(),
+        ];
+        artifact_roots
+            .iter()
+            .map(|root| normalize_workspace_path(root))
+            .any(|root| path.starts_with(&root) || root.starts_with(&path))
+    }
+
+    /// Forces a full rebuild on the next read, for callers that changed the workspace in ways
+    /// they cannot describe as individual paths.
+    pub fn mark_stale(&self) {
+        self.state.write().stale = true;
+        self.graph.invalidate_inventory();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watch::source::ManualFileWatcher;
+    use crate::workspace::FilesystemWorkspaceLocator;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn watcher_applies_changes_to_index_and_inventory() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("workspace");
+        fs::create_dir_all(root.join(".specman")).unwrap();
+        fs::create_dir_all(root.join("spec/core")).unwrap();
+        fs::write(
+            root.join("spec/core/spec.md"),
+            "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n",
+        )
+        .unwrap();
+
+        let locator = FilesystemWorkspaceLocator::new(root.clone());
+        let workspace = locator.workspace().unwrap();
+        let graph = Arc::new(DependencyGraphServices::new(locator));
+        let manual = Arc::new(ManualFileWatcher::new());
+        let watcher =
+            WorkspaceWatcher::new(workspace.clone(), graph.clone(), Box::new(manual.clone()))
+                .unwrap();
+
+        assert_eq!(watcher.index().unwrap().artifacts.len(), 1);
+        assert_eq!(graph.inventory_snapshot().unwrap().entries.len(), 1);
+
+        fs::create_dir_all(root.join("impl/core-rs")).unwrap();
+        fs::write(
+            root.join("impl/core-rs/impl.md"),
+            "---\nname: core-rs\nspec: spec://core\nlocation: src\n---\n# Core\n",
+        )
+        .unwrap();
+        manual.push(FileChange::new(
+            workspace.impl_dir().join("core-rs/impl.md"),
+            FileChangeKind::Created,
+        ));
+
+        let index = watcher.index().unwrap();
+        assert_eq!(index.artifacts.len(), 2);
+        assert_eq!(watcher.generation().unwrap(), 1);
+        let inventory = graph.inventory_snapshot().unwrap();
+        assert_eq!(inventory.entries.len(), 2);
+        let core = workspace.spec_dir().join("core/spec.md");
+        assert_eq!(inventory.dependents_of(&core).len(), 1);
+
+        manual.push(FileChange::new(
+            workspace.root().join("src/main.rs"),
+            FileChangeKind::Modified,
+        ));
+        let delta = watcher.sync().unwrap();
+        assert_eq!(delta.changes.len(), 1);
+        assert!(delta.structure.is_empty());
+        assert_eq!(watcher.generation().unwrap(), 1);
+    }
+}
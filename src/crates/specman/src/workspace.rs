@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
+use serde::Deserialize;
 use thiserror::Error;
 
 use crate::core::error::SpecmanError;
@@ -76,6 +77,123 @@ impl WorkspacePaths {
     }
 }
 
+/// Federation file declaring sibling workspaces, relative to `.specman/`.
+pub const WORKSPACES_FILE: &str = "workspaces.yaml";
+
+/// A sibling workspace declared in `.specman/workspaces.yaml`. Peers are traversed read-only:
+/// dependency trees, reference validation, and status follow links into them, but nothing
+/// writes there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PeerWorkspace {
+    name: String,
+    paths: WorkspacePaths,
+}
+
+impl PeerWorkspace {
+    /// Name used to qualify handles (`spec://<name>:<slug>`) and artifact names.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn paths(&self) -> &WorkspacePaths {
+        &self.paths
+    }
+}
+
+/// Named peers of a workspace, loaded from `.specman/workspaces.yaml`:
+///
+/// ```yaml
+/// workspaces:
+///   platform: ../platform
+/// ```
+///
+/// Peer paths are relative to the declaring workspace root. Peers of a peer are not
+/// inherited; every workspace that traverses into them must declare them itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkspaceFederation {
+    peers: Vec<PeerWorkspace>,
+}
+
+#[derive(Deserialize)]
+struct FederationFile {
+    #[serde(default)]
+    workspaces: BTreeMap<String, PathBuf>,
+}
+
+impl WorkspaceFederation {
+    /// Loads the federation declared by `workspace`; a missing file declares no peers.
+    pub fn load(workspace: &WorkspacePaths) -> Result<Self, SpecmanError> {
+        let file = workspace.dot_specman().join(WORKSPACES_FILE);
+        if !file.is_file() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&file)?;
+        let declared: FederationFile = serde_yaml::from_str(&content)
+            .map_err(|err| SpecmanError::Workspace(format!("invalid {}: {err}", file.display())))?;
+
+        let mut peers = Vec::with_capacity(declared.workspaces.len());
+        for (name, path) in declared.workspaces {
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_'))
+            {
+                return Err(SpecmanError::Workspace(format!(
+                    "peer workspace name `{name}` in {} may only contain lowercase letters, numbers, '-' or '_'",
+                    file.display()
+                )));
+            }
+            let root = normalize_workspace_path(&lexical_normalize(&workspace.root().join(&path)));
+            let dot_specman = root.join(".specman");
+            if !dot_specman.is_dir() {
+                return Err(SpecmanError::Workspace(format!(
+                    "peer workspace `{name}` at {} has no .specman directory",
+                    root.display()
+                )));
+            }
+            if root == normalize_workspace_path(workspace.root()) {
+                return Err(SpecmanError::Workspace(format!(
+                    "peer workspace `{name}` points back at {}",
+                    root.display()
+                )));
+            }
+            peers.push(PeerWorkspace {
+                name,
+                paths: WorkspacePaths::new(root, dot_specman),
+            });
+        }
+        Ok(Self { peers })
+    }
+
+    pub fn peers(&self) -> &[PeerWorkspace] {
+        &self.peers
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Looks up a declared peer by name.
+    pub fn peer(&self, name: &str) -> Result<&PeerWorkspace, SpecmanError> {
+        self.peers
+            .iter()
+            .find(|peer| peer.name == name)
+            .ok_or_else(|| {
+                SpecmanError::Workspace(format!(
+                    "unknown peer workspace `{name}` (declare it in .specman/{WORKSPACES_FILE})"
+                ))
+            })
+    }
+
+    /// Peer whose root contains `path`, if any.
+    pub fn owner_of(&self, path: &Path) -> Option<&PeerWorkspace> {
+        let normalized = normalize_workspace_path(path);
+        self.peers
+            .iter()
+            .find(|peer| normalized.starts_with(peer.paths.root()))
+    }
+}
+
 /// Computes a workspace-relative path using normalized, forward-slash separators.
 ///
 /// On Windows, this strips verbatim (`\\?\`) prefixes and normalizes drive letters
@@ -724,6 +842,37 @@ mod tests {
         assert_eq!(ctx.paths().dot_specman(), link_root.join(".specman"));
     }
 
+    #[test]
+    fn federation_declares_peers_relative_to_root() {
+        let temp = tempdir().unwrap();
+        let local = WorkspaceDiscovery::create(temp.path().join("payments")).unwrap();
+        let peer = WorkspaceDiscovery::create(temp.path().join("platform")).unwrap();
+        let file = local.paths().dot_specman().join(WORKSPACES_FILE);
+
+        assert!(WorkspaceFederation::load(local.paths()).unwrap().is_empty());
+
+        fs::write(&file, "workspaces:\n  platform: ../platform\n").unwrap();
+        let federation = WorkspaceFederation::load(local.paths()).unwrap();
+        let platform = federation.peer("platform").unwrap();
+        assert_eq!(platform.paths().root(), peer.paths().root());
+        assert_eq!(
+            federation
+                .owner_of(&peer.paths().spec_dir().join("auth/spec.md"))
+                .map(PeerWorkspace::name),
+            Some("platform")
+        );
+        assert!(
+            federation
+                .owner_of(&local.paths().spec_dir().join("auth/spec.md"))
+                .is_none()
+        );
+        assert!(federation.peer("infra").is_err());
+
+        fs::write(&file, "workspaces:\n  missing: ../missing\n").unwrap();
+        let err = WorkspaceFederation::load(local.paths()).unwrap_err();
+        assert!(err.to_string().contains("has no .specman directory"));
+    }
+
     #[cfg(windows)]
     #[test]
     fn workspace_relative_path_accepts_verbatim_prefix() {
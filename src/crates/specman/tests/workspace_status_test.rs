@@ -1,7 +1,7 @@
 use schemars::schema_for;
 use specman::{
-    ArtifactId, ArtifactKind, ArtifactStatus, StatusResult, WorkspacePaths, WorkspaceStatusConfig,
-    WorkspaceStatusReport, validate_workspace_status,
+    ArtifactId, ArtifactKind, ArtifactStatus, StatusResult, ValidationType, WorkspacePaths,
+    WorkspaceStatusConfig, WorkspaceStatusReport, validate_workspace_status,
 };
 use std::fs;
 
@@ -240,6 +240,301 @@ fn compliance_fails_when_location_metadata_is_omitted() {
     );
 }
 
+fn write_policy_workspace(root: &std::path::Path, policy: &str, tags: &str) -> ArtifactId {
+    fs::create_dir_all(root.join("spec/core")).unwrap();
+    fs::write(
+        root.join("spec/core/spec.md"),
+        "---\nname: core\nversion: \"1.0.0\"\n---\n# Core\n\n## Concept: Alpha\n\n!concept-alpha.one:\n- MUST exist.\n\n### Details\n\n!alpha-details.two:\n- MUST exist.\n\n## Concept: Beta\n\n!concept-beta.one:\n- MUST exist.\n",
+    )
+    .unwrap();
+
+    fs::create_dir_all(root.join("impl/lib")).unwrap();
+    fs::write(
+        root.join("impl/lib/impl.md"),
+        format!(
+            "---\nname: lib\nspec: spec://core\nlocation: ../../src\nversion: \"1.0.0\"\n{policy}---\n# Lib\n"
+        ),
+    )
+    .unwrap();
+
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), tags).unwrap();
+
+    ArtifactId {
+        kind: ArtifactKind::Implementation,
+        name: "lib".into(),
+    }
+}
+
+#[test]
+fn compliance_threshold_allows_partial_coverage() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    let impl_id = write_policy_workspace(
+        root,
+        "compliance:\n  min_coverage: 0.6\n",
+        "// [ENSURES: concept-alpha.one:TEST]\n// [ENSURES: alpha-details.two:MANUAL]\n",
+    );
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Pass);
+    let status = &report.artifacts[&impl_id];
+    assert_eq!(
+        status.compliance_missing,
+        vec!["concept-beta.one".to_string()]
+    );
+    assert!(status.compliance_errors.is_empty());
+
+    let metrics = status.compliance_metrics.as_ref().expect("metrics");
+    assert_eq!((metrics.covered_groups, metrics.total_groups), (2, 3));
+    assert!((metrics.coverage - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(metrics.by_type.get(&ValidationType::Test), Some(&1));
+    assert_eq!(metrics.by_type.get(&ValidationType::Manual), Some(&1));
+    assert_eq!(metrics.by_type.get(&ValidationType::Check), None);
+    // Groups under nested headings roll up into their top-level concept.
+    assert_eq!(metrics.concepts["Concept: Alpha"].covered_groups, 2);
+    assert_eq!(metrics.concepts["Concept: Beta"].coverage, 0.0);
+}
+
+#[test]
+fn compliance_threshold_failures_fail_status() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    let impl_id = write_policy_workspace(
+        root,
+        "compliance:\n  min_coverage: 0.6\n  require_test: true\n",
+        "// [ENSURES: concept-alpha.one:TEST]\n// [ENSURES: alpha-details.two:CHECK]\n",
+    );
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Fail);
+    let status = &report.artifacts[&impl_id];
+    assert_eq!(status.compliance_errors.len(), 1);
+    assert!(status.compliance_errors[0].contains("TEST-covered (1/3)"));
+
+    // Without a policy every group must be covered.
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    write_policy_workspace(root, "", "// [ENSURES: concept-alpha.one:TEST]\n");
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+    assert_eq!(report.global_status, StatusResult::Fail);
+}
+
+#[test]
+fn active_waivers_excuse_missing_groups() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    let impl_id = write_policy_workspace(
+        root,
+        "waivers:\n  - constraint: concept-beta.one\n    reason: deferred to 2.0\n    expires: 2999-12-31\n",
+        "// [ENSURES: concept-alpha.one:TEST]\n",
+    );
+    fs::write(
+        root.join(".specman/waivers.yaml"),
+        "waivers:\n  - constraint: alpha-details.two\n    reason: covered upstream\n    implementation: lib\n  - constraint: concept-alpha.one\n    reason: other impl\n    implementation: other\n",
+    )
+    .unwrap();
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Pass);
+    let status = &report.artifacts[&impl_id];
+    assert!(status.compliance_missing.is_empty());
+    assert!(status.compliance_errors.is_empty());
+    let waived: Vec<_> = status
+        .compliance_waived
+        .iter()
+        .map(|waiver| waiver.constraint.as_str())
+        .collect();
+    assert_eq!(waived, vec!["alpha-details.two", "concept-beta.one"]);
+}
+
+#[test]
+fn unknown_waivers_are_structure_errors() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    let impl_id = write_policy_workspace(
+        root,
+        "waivers:\n  - constraint: concept-gamma.one\n    reason: typo\n",
+        "// [ENSURES: concept-alpha.one:TEST]\n// [ENSURES: alpha-details.two:TEST]\n// [ENSURES: concept-beta.one:TEST]\n",
+    );
+    // Unscoped workspace waivers may name other specifications' groups.
+    fs::write(
+        root.join(".specman/waivers.yaml"),
+        "waivers:\n  - constraint: other-spec.one\n    reason: elsewhere\n  - constraint: concept-delta.one\n    reason: stale\n    implementation: lib\n",
+    )
+    .unwrap();
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Fail);
+    let status = &report.artifacts[&impl_id];
+    assert_eq!(
+        status.structure_errors,
+        vec![
+            "Waiver names unknown constraint group `concept-gamma.one` (typo)".to_string(),
+            "Waiver names unknown constraint group `concept-delta.one` (stale)".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn expired_waivers_fail_status() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    let impl_id = write_policy_workspace(
+        root,
+        "compliance:\n  min_coverage: 0.5\nwaivers:\n  - constraint: concept-beta.one\n    reason: deferred to 2.0\n    expires: 2000-01-01\n",
+        "// [ENSURES: concept-alpha.one:TEST]\n// [ENSURES: alpha-details.two:TEST]\n",
+    );
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Fail);
+    let status = &report.artifacts[&impl_id];
+    assert_eq!(
+        status.compliance_missing,
+        vec!["concept-beta.one".to_string()]
+    );
+    assert!(status.compliance_waived.is_empty());
+    assert_eq!(status.compliance_errors.len(), 1);
+    assert!(status.compliance_errors[0].contains("expired on 2000-01-01"));
+}
+
+#[test]
+fn unmet_version_requirements_fail_status() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    for (name, body) in [
+        ("core", "version: \"3.1.0\"\n"),
+        (
+            "api",
+            "version: \"1.0.0\"\ndependencies:\n  - ref: ../core/spec.md\n    version: \"^3\"\n",
+        ),
+        (
+            "cli",
+            "version: \"1.0.0\"\ndependencies:\n  - ref: spec://core\n    version: \"~3.0\"\n",
+        ),
+    ] {
+        fs::create_dir_all(root.join("spec").join(name)).unwrap();
+        fs::write(
+            root.join("spec").join(name).join("spec.md"),
+            format!("---\nname: {name}\n{body}---\n# {name}\n"),
+        )
+        .unwrap();
+    }
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Fail);
+    let spec = |name: &str| ArtifactId {
+        kind: ArtifactKind::Specification,
+        name: name.into(),
+    };
+    assert!(report.artifacts[&spec("api")].structure_errors.is_empty());
+    let errors = &report.artifacts[&spec("cli")].structure_errors;
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("Version requirement not met"));
+    assert!(errors[0].contains("`~3.0`, found 3.1.0"));
+}
+
+#[test]
+fn unfilled_template_scaffolding_fails_structure_check() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    fs::create_dir_all(root.join(".specman/templates")).unwrap();
+    fs::write(
+        root.join(".specman/templates/spec.md"),
+        "---\nname: {{output_name}}\n---\n# Title\n\n## Concept: Placeholder\n\n<!-- Describe the concept. -->\n",
+    )
+    .unwrap();
+    for (name, body) in [
+        (
+            "draft",
+            "# Draft\n\n<!-- AI TODO: Write the scope. -->\n\nOwned by {{owner}}.\n\n## Concept: Placeholder\n\n<!-- Describe the concept. -->\n",
+        ),
+        (
+            "done",
+            "# Done\n\n## Concept: Placeholder\n\nPlaceholders are replaced before review.\n",
+        ),
+    ] {
+        fs::create_dir_all(root.join("spec").join(name)).unwrap();
+        fs::write(
+            root.join("spec").join(name).join("spec.md"),
+            format!("---\nname: {name}\nversion: \"1.0.0\"\n---\n{body}"),
+        )
+        .unwrap();
+    }
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.global_status, StatusResult::Fail);
+    let spec = |name: &str| ArtifactId {
+        kind: ArtifactKind::Specification,
+        name: name.into(),
+    };
+    assert!(report.artifacts[&spec("done")].structure_errors.is_empty());
+    assert_eq!(
+        report.artifacts[&spec("draft")].structure_errors,
+        vec![
+            "Unfilled template: template guard comment `<!-- AI TODO: Write the scope. -->` at line 7"
+                .to_string(),
+            "Unfilled template: unrendered template token `{{owner}}` at line 9".to_string(),
+            "Unfilled template: placeholder section `## Concept: Placeholder` copied from the template at line 11"
+                .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn work_types_declare_their_own_unfilled_markers() {
+    let (_dir, workspace) = make_workspace();
+    let root = workspace.root();
+    fs::write(
+        root.join(".specman/work-types.yaml"),
+        "spike:\n  template: docs/spike.md\n  unfilled_markers: [\"SPIKE TODO:\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(
+        root.join("docs/spike.md"),
+        "---\nname: {{output_name}}\nwork_type:\n  spike: {}\n---\n# Spike\n\n<!-- SPIKE TODO: Answer the question. -->\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join(".specman/scratchpad/cache-spike")).unwrap();
+    fs::write(
+        root.join(".specman/scratchpad/cache-spike/scratch.md"),
+        "---\nname: cache-spike\nwork_type:\n  spike: {}\n---\n# Spike\n\n<!-- AI TODO: Not a spike marker. -->\n\n<!-- SPIKE TODO: Answer the question. -->\n",
+    )
+    .unwrap();
+
+    let report =
+        validate_workspace_status(root.to_path_buf(), WorkspaceStatusConfig::default()).unwrap();
+
+    assert_eq!(report.scratchpad_status, StatusResult::Fail);
+    let scratch = ArtifactId {
+        kind: ArtifactKind::ScratchPad,
+        name: "cache-spike".into(),
+    };
+    assert_eq!(
+        report.artifacts[&scratch].structure_errors,
+        vec![
+            "Unfilled template: template guard comment `<!-- SPIKE TODO: Answer the question. -->` at line 10"
+                .to_string(),
+        ]
+    );
+}
+
 #[test]
 fn workspace_status_config_defaults_match_required_categories() {
     // [ENSURES: concept-workspace-status.requirements:TEST]